@@ -16,3 +16,12 @@ export const USDC = new PublicKey(process.env.USDC);
 export const TokenA = new PublicKey(process.env.TOKEN_A);
 
 export const TokenB = new PublicKey(process.env.TOKEN_B);
+
+// Pyth price update accounts of the SOL/USD and USDC/USD feeds, priced as token 0 and token 1
+export const PYTH_SOL_USD_FEED_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
+export const PYTH_USDC_USD_FEED_ACCOUNT = new PublicKey(
+  "Dpw1EAVrSB1ibxiDQyTAW6Zip3J4Btk2x4SgApQCeFbX"
+);
@@ -114,6 +114,51 @@ export const protocolPDAs = ({
       programId
     );
 
+  const [vaultStrategyCfgMint0RebalanceEscrow] =
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vlt_rebalance_0_escrow:"),
+        vaultStrategyConfigPda.toBuffer(),
+        vaultStrategyPda.toBuffer(),
+      ],
+      programId
+    );
+
+  const [vaultStrategyCfgMint1RebalanceEscrow] =
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vlt_rebalance_1_escrow:"),
+        vaultStrategyConfigPda.toBuffer(),
+        vaultStrategyPda.toBuffer(),
+      ],
+      programId
+    );
+
+  const [vaultStrategyClosed0Escrow] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vlt_strtg_closed_0_escrow:"), vaultStrategyPda.toBuffer()],
+    programId
+  );
+
+  const [vaultStrategyClosed1Escrow] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vlt_strtg_closed_1_escrow:"), vaultStrategyPda.toBuffer()],
+    programId
+  );
+
+  const [shareMintPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vlt_strtg_share_mint:"), vaultStrategyPda.toBuffer()],
+    programId
+  );
+
+  const [oracleFeed0Pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle_feed:"), sortedMint0.toBuffer()],
+    programId
+  );
+
+  const [oracleFeed1Pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle_feed:"), sortedMint1.toBuffer()],
+    programId
+  );
+
   const [investReserveVaultPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("invest_reserve_vault:"),
@@ -137,5 +182,12 @@ export const protocolPDAs = ({
     vaultStrategyCfgMint1FeesEscrow,
     vaultStrategyCfgMint0PerfFeesEscrow,
     vaultStrategyCfgMint1PerfFeesEscrow,
+    vaultStrategyCfgMint0RebalanceEscrow,
+    vaultStrategyCfgMint1RebalanceEscrow,
+    vaultStrategyClosed0Escrow,
+    vaultStrategyClosed1Escrow,
+    shareMintPda,
+    oracleFeed0Pda,
+    oracleFeed1Pda,
   };
 };
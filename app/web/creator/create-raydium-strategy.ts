@@ -15,22 +15,13 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { createLookUpTable } from "../../utils";
+import {
+  PYTH_SOL_USD_FEED_ACCOUNT,
+  PYTH_USDC_USD_FEED_ACCOUNT,
+} from "../../constants";
 
 const RAYDIUM_POSITION_NFT = Keypair.generate();
 
-// Pyth price feed IDs (stable (mainnet))
-const PYTH_SOL_USD_FEED_ID =
-  "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
-const PYTH_USDC_USD_FEED_ID =
-  "0xeaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a";
-
-const PYTH_SOL_USD_FEED_ACCOUNT = new PublicKey(
-  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
-);
-const PYTH_USDC_USD_FEED_ACCOUNT = new PublicKey(
-  "Dpw1EAVrSB1ibxiDQyTAW6Zip3J4Btk2x4SgApQCeFbX"
-);
-
 export type CreateRaydiumStrategyParams = {
   provider: anchor.AnchorProvider;
   creator: PublicKey;
@@ -41,6 +32,13 @@ export type CreateRaydiumStrategyParams = {
   tickUpper: number;
   amount0Max: string;
   amount1Max: string;
+  // Pyth price update accounts, default to the SOL/USD and USDC/USD feeds
+  pythToken0PriceUpdate?: PublicKey;
+  pythToken1PriceUpdate?: PublicKey;
+  // Metadata of the strategy share mint
+  shareName?: string;
+  shareSymbol?: string;
+  shareUri?: string;
 };
 
 export const createRaydiumStrategyTx = async ({
@@ -53,6 +51,11 @@ export const createRaydiumStrategyTx = async ({
   tickUpper,
   amount0Max,
   amount1Max,
+  pythToken0PriceUpdate = PYTH_SOL_USD_FEED_ACCOUNT,
+  pythToken1PriceUpdate = PYTH_USDC_USD_FEED_ACCOUNT,
+  shareName = `${strategyConfigName} #${strategyId}`,
+  shareSymbol = "VLT",
+  shareUri = "",
 }: CreateRaydiumStrategyParams) => {
   const ammConfig = raydiumAmmConfig();
   const program = getProgram(provider);
@@ -107,6 +110,8 @@ export const createRaydiumStrategyTx = async ({
     vaultStrategyCfgMint1FeesEscrow,
     vaultStrategyCfgMint0PerfFeesEscrow,
     vaultStrategyCfgMint1PerfFeesEscrow,
+    oracleFeed0Pda,
+    oracleFeed1Pda,
   } = protocolPDAs({
     strategyCreator: creator,
     investor: creator,
@@ -172,8 +177,10 @@ export const createRaydiumStrategyTx = async ({
       mint0,
       mint1,
       USDC,
-      PYTH_SOL_USD_FEED_ACCOUNT,
-      PYTH_USDC_USD_FEED_ACCOUNT,
+      pythToken0PriceUpdate,
+      pythToken1PriceUpdate,
+      oracleFeed0Pda,
+      oracleFeed1Pda,
 
       vaultStrategyConfigPda,
       vaultStrategyPda,
@@ -202,9 +209,10 @@ export const createRaydiumStrategyTx = async ({
       tickUpper,
       tickArrayLowerStartIndex,
       tickArrayUpperStartIndex,
-      PYTH_SOL_USD_FEED_ID,
-      PYTH_USDC_USD_FEED_ID,
-      lookupTableAccount.key
+      lookupTableAccount.key,
+      shareName,
+      shareSymbol,
+      shareUri
     )
     .accounts({
       creator,
@@ -222,8 +230,8 @@ export const createRaydiumStrategyTx = async ({
       raydiumTokenVault1: openPositionTokenVault1,
       raydiumVault0Mint: mint0,
       raydiumVault1Mint: mint1,
-      pythToken0PriceUpdate: PYTH_SOL_USD_FEED_ACCOUNT,
-      pythToken1PriceUpdate: PYTH_USDC_USD_FEED_ACCOUNT,
+      pythToken0PriceUpdate,
+      pythToken1PriceUpdate,
     })
     // .signers([creator, RAYDIUM_POSITION_NFT])
     .remainingAccounts([
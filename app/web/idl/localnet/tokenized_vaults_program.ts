@@ -13,10 +13,146 @@ export type TokenizedVaultsProgram = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        16
+      ],
+      "accounts": [
+        {
+          "name": "pendingAdmin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "activateVaultStrategyConfig",
+      "discriminator": [
+        4
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addLiquidityRaydiumVaultStrategy",
       "discriminator": [
-        11
+        12
       ],
       "accounts": [
         {
@@ -24,6 +160,34 @@ export type TokenizedVaultsProgram = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "vaultStrategyConfig",
           "writable": true
@@ -144,6 +308,106 @@ export type TokenizedVaultsProgram = {
             ]
           }
         },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account receiving the minted shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
         {
           "name": "vaultStrategyCfgMint0Escrow",
           "docs": [
@@ -235,225 +499,247 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "raydiumClmmProgram",
-          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
-        },
-        {
-          "name": "raydiumPositionNftAccount",
-          "docs": [
-            "This account created in the contract by cpi to avoid large stack variables"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumPoolState",
-          "docs": [
-            "The program account of the pool in which the swap will be performed",
-            "Represents the state of the pool Token 0 / Token 1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumPersonalPosition",
-          "docs": [
-            "Increase liquidity for this position",
-            "Represents the position in the pool Token 0 / Token 1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumProtocolPosition",
-          "writable": true
-        },
-        {
-          "name": "raydiumTickArrayLower",
-          "writable": true
-        },
-        {
-          "name": "raydiumTickArrayUpper",
-          "writable": true
-        },
-        {
-          "name": "raydiumTokenVault0",
-          "docs": [
-            "The address that holds raydium pool tokens for token_0"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumTokenVault1",
-          "docs": [
-            "The address that holds raydium pool tokens for token_1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault0Mint",
-          "docs": [
-            "The mint of token 0"
-          ]
-        },
-        {
-          "name": "raydiumVault1Mint",
-          "docs": [
-            "The mint of token 1"
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "docs": [
-            "SPL program for token transfers"
-          ],
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "tokenProgram2022",
-          "docs": [
-            "SPL program 2022 for token transfers"
-          ],
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "memoProgram",
-          "docs": [
-            "memo program"
-          ],
-          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
-        },
-        {
-          "name": "systemProgram",
+          "name": "escrowVault",
           "docs": [
-            "System program"
+            "The investor USDC escrow, receives the remainder swapped back to USDC"
           ],
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "strategyId",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "createRaydiumVaultStrategy",
-      "discriminator": [
-        4
-      ],
-      "accounts": [
-        {
-          "name": "creator",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "vaultStrategyConfig",
-          "writable": true
-        },
-        {
-          "name": "vaultStrategy",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  108,
-                  116,
-                  95,
+                  101,
                   115,
-                  116,
+                  99,
                   114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
                   116,
-                  103,
                   58
                 ]
               },
               {
                 "kind": "account",
-                "path": "vaultStrategyConfig"
-              },
-              {
-                "kind": "account",
-                "path": "raydiumVault0Mint"
-              },
-              {
-                "kind": "account",
-                "path": "raydiumVault1Mint"
-              },
-              {
-                "kind": "arg",
-                "path": "strategyId"
+                "path": "investor"
               }
             ]
           }
         },
         {
-          "name": "investorStrategyPosition",
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0, receives the remainder not added as liquidity"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
               {
                 "kind": "const",
                 "value": [
-                  105,
-                  110,
-                  118,
-                  95,
-                  115,
-                  116,
-                  114,
-                  116,
-                  103,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
                   95,
-                  112,
-                  111,
-                  115,
-                  58
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
               },
               {
                 "kind": "account",
-                "path": "vaultStrategy"
-              },
-              {
-                "kind": "account",
-                "path": "creator"
+                "path": "raydiumVault0Mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "vaultStrategyCfgMint0Escrow",
+          "name": "investorMint1Account",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The investor account for the token 1, receives the remainder not added as liquidity"
           ],
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  108,
-                  116,
-                  95,
-                  115,
-                  119,
-                  97,
-                  112,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
                   95,
-                  114,
-                  97,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 0",
+            "Vault strategy Config collects the token 0 fees in this account before the deposit"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
                   116,
-                  105,
-                  111,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
                   95,
                   48,
                   95,
@@ -474,10 +760,10 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgMint1Escrow",
+          "name": "vaultStrategyCfgMint1FeesEscrow",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The fees escrow account for the token 1",
+            "Vault strategy Config collects the token 1 fees in this account before the deposit"
           ],
           "writable": true,
           "pda": {
@@ -489,16 +775,10 @@ export type TokenizedVaultsProgram = {
                   108,
                   116,
                   95,
+                  102,
+                  101,
+                  101,
                   115,
-                  119,
-                  97,
-                  112,
-                  95,
-                  114,
-                  97,
-                  116,
-                  105,
-                  111,
                   95,
                   49,
                   95,
@@ -519,10 +799,9 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The performance fees escrow account for the token 0"
           ],
           "writable": true,
           "pda": {
@@ -534,6 +813,11 @@ export type TokenizedVaultsProgram = {
                   108,
                   116,
                   95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
                   102,
                   101,
                   101,
@@ -558,10 +842,9 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The performance fees escrow account for the token 1"
           ],
           "writable": true,
           "pda": {
@@ -573,6 +856,11 @@ export type TokenizedVaultsProgram = {
                   108,
                   116,
                   95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
                   102,
                   101,
                   101,
@@ -597,10 +885,10 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "name": "protocolMint0FeesEscrow",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
           ],
           "writable": true,
           "pda": {
@@ -608,20 +896,103 @@ export type TokenizedVaultsProgram = {
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  108,
+                  112,
+                  114,
+                  111,
                   116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
                   95,
-                  112,
                   101,
+                  115,
+                  99,
                   114,
-                  102,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
                   95,
                   102,
                   101,
                   101,
                   115,
                   95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
                   48,
                   95,
                   101,
@@ -636,15 +1007,18 @@ export type TokenizedVaultsProgram = {
               {
                 "kind": "account",
                 "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
               }
             ]
           }
         },
         {
-          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded"
           ],
           "writable": true,
           "pda": {
@@ -656,15 +1030,15 @@ export type TokenizedVaultsProgram = {
                   108,
                   116,
                   95,
-                  112,
-                  101,
                   114,
-                  102,
-                  95,
-                  102,
                   101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
                   101,
-                  115,
                   95,
                   49,
                   95,
@@ -680,25 +1054,18 @@ export type TokenizedVaultsProgram = {
               {
                 "kind": "account",
                 "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
               }
             ]
           }
         },
-        {
-          "name": "pythToken0PriceUpdate"
-        },
-        {
-          "name": "pythToken1PriceUpdate"
-        },
         {
           "name": "raydiumClmmProgram",
           "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
         },
-        {
-          "name": "raydiumPositionNftMint",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "raydiumPositionNftAccount",
           "docs": [
@@ -708,36 +1075,30 @@ export type TokenizedVaultsProgram = {
         },
         {
           "name": "raydiumPoolState",
+          "docs": [
+            "The program account of the pool in which the swap will be performed",
+            "Represents the state of the pool Token 0 / Token 1"
+          ],
           "writable": true
         },
         {
-          "name": "raydiumProtocolPosition",
-          "writable": true
-        },
-        {
-          "name": "raydiumTickArrayLower",
-          "writable": true
-        },
-        {
-          "name": "raydiumTickArrayUpper",
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "Increase liquidity for this position",
+            "Represents the position in the pool Token 0 / Token 1"
+          ],
           "writable": true
         },
         {
-          "name": "raydiumPersonalPosition",
+          "name": "raydiumProtocolPosition",
           "writable": true
         },
         {
-          "name": "raydiumTokenAccount0",
-          "docs": [
-            "The token_0 account that will deposit tokens to the raydium pool"
-          ],
+          "name": "raydiumTickArrayLower",
           "writable": true
         },
         {
-          "name": "raydiumTokenAccount1",
-          "docs": [
-            "The token_1 account that will deposit tokens to the raydium pool"
-          ],
+          "name": "raydiumTickArrayUpper",
           "writable": true
         },
         {
@@ -757,345 +1118,369 @@ export type TokenizedVaultsProgram = {
         {
           "name": "raydiumVault0Mint",
           "docs": [
-            "The mint of raydium token vault 0"
+            "The mint of token 0"
           ]
         },
         {
           "name": "raydiumVault1Mint",
           "docs": [
-            "The mint of raydium token vault 1"
+            "The mint of token 1"
           ]
         },
         {
-          "name": "rent",
+          "name": "token0OracleFeed",
           "docs": [
-            "Sysvar for token mint and ATA creation"
+            "Oracle registry entry of token 0"
           ],
-          "address": "SysvarRent111111111111111111111111111111111"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
+          "name": "token1OracleFeed",
           "docs": [
-            "Program to create the position manager state account"
+            "Oracle registry entry of token 1"
           ],
-          "address": "11111111111111111111111111111111"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
+          "name": "pythToken0PriceUpdate",
           "docs": [
-            "Program to create mint account and mint tokens"
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
           ],
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "optional": true
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "pythToken1PriceUpdate",
           "docs": [
-            "Program to create an ATA for receiving position NFT"
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
           ],
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "optional": true
         },
         {
-          "name": "tokenProgram2022",
+          "name": "token0SwitchboardFeed",
           "docs": [
-            "Program to create mint account and mint tokens"
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
           ],
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        }
-      ],
-      "args": [
+          "optional": true
+        },
         {
-          "name": "strategyId",
-          "type": "u8"
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
         },
         {
-          "name": "percentage",
-          "type": "u32"
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
         },
         {
-          "name": "amount0Max",
-          "type": "u64"
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
         },
         {
-          "name": "amount1Max",
-          "type": "u64"
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
         },
         {
-          "name": "tickLowerIndex",
-          "type": "i32"
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
         },
         {
-          "name": "tickUpperIndex",
-          "type": "i32"
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "tickArrayLowerStartIndex",
-          "type": "i32"
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         },
         {
-          "name": "tickArrayUpperStartIndex",
-          "type": "i32"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "token0FeedId",
-          "type": "string"
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
         },
         {
-          "name": "token1FeedId",
-          "type": "string"
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
         },
         {
-          "name": "lookUpTable",
-          "type": "pubkey"
+          "name": "refund0UsdcAmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "refund1UsdcAmountOutMin",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "depositToEscrow",
+      "name": "cancelAdminProposal",
       "discriminator": [
-        5
+        17
       ],
       "accounts": [
         {
-          "name": "investor",
+          "name": "adminAuthority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
         },
         {
-          "name": "escrowVault",
+          "name": "protocolConfig",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
-                  99,
+                  112,
                   114,
                   111,
-                  119,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
                   95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelInvestReserve",
+      "discriminator": [
+        30
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   118,
                   97,
                   117,
                   108,
                   116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
                   58
                 ]
               },
               {
                 "kind": "account",
-                "path": "investor"
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
               }
             ]
           }
         },
         {
-          "name": "investorTokenAccount",
+          "name": "investReserveVault",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "investor"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
                 ]
               },
               {
                 "kind": "account",
-                "path": "usdcMint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
-        },
-        {
-          "name": "usdcMint"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initProtocolConfig",
-      "discriminator": [
-        0
-      ],
-      "accounts": [
-        {
-          "name": "adminAuthority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocolConfig",
-          "writable": true,
-          "pda": {
-            "seeds": [
+                "path": "investor"
+              },
               {
-                "kind": "const",
-                "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103,
-                  58
-                ]
+                "kind": "account",
+                "path": "vaultStrategyConfig"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "protocolFees",
-          "type": "u32"
-        }
-      ]
-    },
-    {
-      "name": "initVaultStrategyConfig",
-      "discriminator": [
-        3
-      ],
-      "accounts": [
-        {
-          "name": "creator",
+          "name": "escrowVault",
+          "docs": [
+            "The investor escrow vault, receives the USDC that was never swapped"
+          ],
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocolConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
+                  101,
+                  115,
                   99,
+                  114,
                   111,
-                  108,
+                  119,
                   95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
                   58
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
               }
             ]
           }
         },
         {
-          "name": "vaultStrategyConfig",
+          "name": "vaultStrategyCfgUsdcEscrow",
+          "docs": [
+            "The escrow account for the USDC",
+            "Holds the reserved USDC until it is swapped"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -1103,8 +1488,6 @@ export type TokenizedVaultsProgram = {
                 "kind": "const",
                 "value": [
                   118,
-                  97,
-                  117,
                   108,
                   116,
                   95,
@@ -1118,30 +1501,35 @@ export type TokenizedVaultsProgram = {
                   121,
                   95,
                   99,
-                  111,
-                  110,
                   102,
-                  105,
                   103,
+                  95,
+                  117,
+                  115,
+                  100,
+                  99,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
                   58
                 ]
               },
               {
                 "kind": "account",
-                "path": "creator"
-              },
-              {
-                "kind": "arg",
-                "path": "name"
+                "path": "vaultStrategyConfig"
               }
             ]
           }
         },
         {
-          "name": "vaultStrategyCfgUsdcEscrow",
+          "name": "vaultStrategyCfgMint0Escrow",
           "docs": [
-            "The escrow account for the USDC",
-            "Vault strategy Config receives USDC in this account from User's escrow vault"
+            "The escrow account for the token 0",
+            "Holds the swapped token 0 until the liquidity is added"
           ],
           "writable": true,
           "pda": {
@@ -1154,22 +1542,17 @@ export type TokenizedVaultsProgram = {
                   116,
                   95,
                   115,
-                  116,
+                  119,
+                  97,
+                  112,
+                  95,
                   114,
                   97,
                   116,
-                  101,
-                  103,
-                  121,
-                  95,
-                  99,
-                  102,
-                  103,
+                  105,
+                  111,
                   95,
-                  117,
-                  115,
-                  100,
-                  99,
+                  48,
                   95,
                   101,
                   115,
@@ -1188,85 +1571,11 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "usdcMint"
-        },
-        {
-          "name": "tokenProgram",
+          "name": "vaultStrategyCfgMint1Escrow",
           "docs": [
-            "Program to create mint account and mint tokens"
+            "The escrow account for the token 1",
+            "Holds the swapped token 1 until the liquidity is added"
           ],
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "performanceFee",
-          "type": "u32"
-        },
-        {
-          "name": "vaultStrategyType",
-          "type": {
-            "defined": {
-              "name": "vaultStrategyType"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "investReserve",
-      "discriminator": [
-        9
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "vaultStrategyConfig"
-        },
-        {
-          "name": "escrowVault",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
-                  58
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "investor"
-              }
-            ]
-          }
-        },
-        {
-          "name": "vaultStrategyCfgUsdcEscrow",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1278,22 +1587,17 @@ export type TokenizedVaultsProgram = {
                   116,
                   95,
                   115,
-                  116,
+                  119,
+                  97,
+                  112,
+                  95,
                   114,
                   97,
                   116,
-                  101,
-                  103,
-                  121,
-                  95,
-                  99,
-                  102,
-                  103,
+                  105,
+                  111,
                   95,
-                  117,
-                  115,
-                  100,
-                  99,
+                  49,
                   95,
                   101,
                   115,
@@ -1312,109 +1616,208 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "investReserveVault",
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
               {
                 "kind": "const",
                 "value": [
-                  105,
-                  110,
-                  118,
-                  101,
-                  115,
-                  116,
-                  95,
-                  114,
-                  101,
-                  115,
-                  101,
-                  114,
-                  118,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
                   101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
                   95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
-                  58
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
               },
               {
                 "kind": "account",
-                "path": "investor"
-              },
-              {
-                "kind": "account",
-                "path": "vaultStrategyConfig"
+                "path": "raydiumVault0Mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "usdcMint"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "pauseProtocol",
-      "discriminator": [
-        1
-      ],
-      "accounts": [
-        {
-          "name": "adminAuthority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "protocolConfig"
-          ]
-        },
-        {
-          "name": "protocolConfig",
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor account for the token 1"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103,
-                  58
-                ]
-              }
-            ]
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "raydiumVault0Mint"
+        },
+        {
+          "name": "raydiumVault1Mint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -1423,22 +1826,46 @@ export type TokenizedVaultsProgram = {
       "args": []
     },
     {
-      "name": "pauseVault",
+      "name": "claimInvestorFees",
       "discriminator": [
-        7
+        31
       ],
       "accounts": [
         {
-          "name": "creator",
+          "name": "investor",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "vaultStrategyConfig"
-          ]
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "vaultStrategyConfig",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1467,32 +1894,20 @@ export type TokenizedVaultsProgram = {
                   103,
                   58
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
               }
             ]
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "removeLiquidityRaydiumVaultStrategy",
-      "discriminator": [
-        12
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "vaultStrategyConfig",
-          "writable": true
-        },
         {
           "name": "vaultStrategy",
           "writable": true,
@@ -1567,11 +1982,108 @@ export type TokenizedVaultsProgram = {
             ]
           }
         },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ]
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares, the fees are claimed on its balance"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
         {
           "name": "investorMint0Account",
           "docs": [
-            "The investor escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium LP"
+            "The investor account for the token 0, receives the claimed fees"
           ],
           "writable": true,
           "pda": {
@@ -1664,8 +2176,7 @@ export type TokenizedVaultsProgram = {
         {
           "name": "investorMint1Account",
           "docs": [
-            "The investor escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium LP"
+            "The investor account for the token 1, receives the claimed fees"
           ],
           "writable": true,
           "pda": {
@@ -1758,8 +2269,8 @@ export type TokenizedVaultsProgram = {
         {
           "name": "vaultStrategyCfgMint0FeesEscrow",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The fees escrow account for the token 0",
+            "Vault strategy Config collects the token 0 fees in this account"
           ],
           "writable": true,
           "pda": {
@@ -1797,8 +2308,8 @@ export type TokenizedVaultsProgram = {
         {
           "name": "vaultStrategyCfgMint1FeesEscrow",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The fees escrow account for the token 1",
+            "Vault strategy Config collects the token 1 fees in this account"
           ],
           "writable": true,
           "pda": {
@@ -1836,8 +2347,7 @@ export type TokenizedVaultsProgram = {
         {
           "name": "vaultStrategyCfgMint0PerfFeesEscrow",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The performance fees escrow account for the token 0"
           ],
           "writable": true,
           "pda": {
@@ -1880,8 +2390,7 @@ export type TokenizedVaultsProgram = {
         {
           "name": "vaultStrategyCfgMint1PerfFeesEscrow",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The performance fees escrow account for the token 1"
           ],
           "writable": true,
           "pda": {
@@ -1921,30 +2430,106 @@ export type TokenizedVaultsProgram = {
             ]
           }
         },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
         {
           "name": "raydiumClmmProgram",
           "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
         },
         {
           "name": "raydiumPositionNftAccount",
-          "docs": [
-            "This account created in the contract by cpi to avoid large stack variables"
-          ],
           "writable": true
         },
         {
           "name": "raydiumPoolState",
-          "docs": [
-            "The program account of the pool in which the swap will be performed",
-            "Represents the state of the pool Token 0 / Token 1"
-          ],
           "writable": true
         },
         {
           "name": "raydiumPersonalPosition",
           "docs": [
-            "Increase liquidity for this position",
-            "Represents the position in the pool Token 0 / Token 1"
+            "The raydium position of the vault strategy"
           ],
           "writable": true
         },
@@ -1986,6 +2571,126 @@ export type TokenizedVaultsProgram = {
             "The mint of token 1"
           ]
         },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
         {
           "name": "tokenProgram",
           "docs": [
@@ -2023,110 +2728,102 @@ export type TokenizedVaultsProgram = {
         {
           "name": "strategyId",
           "type": "u8"
-        },
-        {
-          "name": "percentage",
-          "type": "u64"
-        },
-        {
-          "name": "amount0Min",
-          "type": "u64"
-        },
-        {
-          "name": "amount1Min",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "swapToRatioRaydiumVaultStrategy",
+      "name": "claimPerformanceFees",
       "discriminator": [
-        10
+        22
       ],
       "accounts": [
         {
-          "name": "investor",
+          "name": "creator",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
         },
         {
           "name": "vaultStrategyConfig",
-          "writable": true
-        },
-        {
-          "name": "vaultStrategy",
-          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   118,
+                  97,
+                  117,
                   108,
                   116,
                   95,
                   115,
                   116,
                   114,
+                  97,
                   116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
                   103,
                   58
                 ]
               },
               {
                 "kind": "account",
-                "path": "vaultStrategyConfig"
-              },
-              {
-                "kind": "account",
-                "path": "raydiumVault0Mint"
+                "path": "creator"
               },
               {
                 "kind": "account",
-                "path": "raydiumVault1Mint"
-              },
-              {
-                "kind": "arg",
-                "path": "strategyId"
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
               }
             ]
           }
         },
         {
-          "name": "investReserveVault",
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  105,
-                  110,
                   118,
-                  101,
-                  115,
+                  108,
                   116,
                   95,
+                  112,
+                  101,
                   114,
+                  102,
+                  95,
+                  102,
+                  101,
                   101,
                   115,
+                  95,
+                  48,
+                  95,
                   101,
+                  115,
+                  99,
                   114,
-                  118,
-                  101,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
+                  111,
+                  119,
                   58
                 ]
               },
-              {
-                "kind": "account",
-                "path": "investor"
-              },
               {
                 "kind": "account",
                 "path": "vaultStrategyConfig"
@@ -2135,10 +2832,9 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgUsdcEscrow",
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
           "docs": [
-            "The escrow account for the USDC",
-            "Vault strategy Config receives USDC in this account from User's escrow vault"
+            "The performance fees escrow account for the token 1"
           ],
           "writable": true,
           "pda": {
@@ -2150,23 +2846,17 @@ export type TokenizedVaultsProgram = {
                   108,
                   116,
                   95,
-                  115,
-                  116,
-                  114,
-                  97,
-                  116,
+                  112,
                   101,
-                  103,
-                  121,
-                  95,
-                  99,
+                  114,
                   102,
-                  103,
                   95,
-                  117,
+                  102,
+                  101,
+                  101,
                   115,
-                  100,
-                  99,
+                  95,
+                  49,
                   95,
                   101,
                   115,
@@ -2185,271 +2875,251 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "vaultStrategyCfgMint0Escrow",
+          "name": "creatorMint0Account",
           "docs": [
-            "The escrow account for the token 0",
-            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+            "The creator account for the token 0, only passed, and created if needed,",
+            "when the token 0 fees go to the creator"
           ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  108,
-                  116,
-                  95,
-                  115,
-                  119,
-                  97,
-                  112,
-                  95,
-                  114,
-                  97,
-                  116,
-                  105,
-                  111,
-                  95,
-                  48,
-                  95,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
                   101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  58
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
               },
               {
                 "kind": "account",
-                "path": "vaultStrategyConfig"
+                "path": "vault0Mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "vaultStrategyCfgMint1Escrow",
+          "name": "creatorMint1Account",
           "docs": [
-            "The escrow account for the token 1",
-            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+            "The creator account for the token 1, only passed, and created if needed,",
+            "when the token 1 fees go to the creator"
           ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  108,
-                  116,
-                  95,
-                  115,
-                  119,
-                  97,
-                  112,
-                  95,
-                  114,
-                  97,
-                  116,
-                  105,
-                  111,
-                  95,
-                  49,
-                  95,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
                   101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  58
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
               },
               {
                 "kind": "account",
-                "path": "vaultStrategyConfig"
+                "path": "vault1Mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "usdcMint"
-        },
-        {
-          "name": "raydiumClmmProgram",
-          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+          "name": "destinationMint0Account",
+          "docs": [
+            "Optional destination for the token 0 fees, replaces the creator account"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "raydiumPoolState",
+          "name": "destinationMint1Account",
           "docs": [
-            "The program account of the pool in which the swap will be performed",
-            "Represents the state of the pool Token 0 / Token 1"
+            "Optional destination for the token 1 fees, replaces the creator account"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "raydiumPersonalPosition",
+          "name": "vault0Mint",
           "docs": [
-            "Increase liquidity for this position",
-            "Represents the position in the pool Token 0 / Token 1"
+            "The mint of token 0"
           ]
         },
         {
-          "name": "raydiumAmmConfigUsdcForToken0",
-          "docs": [
-            "The factory state to read protocol fees"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumPoolStateUsdcForToken0",
-          "docs": [
-            "The program account of the pool in which the swap will be performed"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumAmmConfigUsdcForToken1",
-          "docs": [
-            "The factory state to read protocol fees"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumPoolStateUsdcForToken1",
-          "docs": [
-            "The program account of the pool in which the swap will be performed"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault0Input",
-          "docs": [
-            "The vault token account for input token",
-            "User sends USDC to this account to swap to token 0",
-            "Pool: USDC / Token 0"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault1Input",
-          "docs": [
-            "The vault token account for input token",
-            "User sends USDC to this account to swap to token 1",
-            "Pool: USDC / Token 1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault0Output",
-          "docs": [
-            "The vault token account for output token",
-            "User receives token 0 from this account",
-            "Pool: USDC / Token 0"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault1Output",
-          "docs": [
-            "The vault token account for output token",
-            "User receives token 1 from this account",
-            "Pool: USDC / Token 1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumVault0Mint",
-          "docs": [
-            "The mint of token 0"
-          ]
-        },
-        {
-          "name": "raydiumVault1Mint",
+          "name": "vault1Mint",
           "docs": [
             "The mint of token 1"
           ]
         },
-        {
-          "name": "raydiumObservationState0",
-          "docs": [
-            "The program account for the most recent oracle observation",
-            "USDC/Token 0"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumObservationState1",
-          "docs": [
-            "The program account for the most recent oracle observation",
-            "USDC/Token 1"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumTokenVault0",
-          "docs": [
-            "The address that holds raydium pool tokens for token_0"
-          ],
-          "writable": true
-        },
-        {
-          "name": "raydiumTokenVault1",
-          "docs": [
-            "The address that holds raydium pool tokens for token_1"
-          ],
-          "writable": true
-        },
         {
           "name": "tokenProgram",
-          "docs": [
-            "SPL program for token transfers"
-          ],
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "tokenProgram2022",
-          "docs": [
-            "SPL program 2022 for token transfers"
-          ],
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "memoProgram",
-          "docs": [
-            "memo program"
-          ],
-          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
           "name": "systemProgram",
-          "docs": [
-            "System program"
-          ],
           "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "strategyId",
-          "type": "u8"
-        },
-        {
-          "name": "token0AmountOutMin",
+          "name": "amount0",
           "type": "u64"
         },
         {
-          "name": "token1AmountOutMin",
+          "name": "amount1",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "unpauseProtocol",
+      "name": "claimProtocolFees",
       "discriminator": [
-        2
+        14
       ],
       "accounts": [
         {
@@ -2462,7 +3132,6 @@ export type TokenizedVaultsProgram = {
         },
         {
           "name": "protocolConfig",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2490,16 +3159,72 @@ export type TokenizedVaultsProgram = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "protocolFeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "docs": [
+            "The token account that receives the claimed protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "unpauseVault",
+      "name": "closeRaydiumPosition",
       "discriminator": [
-        8
+        21
       ],
       "accounts": [
         {
@@ -2541,167 +3266,14812 @@ export type TokenizedVaultsProgram = {
                   103,
                   58
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdrawFromEscrow",
-      "discriminator": [
-        6
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "escrowVault",
-          "writable": true,
+          "name": "vaultStrategy",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119,
-                  95,
                   118,
-                  97,
-                  117,
                   108,
                   116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
                   58
                 ]
               },
               {
                 "kind": "account",
-                "path": "investor"
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_0",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_1",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
               }
             ]
           }
         },
         {
-          "name": "investorTokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "investor"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftMint",
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022, the position NFT is a Token-2022 mint"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeRaydiumVaultStrategy",
+      "discriminator": [
+        19
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The vault creator or the protocol admin"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyClosedMint0Escrow",
+          "docs": [
+            "The escrow account that holds the token 0 proceeds of the closed strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  99,
+                  108,
+                  111,
+                  115,
+                  101,
+                  100,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyClosedMint1Escrow",
+          "docs": [
+            "The escrow account that holds the token 1 proceeds of the closed strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  99,
+                  108,
+                  111,
+                  115,
+                  101,
+                  100,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded,",
+            "swept into the closed escrow"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded,",
+            "swept into the closed escrow"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the collected token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the collected token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position owned by the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeVault",
+      "discriminator": [
+        18
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The vault creator or the protocol admin"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "compoundRaydiumVaultStrategy",
+      "discriminator": [
+        25
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can crank the compound, pays the escrows rent on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Holds the token 0 fees until they are added back to the position"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Holds the token 1 fees until they are added back to the position"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the collected token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the collected token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumAmmConfig",
+          "docs": [
+            "The factory state of the pool, used by the swap"
+          ]
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState",
+          "docs": [
+            "The program account for the most recent oracle observation, used by the swap",
+            "and for the TWAP its minimum output is derived from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createRaydiumVaultStrategy",
+      "discriminator": [
+        5
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorShareAccount",
+          "docs": [
+            "The creator account for the shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "shareMintMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0Escrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1Escrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftMint",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "docs": [
+            "This account created in the contract by cpi to avoid large stack variables"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenAccount0",
+          "docs": [
+            "The token_0 account that will deposit tokens to the raydium pool"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenAccount1",
+          "docs": [
+            "The token_1 account that will deposit tokens to the raydium pool"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of raydium token vault 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of raydium token vault 1"
+          ]
+        },
+        {
+          "name": "rent",
+          "docs": [
+            "Sysvar for token mint and ATA creation"
+          ],
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "Program to create the position manager state account"
+          ],
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Program to create mint account and mint tokens"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Program to create an ATA for receiving position NFT"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "Program to create mint account and mint tokens"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "Program to create the share mint metadata"
+          ],
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "percentage",
+          "type": "u32"
+        },
+        {
+          "name": "amount0Max",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Max",
+          "type": "u64"
+        },
+        {
+          "name": "tickLowerIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickUpperIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickArrayLowerStartIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickArrayUpperStartIndex",
+          "type": "i32"
+        },
+        {
+          "name": "lookUpTable",
+          "type": "pubkey"
+        },
+        {
+          "name": "shareName",
+          "type": "string"
+        },
+        {
+          "name": "shareSymbol",
+          "type": "string"
+        },
+        {
+          "name": "shareUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "depositRaydiumVault",
+      "discriminator": [
+        27
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowVault",
+          "docs": [
+            "The investor USDC escrow, funded by `deposit_to_escrow`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgUsdcEscrow",
+          "docs": [
+            "The escrow account for the USDC",
+            "Vault strategy Config receives USDC in this account from User's escrow vault"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  102,
+                  103,
+                  95,
+                  117,
+                  115,
+                  100,
+                  99,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0Escrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1Escrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0, receives the remainder not added as liquidity"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor account for the token 1, receives the remainder not added as liquidity"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 0",
+            "Vault strategy Config collects the token 0 fees in this account before the deposit"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 1",
+            "Vault strategy Config collects the token 1 fees in this account before the deposit"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account receiving the minted shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumAmmConfigUsdcForToken0",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateUsdcForToken0",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumAmmConfigUsdcForToken1",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateUsdcForToken1",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Input",
+          "docs": [
+            "The vault token account for input token",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Input",
+          "docs": [
+            "The vault token account for input token",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Output",
+          "docs": [
+            "The vault token account for output token",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Output",
+          "docs": [
+            "The vault token account for output token",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState0",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState1",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "token0AmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "token1AmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "minSharesOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositToEscrow",
+      "discriminator": [
+        6
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositTokensRaydiumVault",
+      "discriminator": [
+        38
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0, funds the deposit and receives",
+            "the remainder not added as liquidity"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor account for the token 1, funds the deposit and receives",
+            "the remainder not added as liquidity"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0Escrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config adds the token 0 to the position from this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1Escrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config adds the token 1 to the position from this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 0",
+            "Vault strategy Config collects the token 0 fees in this account before the deposit"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 1",
+            "Vault strategy Config collects the token 1 fees in this account before the deposit"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account receiving the minted shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumAmmConfig",
+          "docs": [
+            "The factory state of the pool, used by the swap"
+          ]
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState",
+          "docs": [
+            "The program account for the most recent oracle observation, used by the swap"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "amount0",
+          "type": "u64"
+        },
+        {
+          "name": "amount1",
+          "type": "u64"
+        },
+        {
+          "name": "swapAmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "minSharesOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "getPositionValue",
+      "discriminator": [
+        37
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position to value, only required by `get_position_value`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor_strategy_position.authority",
+                "account": "investorStrategyPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumPoolState"
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ]
+        },
+        {
+          "name": "raydiumTickArrayLower"
+        },
+        {
+          "name": "raydiumTickArrayUpper"
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "positionValue"
+        }
+      }
+    },
+    {
+      "name": "getVaultNav",
+      "discriminator": [
+        34
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position to value, only required by `get_position_value`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor_strategy_position.authority",
+                "account": "investorStrategyPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumPoolState"
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ]
+        },
+        {
+          "name": "raydiumTickArrayLower"
+        },
+        {
+          "name": "raydiumTickArrayUpper"
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "vaultNav"
+        }
+      }
+    },
+    {
+      "name": "initProtocolConfig",
+      "discriminator": [
+        0
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFees",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "initVaultStrategyConfig",
+      "discriminator": [
+        3
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "name"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgUsdcEscrow",
+          "docs": [
+            "The escrow account for the USDC",
+            "Vault strategy Config receives USDC in this account from User's escrow vault"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  102,
+                  103,
+                  95,
+                  117,
+                  115,
+                  100,
+                  99,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Program to create mint account and mint tokens"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "performanceFee",
+          "type": "u32"
+        },
+        {
+          "name": "vaultStrategyType",
+          "type": {
+            "defined": {
+              "name": "vaultStrategyType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initVaultStrategyShareMint",
+      "discriminator": [
+        26
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "docs": [
+            "A strategy created before the share mint existed, grown by `migrate_account`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_0",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_1",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMintMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "investReserve",
+      "discriminator": [
+        10
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig"
+        },
+        {
+          "name": "escrowVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgUsdcEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  102,
+                  103,
+                  95,
+                  117,
+                  115,
+                  100,
+                  99,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investReserveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  95,
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateAccount",
+      "discriminator": [
+        43
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can migrate an account, the payer funds the rent of the appended fields"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateInvestorShares",
+      "discriminator": [
+        44
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can migrate a position, the payer funds the investor share account if needed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_0",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy.mint_1",
+                "account": "vaultStrategy"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "A position opened before the strategy had a share mint, grown by `migrate_account`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investor"
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy, created by `init_vault_strategy_share_mint`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account receiving the position shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "pauseProtocol",
+      "discriminator": [
+        1
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseVault",
+      "discriminator": [
+        8
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "previewDeposit",
+      "discriminator": [
+        35
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position to value, only required by `get_position_value`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor_strategy_position.authority",
+                "account": "investorStrategyPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumPoolState"
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ]
+        },
+        {
+          "name": "raydiumTickArrayLower"
+        },
+        {
+          "name": "raydiumTickArrayUpper"
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "amount0",
+          "type": "u64"
+        },
+        {
+          "name": "amount1",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "depositPreview"
+        }
+      }
+    },
+    {
+      "name": "previewRedeem",
+      "discriminator": [
+        36
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position to value, only required by `get_position_value`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor_strategy_position.authority",
+                "account": "investorStrategyPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumPoolState"
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ]
+        },
+        {
+          "name": "raydiumTickArrayLower"
+        },
+        {
+          "name": "raydiumTickArrayUpper"
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "redeemPreview"
+        }
+      }
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        15
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "rebalanceRaydiumVaultStrategy",
+      "discriminator": [
+        24
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The vault creator or its keeper, pays the rent of the new position"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "docs": [
+            "Receives the rent raydium refunds when the current position is closed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Holds the token 0 pulled from the old position until it is added to the new one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Holds the token 1 pulled from the old position until it is added to the new one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the collected token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the collected token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumAmmConfig",
+          "docs": [
+            "The factory state of the pool, used by the swap"
+          ]
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState",
+          "docs": [
+            "The program account for the most recent oracle observation, used by the swap",
+            "and for the TWAP the rebalance is checked against"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftMint",
+          "writable": true
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The current raydium position of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumNewPositionNftMint",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "raydiumNewPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumNewPersonalPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumNewProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumNewTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumNewTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "rent",
+          "docs": [
+            "Sysvar for token mint and ATA creation"
+          ],
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Program to create an ATA for receiving position NFT"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "tickLowerIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickUpperIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickArrayLowerStartIndex",
+          "type": "i32"
+        },
+        {
+          "name": "tickArrayUpperStartIndex",
+          "type": "i32"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        },
+        {
+          "name": "swapAmountIn",
+          "type": "u64"
+        },
+        {
+          "name": "swapAmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "swapZeroForOne",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "redeemClosedVaultStrategy",
+      "discriminator": [
+        20
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "vault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position, created for shares received by transfer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares to burn"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyClosedMint0Escrow",
+          "docs": [
+            "The escrow account that holds the token 0 proceeds of the closed strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  99,
+                  108,
+                  111,
+                  115,
+                  101,
+                  100,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyClosedMint1Escrow",
+          "docs": [
+            "The escrow account that holds the token 1 proceeds of the closed strategy"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  99,
+                  108,
+                  111,
+                  115,
+                  101,
+                  100,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 0, holds the fees still owed to investors"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The fees escrow account for the token 1, holds the fees still owed to investors"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "vault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "redeemRaydiumVaultStrategy",
+      "discriminator": [
+        32
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position, created for shares received by transfer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares to burn"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "docs": [
+            "This account created in the contract by cpi to avoid large stack variables"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "docs": [
+            "The program account of the pool in which the swap will be performed",
+            "Represents the state of the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "Increase liquidity for this position",
+            "Represents the position in the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "shares",
+          "type": "u64"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemVault",
+      "discriminator": [
+        29
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the collected token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the collected token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0, shared by every strategy of the vault"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1, shared by every strategy of the vault"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "percentage",
+          "type": "u64"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeLiquidityRaydiumVaultStrategy",
+      "discriminator": [
+        13
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position, created for shares received by transfer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares to burn"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "docs": [
+            "This account created in the contract by cpi to avoid large stack variables"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "docs": [
+            "The program account of the pool in which the swap will be performed",
+            "Represents the state of the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "Increase liquidity for this position",
+            "Represents the position in the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "percentage",
+          "type": "u64"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setKeeper",
+      "discriminator": [
+        23
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "keeper",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setMaxPriceDeviation",
+      "discriminator": [
+        41
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPriceDeviation",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setOracleFeed",
+      "discriminator": [
+        40
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The mint priced by the oracle feed"
+          ]
+        },
+        {
+          "name": "oracleFeed",
+          "docs": [
+            "Oracle registry entry of the mint, created on first registration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "feedId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "exponent",
+          "type": "i32"
+        },
+        {
+          "name": "maxPriceAge",
+          "type": "u64"
+        },
+        {
+          "name": "maxPriceConf",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setOracleSources",
+      "discriminator": [
+        42
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The mint priced by the oracle feed"
+          ]
+        },
+        {
+          "name": "oracleFeed",
+          "docs": [
+            "Entries registered before the price sources existed are smaller than `OracleFeed`,",
+            "they are grown before being deserialized"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "sources",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "oracleSource"
+              }
+            }
+          }
+        },
+        {
+          "name": "switchboardFeed",
+          "type": "pubkey"
+        },
+        {
+          "name": "twapPool",
+          "type": "pubkey"
+        },
+        {
+          "name": "twapWindow",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setPriceBounds",
+      "discriminator": [
+        39
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPriceAge",
+          "type": "u64"
+        },
+        {
+          "name": "maxPriceConf",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "swapToRatioRaydiumVaultStrategy",
+      "discriminator": [
+        11
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investReserveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  115,
+                  116,
+                  95,
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgUsdcEscrow",
+          "docs": [
+            "The escrow account for the USDC",
+            "Vault strategy Config receives USDC in this account from User's escrow vault"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  102,
+                  103,
+                  95,
+                  117,
+                  115,
+                  100,
+                  99,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0Escrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1Escrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  119,
+                  97,
+                  112,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPoolState",
+          "docs": [
+            "The program account of the pool in which the swap will be performed",
+            "Represents the state of the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "Increase liquidity for this position",
+            "Represents the position in the pool Token 0 / Token 1"
+          ]
+        },
+        {
+          "name": "raydiumAmmConfigUsdcForToken0",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateUsdcForToken0",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumAmmConfigUsdcForToken1",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateUsdcForToken1",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Input",
+          "docs": [
+            "The vault token account for input token",
+            "User sends USDC to this account to swap to token 0",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Input",
+          "docs": [
+            "The vault token account for input token",
+            "User sends USDC to this account to swap to token 1",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Output",
+          "docs": [
+            "The vault token account for output token",
+            "User receives token 0 from this account",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Output",
+          "docs": [
+            "The vault token account for output token",
+            "User receives token 1 from this account",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "raydiumObservationState0",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState1",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "token0AmountOutMin",
+          "type": "u64"
+        },
+        {
+          "name": "token1AmountOutMin",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "unpauseProtocol",
+      "discriminator": [
+        2
+      ],
+      "accounts": [
+        {
+          "name": "adminAuthority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpauseVault",
+      "discriminator": [
+        9
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vaultStrategyConfig"
+          ]
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFromEscrow",
+      "discriminator": [
+        7
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "escrowVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawRaydiumVaultStrategy",
+      "discriminator": [
+        33
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "writable": true
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position, created for shares received by transfer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares to burn"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint0Account",
+          "docs": [
+            "The investor escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "investorMint1Account",
+          "docs": [
+            "The investor escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium LP"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives token 0 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives token 1 in this account from Raydium Swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded,",
+            "the investor receives the part backing the burned shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0",
+            "Protocol Config receives its cut of the token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1",
+            "Protocol Config receives its cut of the token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "docs": [
+            "This account created in the contract by cpi to avoid large stack variables"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "docs": [
+            "The program account of the pool in which the swap will be performed",
+            "Represents the state of the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "Increase liquidity for this position",
+            "Represents the position in the pool Token 0 / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "assets",
+          "type": "u64"
+        },
+        {
+          "name": "maxShares",
+          "type": "u64"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawToUsdcRaydiumVaultStrategy",
+      "discriminator": [
+        28
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  97,
+                  116,
+                  101,
+                  103,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.creator",
+                "account": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vault_strategy_config.name",
+                "account": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              },
+              {
+                "kind": "arg",
+                "path": "strategyId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorStrategyPosition",
+          "docs": [
+            "The investor position, created for shares received by transfer"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  95,
+                  115,
+                  116,
+                  114,
+                  116,
+                  103,
+                  95,
+                  112,
+                  111,
+                  115,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "The share mint of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "investorShareAccount",
+          "docs": [
+            "The investor account holding the shares to burn"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "escrowVault",
+          "docs": [
+            "The investor USDC escrow, default destination of the withdrawn USDC"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorUsdcAccount",
+          "docs": [
+            "Optional destination, the investor USDC account instead of the escrow"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0WithdrawEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the withdrawn token 0 in this account before the swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1WithdrawEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the withdrawn token 1 in this account before the swap"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 0, idle amounts waiting to be compounded,",
+            "the part backing the burned shares is swapped to USDC with the withdrawn tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1RebalanceEscrow",
+          "docs": [
+            "The rebalance escrow account for the token 1, idle amounts waiting to be compounded,",
+            "the part backing the burned shares is swapped to USDC with the withdrawn tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0FeesEscrow",
+          "docs": [
+            "The escrow account for the token 0",
+            "Vault strategy Config receives the collected token 0 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1FeesEscrow",
+          "docs": [
+            "The escrow account for the token 1",
+            "Vault strategy Config receives the collected token 1 fees in this account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint0PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  48,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultStrategyCfgMint1PerfFeesEscrow",
+          "docs": [
+            "The performance fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  108,
+                  116,
+                  95,
+                  112,
+                  101,
+                  114,
+                  102,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  49,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultStrategyConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint0FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 0"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolMint1FeesEscrow",
+          "docs": [
+            "The protocol fees escrow account for the token 1"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault1Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "raydiumClmmProgram",
+          "address": "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"
+        },
+        {
+          "name": "raydiumPositionNftAccount",
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolState",
+          "writable": true
+        },
+        {
+          "name": "raydiumPersonalPosition",
+          "docs": [
+            "The raydium position of the vault strategy"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumProtocolPosition",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayLower",
+          "writable": true
+        },
+        {
+          "name": "raydiumTickArrayUpper",
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault0",
+          "docs": [
+            "The address that holds raydium pool tokens for token_0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumTokenVault1",
+          "docs": [
+            "The address that holds raydium pool tokens for token_1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumAmmConfigToken0ForUsdc",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateToken0ForUsdc",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumAmmConfigToken1ForUsdc",
+          "docs": [
+            "The factory state to read protocol fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumPoolStateToken1ForUsdc",
+          "docs": [
+            "The program account of the pool in which the swap will be performed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Input",
+          "docs": [
+            "The vault token account for input token",
+            "Vault strategy Config sends token 0 to this account to swap to USDC",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Input",
+          "docs": [
+            "The vault token account for input token",
+            "Vault strategy Config sends token 1 to this account to swap to USDC",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Output",
+          "docs": [
+            "The vault token account for output token",
+            "Investor receives USDC from this account",
+            "Pool: USDC / Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault1Output",
+          "docs": [
+            "The vault token account for output token",
+            "Investor receives USDC from this account",
+            "Pool: USDC / Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState0",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 0"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumObservationState1",
+          "docs": [
+            "The program account for the most recent oracle observation",
+            "USDC/Token 1"
+          ],
+          "writable": true
+        },
+        {
+          "name": "raydiumVault0Mint",
+          "docs": [
+            "The mint of token 0"
+          ]
+        },
+        {
+          "name": "raydiumVault1Mint",
+          "docs": [
+            "The mint of token 1"
+          ]
+        },
+        {
+          "name": "token0OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 0"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raydiumVault0Mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "token1OracleFeed",
+          "docs": [
+            "Oracle registry entry of token 1"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  102,
+                  101,
+                  101,
+                  100,
+                  58
                 ]
               },
               {
                 "kind": "account",
-                "path": "usdcMint"
+                "path": "raydiumVault1Mint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "usdcMint"
+          "name": "pythToken0PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_0_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pythToken1PriceUpdate",
+          "docs": [
+            "Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,",
+            "only read when Pyth is a source of `token_1_oracle_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0SwitchboardFeed",
+          "docs": [
+            "`token_0_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1SwitchboardFeed",
+          "docs": [
+            "`token_1_oracle_feed` and checked against its `switchboard_feed`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token0TwapObservationState",
+          "docs": [
+            "Observations of the token 0 TWAP pool"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapPoolState",
+          "docs": [
+            "Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "token1TwapObservationState",
+          "docs": [
+            "Observations of the token 1 TWAP pool"
+          ],
+          "optional": true
         },
         {
           "name": "tokenProgram",
+          "docs": [
+            "SPL program for token transfers"
+          ],
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "tokenProgram2022",
+          "docs": [
+            "SPL program 2022 for token transfers"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
+        {
+          "name": "memoProgram",
+          "docs": [
+            "memo program"
+          ],
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
         {
           "name": "systemProgram",
+          "docs": [
+            "System program"
+          ],
           "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "strategyId",
+          "type": "u8"
+        },
+        {
+          "name": "percentage",
+          "type": "u64"
+        },
+        {
+          "name": "amount0Min",
+          "type": "u64"
+        },
+        {
+          "name": "amount1Min",
+          "type": "u64"
+        },
+        {
+          "name": "usdcAmountOutMin",
           "type": "u64"
         }
       ]
@@ -2746,6 +18116,12 @@ export type TokenizedVaultsProgram = {
         132
       ]
     },
+    {
+      "name": "oracleFeed",
+      "discriminator": [
+        7
+      ]
+    },
     {
       "name": "personalPositionState",
       "discriminator": [
@@ -2818,6 +18194,19 @@ export type TokenizedVaultsProgram = {
     }
   ],
   "events": [
+    {
+      "name": "investReserveCancelledEvent",
+      "discriminator": [
+        80,
+        247,
+        56,
+        180,
+        110,
+        17,
+        132,
+        128
+      ]
+    },
     {
       "name": "investorEscrowDepositEvent",
       "discriminator": [
@@ -2845,42 +18234,211 @@ export type TokenizedVaultsProgram = {
       ]
     },
     {
-      "name": "investorEscrowWithdrawEvent",
+      "name": "investorEscrowWithdrawEvent",
+      "discriminator": [
+        6,
+        163,
+        41,
+        54,
+        203,
+        79,
+        209,
+        208
+      ]
+    },
+    {
+      "name": "investorFeesClaimedEvent",
+      "discriminator": [
+        239,
+        161,
+        33,
+        27,
+        6,
+        201,
+        221,
+        122
+      ]
+    },
+    {
+      "name": "investorStrategyPositionEvent",
+      "discriminator": [
+        218,
+        62,
+        22,
+        58,
+        160,
+        0,
+        52,
+        235
+      ]
+    },
+    {
+      "name": "oracleFallbackEvent",
+      "discriminator": [
+        28,
+        85,
+        229,
+        179,
+        231,
+        164,
+        207,
+        155
+      ]
+    },
+    {
+      "name": "oracleFeedEvent",
+      "discriminator": [
+        40,
+        4,
+        118,
+        52,
+        173,
+        195,
+        16,
+        202
+      ]
+    },
+    {
+      "name": "oracleSourcesEvent",
+      "discriminator": [
+        211,
+        97,
+        130,
+        23,
+        245,
+        147,
+        73,
+        136
+      ]
+    },
+    {
+      "name": "performanceFeesClaimedEvent",
+      "discriminator": [
+        18,
+        47,
+        251,
+        185,
+        198,
+        71,
+        31,
+        128
+      ]
+    },
+    {
+      "name": "poolPriceDeviationEvent",
+      "discriminator": [
+        184,
+        133,
+        214,
+        120,
+        25,
+        51,
+        38,
+        178
+      ]
+    },
+    {
+      "name": "protocolConfigEvent",
+      "discriminator": [
+        192,
+        130,
+        47,
+        255,
+        184,
+        59,
+        76,
+        189
+      ]
+    },
+    {
+      "name": "protocolFeesAccruedEvent",
+      "discriminator": [
+        46,
+        117,
+        127,
+        252,
+        170,
+        131,
+        85,
+        220
+      ]
+    },
+    {
+      "name": "protocolFeesClaimedEvent",
+      "discriminator": [
+        98,
+        136,
+        24,
+        62,
+        181,
+        80,
+        53,
+        127
+      ]
+    },
+    {
+      "name": "protocolPriceBoundsEvent",
+      "discriminator": [
+        97,
+        218,
+        252,
+        27,
+        241,
+        4,
+        68,
+        19
+      ]
+    },
+    {
+      "name": "swapToRatioVaultExecutedEvent",
       "discriminator": [
-        6,
-        163,
-        41,
-        54,
-        203,
-        79,
-        209,
-        208
+        155,
+        179,
+        208,
+        110,
+        40,
+        19,
+        115,
+        58
       ]
     },
     {
-      "name": "investorStrategyPositionEvent",
+      "name": "vaultRedeemEvent",
       "discriminator": [
-        218,
-        62,
-        22,
+        217,
         58,
-        160,
-        0,
-        52,
-        235
+        216,
+        14,
+        209,
+        205,
+        108,
+        26
       ]
     },
     {
-      "name": "protocolConfigEvent",
+      "name": "vaultStrategyClosedEvent",
       "discriminator": [
-        192,
-        130,
-        47,
-        255,
-        184,
-        59,
-        76,
-        189
+        30,
+        27,
+        98,
+        123,
+        125,
+        25,
+        11,
+        87
+      ]
+    },
+    {
+      "name": "vaultStrategyCompoundedEvent",
+      "discriminator": [
+        226,
+        97,
+        206,
+        52,
+        175,
+        18,
+        54,
+        162
       ]
     },
     {
@@ -2896,6 +18454,45 @@ export type TokenizedVaultsProgram = {
         66
       ]
     },
+    {
+      "name": "vaultStrategyConfigKeeperEvent",
+      "discriminator": [
+        106,
+        103,
+        29,
+        11,
+        219,
+        22,
+        71,
+        63
+      ]
+    },
+    {
+      "name": "vaultStrategyConfigPriceDeviationEvent",
+      "discriminator": [
+        61,
+        213,
+        151,
+        0,
+        198,
+        209,
+        110,
+        250
+      ]
+    },
+    {
+      "name": "vaultStrategyFeesAccruedEvent",
+      "discriminator": [
+        133,
+        16,
+        40,
+        199,
+        217,
+        198,
+        135,
+        250
+      ]
+    },
     {
       "name": "vaultStrategyInitializeEvent",
       "discriminator": [
@@ -2909,6 +18506,58 @@ export type TokenizedVaultsProgram = {
         173
       ]
     },
+    {
+      "name": "vaultStrategyNavEvent",
+      "discriminator": [
+        150,
+        37,
+        88,
+        229,
+        19,
+        202,
+        78,
+        142
+      ]
+    },
+    {
+      "name": "vaultStrategyRebalancedEvent",
+      "discriminator": [
+        211,
+        120,
+        126,
+        126,
+        30,
+        194,
+        151,
+        244
+      ]
+    },
+    {
+      "name": "vaultStrategyRedeemEvent",
+      "discriminator": [
+        111,
+        236,
+        91,
+        195,
+        56,
+        48,
+        114,
+        163
+      ]
+    },
+    {
+      "name": "vaultStrategyShareMintEvent",
+      "discriminator": [
+        159,
+        138,
+        208,
+        178,
+        102,
+        72,
+        255,
+        115
+      ]
+    },
     {
       "name": "vaultStrategyUpdateAssetsEvent",
       "discriminator": [
@@ -2979,203 +18628,418 @@ export type TokenizedVaultsProgram = {
     },
     {
       "code": 6008,
+      "name": "protocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6009,
       "name": "vaultStrategyConfigInitialized",
       "msg": "VaultStrategyConfig is already initialized"
     },
     {
-      "code": 6009,
+      "code": 6010,
       "name": "vaultStrategyConfigNotActive",
       "msg": "VaultStrategyConfig is not in active status"
     },
     {
-      "code": 6010,
+      "code": 6011,
       "name": "vaultStrategyConfigNotDraft",
       "msg": "VaultStrategyConfig is not in draft status"
     },
     {
-      "code": 6011,
+      "code": 6012,
       "name": "vaultStrategyConfigNotPaused",
       "msg": "VaultStrategyConfig is not in paused status"
     },
     {
-      "code": 6012,
+      "code": 6013,
+      "name": "vaultStrategyConfigCreatorOnly",
+      "msg": "VaultStrategyConfig is in draft status, only the creator can operate it"
+    },
+    {
+      "code": 6014,
+      "name": "vaultOperationNotAllowed",
+      "msg": "Operation not allowed for the current VaultStrategyConfig status"
+    },
+    {
+      "code": 6015,
       "name": "vaultStrategyConfigMaxStrategiesReached",
       "msg": "VaultStrategyConfig max strategies reached"
     },
     {
-      "code": 6013,
+      "code": 6016,
       "name": "invalidVaultStrategyType",
       "msg": "Invalid vault strategy type"
     },
     {
-      "code": 6014,
+      "code": 6017,
       "name": "invalidVaultStrategyName",
       "msg": "Invalid vault strategy name"
     },
     {
-      "code": 6015,
+      "code": 6018,
       "name": "invalidVaultStrategyPercentage",
       "msg": "Invalid vault strategy percentage"
     },
     {
-      "code": 6016,
+      "code": 6019,
       "name": "performanceFeeTooHigh",
       "msg": "Performance fee cannot exceed 100% (1_000_000)"
     },
     {
-      "code": 6017,
+      "code": 6020,
       "name": "performanceFeeTooLow",
       "msg": "Performance fee cannot be less than 10% (100_000)"
     },
     {
-      "code": 6018,
+      "code": 6021,
       "name": "investorEscrowAlreadyInitialized",
       "msg": "Investor escrow already initialized"
     },
     {
-      "code": 6019,
+      "code": 6022,
       "name": "insufficientFunds",
       "msg": "Insufficient funds in escrow"
     },
     {
-      "code": 6020,
+      "code": 6023,
       "name": "invalidPrice",
       "msg": "Invalid Pyth price feed"
     },
     {
-      "code": 6021,
+      "code": 6024,
       "name": "pythPriceFeedNotFound",
       "msg": "Pyth price feed not found"
     },
     {
-      "code": 6022,
+      "code": 6025,
       "name": "invalidPythFeedId",
       "msg": "Invalid Pyth feed ID"
     },
     {
-      "code": 6023,
+      "code": 6026,
       "name": "mathOverflow",
       "msg": "Math overflow"
     },
     {
-      "code": 6024,
+      "code": 6027,
       "name": "invalidMint",
       "msg": "Invalid mint"
     },
     {
-      "code": 6025,
+      "code": 6028,
       "name": "invalidAmount",
       "msg": "Invalid amount"
     },
     {
-      "code": 6026,
+      "code": 6029,
       "name": "percentageTooHigh",
       "msg": "Percentage cannot exceed 100% (1_000_000)"
     },
     {
-      "code": 6027,
+      "code": 6030,
       "name": "percentageTooLow",
       "msg": "Percentage cannot be less than 10% (100_000)"
     },
     {
-      "code": 6028,
+      "code": 6031,
       "name": "sharesCalculatedToZero",
       "msg": "Shares calculated to zero"
     },
     {
-      "code": 6029,
+      "code": 6032,
       "name": "assetsCalculatedToZero",
       "msg": "Assets calculated to zero"
     },
     {
-      "code": 6030,
+      "code": 6033,
       "name": "insufficientShares",
       "msg": "Insufficient shares"
     },
     {
-      "code": 6031,
+      "code": 6034,
       "name": "invalidTickRange",
       "msg": "Invalid tick range"
     },
     {
-      "code": 6032,
+      "code": 6035,
       "name": "sqrtPriceX64",
       "msg": "sqrt_price_x64 out of range"
     },
     {
-      "code": 6033,
+      "code": 6036,
       "name": "tickUpperOverflow",
       "msg": "The tick must be lesser than, or equal to the maximum tick(443636)"
     },
     {
-      "code": 6034,
+      "code": 6037,
       "name": "invalidTickArray",
       "msg": "Invalid tick array"
     },
     {
-      "code": 6035,
+      "code": 6038,
       "name": "insufficientRemainingAccounts",
       "msg": "Insufficient remaining accounts"
     },
     {
-      "code": 6036,
+      "code": 6039,
       "name": "invalidRemainingAccountsForSwapToRatio",
       "msg": "Invalid remaining accounts for swap to ratio"
     },
     {
-      "code": 6037,
+      "code": 6040,
       "name": "maxSwapToRatioVaultsReached",
       "msg": "Max 3 swap to ratio vaults allowed"
     },
     {
-      "code": 6038,
+      "code": 6041,
       "name": "swapToRatioVaultAlreadyExists",
       "msg": "SwapToRatioVault already exists"
     },
     {
-      "code": 6039,
-      "name": "noSwapToRatioVaultsToClear",
-      "msg": "No SwapToRatioVaults to clear"
+      "code": 6042,
+      "name": "noSwapToRatioVaultsToClear",
+      "msg": "No SwapToRatioVaults to clear"
+    },
+    {
+      "code": 6043,
+      "name": "transferFailed",
+      "msg": "Transfer failed"
+    },
+    {
+      "code": 6044,
+      "name": "invalidStrategyIndex",
+      "msg": "Invalid strategy index"
+    },
+    {
+      "code": 6045,
+      "name": "invalidAllocation",
+      "msg": "Invalid allocation - total does not match reserved amount"
+    },
+    {
+      "code": 6046,
+      "name": "swapToRatioVaultNotFound",
+      "msg": "SwapToRatioVault not found"
+    },
+    {
+      "code": 6047,
+      "name": "investReserveVaultAlreadyInitialized",
+      "msg": "InvestReserveVault already initialized"
+    },
+    {
+      "code": 6048,
+      "name": "vaultStrategyInitialized",
+      "msg": "VaultStrategy is already initialized"
+    },
+    {
+      "code": 6049,
+      "name": "noReservedAmount",
+      "msg": "No reserved amount"
+    },
+    {
+      "code": 6050,
+      "name": "invalidRemovePercentage",
+      "msg": "Invalid remove percentage"
+    },
+    {
+      "code": 6051,
+      "name": "vaultStrategyConfigNoStrategies",
+      "msg": "VaultStrategyConfig has no strategies configured"
+    },
+    {
+      "code": 6052,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6053,
+      "name": "invalidPendingAdmin",
+      "msg": "Invalid pending admin"
+    },
+    {
+      "code": 6054,
+      "name": "noPendingAdmin",
+      "msg": "No pending admin proposal"
+    },
+    {
+      "code": 6055,
+      "name": "vaultStrategyConfigAlreadyClosed",
+      "msg": "VaultStrategyConfig is already closed"
+    },
+    {
+      "code": 6056,
+      "name": "vaultStrategyConfigNotClosed",
+      "msg": "VaultStrategyConfig is not in closed status"
+    },
+    {
+      "code": 6057,
+      "name": "vaultStrategyAlreadyClosed",
+      "msg": "VaultStrategy is already closed"
+    },
+    {
+      "code": 6058,
+      "name": "vaultStrategyNotClosed",
+      "msg": "VaultStrategy is not closed"
+    },
+    {
+      "code": 6059,
+      "name": "vaultStrategyHasOutstandingShares",
+      "msg": "VaultStrategy still has outstanding shares"
+    },
+    {
+      "code": 6060,
+      "name": "invalidRaydiumPosition",
+      "msg": "Raydium position does not belong to the vault strategy"
+    },
+    {
+      "code": 6061,
+      "name": "raydiumPositionInRange",
+      "msg": "Raydium position is still in range"
+    },
+    {
+      "code": 6062,
+      "name": "liquidityCalculatedToZero",
+      "msg": "Liquidity calculated to zero"
+    },
+    {
+      "code": 6063,
+      "name": "vaultStrategyShareMintInitialized",
+      "msg": "VaultStrategy share mint already initialized"
+    },
+    {
+      "code": 6064,
+      "name": "invalidShareMint",
+      "msg": "Invalid vault strategy share mint"
+    },
+    {
+      "code": 6065,
+      "name": "sharesBelowMinimum",
+      "msg": "Minted shares are below the minimum shares out"
+    },
+    {
+      "code": 6066,
+      "name": "amountOutBelowMinimum",
+      "msg": "Amount out is below the minimum amount out"
+    },
+    {
+      "code": 6067,
+      "name": "noFeesToClaim",
+      "msg": "No fees to claim"
+    },
+    {
+      "code": 6068,
+      "name": "sharesBelowDust",
+      "msg": "Shares to burn are below the dust threshold"
+    },
+    {
+      "code": 6069,
+      "name": "remainingSharesBelowDust",
+      "msg": "Shares left in the position are below the dust threshold"
+    },
+    {
+      "code": 6070,
+      "name": "sharesAboveMaximum",
+      "msg": "Shares to burn are above the maximum shares"
+    },
+    {
+      "code": 6071,
+      "name": "missingInvestorStrategyPosition",
+      "msg": "Investor strategy position account is required"
+    },
+    {
+      "code": 6072,
+      "name": "priceTooOld",
+      "msg": "Oracle price is older than the maximum price age"
+    },
+    {
+      "code": 6073,
+      "name": "priceConfidenceTooWide",
+      "msg": "Oracle price confidence interval is too wide"
+    },
+    {
+      "code": 6074,
+      "name": "invalidPriceBounds",
+      "msg": "Invalid price bounds"
+    },
+    {
+      "code": 6075,
+      "name": "invalidOracleFeed",
+      "msg": "Invalid oracle feed"
+    },
+    {
+      "code": 6076,
+      "name": "pythExponentMismatch",
+      "msg": "Pyth price exponent differs from the oracle registry"
+    },
+    {
+      "code": 6077,
+      "name": "poolPriceDeviationTooHigh",
+      "msg": "Pool price deviates too much from the oracle price"
+    },
+    {
+      "code": 6078,
+      "name": "invalidPriceDeviation",
+      "msg": "Invalid maximum price deviation"
+    },
+    {
+      "code": 6079,
+      "name": "insufficientTwapObservations",
+      "msg": "Oracle observations do not cover the TWAP window"
+    },
+    {
+      "code": 6080,
+      "name": "invalidTwapWindow",
+      "msg": "Invalid TWAP window"
+    },
+    {
+      "code": 6081,
+      "name": "invalidOracleSources",
+      "msg": "Invalid oracle sources"
+    },
+    {
+      "code": 6082,
+      "name": "oracleSourceUnavailable",
+      "msg": "Accounts of the oracle source are missing"
     },
     {
-      "code": 6040,
-      "name": "transferFailed",
-      "msg": "Transfer failed"
+      "code": 6083,
+      "name": "invalidOracleAccount",
+      "msg": "Oracle account differs from the oracle registry"
     },
     {
-      "code": 6041,
-      "name": "invalidStrategyIndex",
-      "msg": "Invalid strategy index"
+      "code": 6084,
+      "name": "invalidSwitchboardFeed",
+      "msg": "Invalid Switchboard feed account"
     },
     {
-      "code": 6042,
-      "name": "invalidAllocation",
-      "msg": "Invalid allocation - total does not match reserved amount"
+      "code": 6085,
+      "name": "invalidMigrationAccount",
+      "msg": "Account type cannot be migrated"
     },
     {
-      "code": 6043,
-      "name": "swapToRatioVaultNotFound",
-      "msg": "SwapToRatioVault not found"
+      "code": 6086,
+      "name": "invalidFeesDestination",
+      "msg": "Pass either a fees destination or the creator account"
     },
     {
-      "code": 6044,
-      "name": "investReserveVaultAlreadyInitialized",
-      "msg": "InvestReserveVault already initialized"
+      "code": 6087,
+      "name": "sharesNotMinted",
+      "msg": "Position shares are not minted as share tokens yet"
     },
     {
-      "code": 6045,
-      "name": "vaultStrategyInitialized",
-      "msg": "VaultStrategy is already initialized"
+      "code": 6088,
+      "name": "sharesAlreadyMinted",
+      "msg": "Position shares are already minted as share tokens"
     },
     {
-      "code": 6046,
-      "name": "noReservedAmount",
-      "msg": "No reserved amount"
+      "code": 6089,
+      "name": "invalidRebalanceEscrow",
+      "msg": "Invalid vault strategy rebalance escrow"
     },
     {
-      "code": 6047,
-      "name": "invalidRemovePercentage",
-      "msg": "Invalid remove percentage"
+      "code": 6090,
+      "name": "invalidSwapPool",
+      "msg": "Swap pool does not trade the expected mints"
     }
   ],
   "types": [
@@ -3189,288 +19053,668 @@ export type TokenizedVaultsProgram = {
         "fields": [
           {
             "name": "bump",
-            "docs": [
-              "Bump to identify PDA"
-            ],
+            "docs": [
+              "Bump to identify PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "index",
+            "type": "u16"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Address of the protocol owner"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "protocolFeeRate",
+            "docs": [
+              "The protocol fee"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "tradeFeeRate",
+            "docs": [
+              "The trade fee, denominated in hundredths of a bip (10^-6)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "tickSpacing",
+            "docs": [
+              "The tick spacing"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fundFeeRate",
+            "docs": [
+              "The fund fee, denominated in hundredths of a bip (10^-6)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "paddingU32",
+            "type": "u32"
+          },
+          {
+            "name": "fundOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositPreview",
+      "docs": [
+        "Returned by `preview_deposit`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assets",
+            "docs": [
+              "Value in USDC of the deposited amounts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "docs": [
+              "Shares minted for the deposit"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investReserveCancelledEvent",
+      "docs": [
+        "Emitted when an investor cancels a pending investment"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "usdcAmount",
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investReserveVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "reservedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "swapToRatioVaults",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "swapToRatioVault"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorEscrowDepositEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorEscrowEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorEscrowWithdrawEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorFeesClaimedEvent",
+      "docs": [
+        "Emitted when an investor is paid the fees earned by their shares"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount0",
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorStrategyPosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "assets",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "index",
-            "type": "u16"
-          },
-          {
-            "name": "owner",
+            "name": "feeGrowthCheckpoint0X64",
             "docs": [
-              "Address of the protocol owner"
+              "Fee growth per share of token 0 when the fees owed were last settled, Q64.64"
             ],
-            "type": "pubkey"
+            "type": "u128"
           },
           {
-            "name": "protocolFeeRate",
+            "name": "feeGrowthCheckpoint1X64",
             "docs": [
-              "The protocol fee"
+              "Fee growth per share of token 1 when the fees owed were last settled, Q64.64"
             ],
-            "type": "u32"
+            "type": "u128"
           },
           {
-            "name": "tradeFeeRate",
+            "name": "feesOwed0",
             "docs": [
-              "The trade fee, denominated in hundredths of a bip (10^-6)"
+              "Token 0 fees earned by the shares and not paid out yet"
             ],
-            "type": "u32"
+            "type": "u64"
           },
           {
-            "name": "tickSpacing",
+            "name": "feesOwed1",
             "docs": [
-              "The tick spacing"
+              "Token 1 fees earned by the shares and not paid out yet"
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "fundFeeRate",
+            "name": "sharesMinted",
             "docs": [
-              "The fund fee, denominated in hundredths of a bip (10^-6)"
+              "Whether the shares are held as share tokens, false for the positions opened",
+              "before the strategy had a share mint until `migrate_investor_shares`"
             ],
-            "type": "u32"
-          },
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "investorStrategyPositionEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "paddingU32",
-            "type": "u32"
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "fundOwner",
+            "name": "vaultStrategyKey",
             "type": "pubkey"
           },
           {
-            "name": "padding",
-            "type": {
-              "array": [
-                "u64",
-                3
-              ]
-            }
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "assets",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "investReserveVault",
+      "name": "observation",
+      "docs": [
+        "The element of observations in ObservationState"
+      ],
+      "serialization": "bytemuckunsafe",
+      "repr": {
+        "kind": "c",
+        "packed": true
+      },
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "vaultStrategyConfigKey",
-            "type": "pubkey"
+            "name": "blockTimestamp",
+            "docs": [
+              "The block timestamp of the observation"
+            ],
+            "type": "u32"
           },
           {
-            "name": "reservedAmount",
-            "type": "u64"
+            "name": "sqrtPriceX64",
+            "docs": [
+              "the price of the observation timestamp, Q64.64"
+            ],
+            "type": "u128"
           },
           {
-            "name": "swapToRatioVaults",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "swapToRatioVault"
-                }
-              }
-            }
+            "name": "cumulativeTimePriceX64",
+            "docs": [
+              "the cumulative of price during the duration time, Q64.64"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "padding for feature update"
+            ],
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "investorEscrowDepositEvent",
+      "name": "observationState",
+      "serialization": "bytemuckunsafe",
+      "repr": {
+        "kind": "c",
+        "packed": true
+      },
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "initialized",
+            "docs": [
+              "Whether the ObservationState is initialized"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "poolId",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "observations",
+            "docs": [
+              "observation array"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "observation"
+                  }
+                },
+                1000
+              ]
+            }
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "padding for feature update"
+            ],
+            "type": {
+              "array": [
+                "u128",
+                5
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "investorEscrowEvent",
+      "name": "oracleFallbackEvent",
+      "docs": [
+        "Emitted when a price is read from a fallback source because the primary one failed"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "primarySource",
+            "type": {
+              "defined": {
+                "name": "oracleSource"
+              }
+            }
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": {
+                "name": "oracleSource"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "investorEscrowWithdrawEvent",
+      "name": "oracleFeed",
+      "docs": [
+        "Oracle registry entry of a mint, managed by the admin authority.",
+        "Every USD valuation of the mint resolves its Pyth feed from here"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "feedId",
+            "docs": [
+              "Pyth feed id pricing the mint in USD, zero when Pyth is not a source"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "exponent",
+            "docs": [
+              "Exponent the prices of the mint are expected in"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "maxPriceAge",
+            "docs": [
+              "Maximum age in seconds of the price, 0 uses the protocol config bound"
+            ],
             "type": "u64"
+          },
+          {
+            "name": "maxPriceConf",
+            "docs": [
+              "Maximum confidence interval relative to the price in BPS scale (100% = 1_000_000),",
+              "0 uses the protocol config bound"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sources",
+            "docs": [
+              "Price sources tried in order until one is available and within the bounds,",
+              "`None` ends the list"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "oracleSource"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "switchboardFeed",
+            "docs": [
+              "Switchboard On-Demand pull feed pricing the mint in USD"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "twapPool",
+            "docs": [
+              "Raydium CLMM pool pairing the mint with a USD stablecoin, valued at 1 USD"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "twapWindow",
+            "docs": [
+              "Window of the Raydium TWAP in seconds"
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "investorStrategyPosition",
+      "name": "oracleFeedEvent",
+      "docs": [
+        "Emitted when the admin registers or updates the oracle feed of a mint"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "adminAuthority",
             "type": "pubkey"
           },
           {
-            "name": "vaultStrategyKey",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "shares",
-            "type": "u64"
+            "name": "feedId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "assets",
+            "name": "exponent",
+            "type": "i32"
+          },
+          {
+            "name": "maxPriceAge",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "maxPriceConf",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "investorStrategyPositionEvent",
+      "name": "oracleSource",
+      "docs": [
+        "Where the USD price of a mint is read from"
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "none"
           },
           {
-            "name": "vaultStrategyKey",
-            "type": "pubkey"
+            "name": "pyth"
           },
           {
-            "name": "shares",
-            "type": "u64"
+            "name": "switchboardOnDemand"
           },
           {
-            "name": "assets",
-            "type": "u64"
+            "name": "raydiumTwap"
           }
         ]
       }
     },
     {
-      "name": "observation",
+      "name": "oracleSourcesEvent",
       "docs": [
-        "The element of observations in ObservationState"
+        "Emitted when the admin sets the price sources of a mint"
       ],
-      "serialization": "bytemuckunsafe",
-      "repr": {
-        "kind": "c",
-        "packed": true
-      },
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "blockTimestamp",
-            "docs": [
-              "The block timestamp of the observation"
-            ],
-            "type": "u32"
+            "name": "adminAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "sqrtPriceX64",
-            "docs": [
-              "the price of the observation timestamp, Q64.64"
-            ],
-            "type": "u128"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "cumulativeTimePriceX64",
-            "docs": [
-              "the cumulative of price during the duration time, Q64.64"
-            ],
-            "type": "u128"
+            "name": "sources",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "oracleSource"
+                  }
+                },
+                3
+              ]
+            }
           },
           {
-            "name": "padding",
-            "docs": [
-              "padding for feature update"
-            ],
-            "type": "u128"
+            "name": "switchboardFeed",
+            "type": "pubkey"
+          },
+          {
+            "name": "twapPool",
+            "type": "pubkey"
+          },
+          {
+            "name": "twapWindow",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "observationState",
-      "serialization": "bytemuckunsafe",
-      "repr": {
-        "kind": "c",
-        "packed": true
-      },
+      "name": "performanceFeesClaimedEvent",
+      "docs": [
+        "Emitted when the creator claims performance fees, once per mint"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "initialized",
-            "docs": [
-              "Whether the ObservationState is initialized"
-            ],
-            "type": "bool"
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
           },
           {
-            "name": "poolId",
+            "name": "creator",
             "type": "pubkey"
           },
           {
-            "name": "observations",
-            "docs": [
-              "observation array"
-            ],
-            "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "observation"
-                  }
-                },
-                1000
-              ]
-            }
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "padding",
-            "docs": [
-              "padding for feature update"
-            ],
-            "type": {
-              "array": [
-                "u128",
-                5
-              ]
-            }
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
@@ -3575,6 +19819,33 @@ export type TokenizedVaultsProgram = {
         ]
       }
     },
+    {
+      "name": "poolPriceDeviationEvent",
+      "docs": [
+        "Emitted when an operation passes the pool price deviation check"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "sqrtPriceX64",
+            "type": "u128"
+          },
+          {
+            "name": "deviation",
+            "type": "u32"
+          },
+          {
+            "name": "maxPriceDeviation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "poolState",
       "docs": [
@@ -3852,6 +20123,56 @@ export type TokenizedVaultsProgram = {
         ]
       }
     },
+    {
+      "name": "positionValue",
+      "docs": [
+        "Returned by `get_position_value`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "assets",
+            "docs": [
+              "Value in USDC of the shares"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "docs": [
+              "Token 0 backing the shares, position and idle"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "docs": [
+              "Token 1 backing the shares, position and idle"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fees0",
+            "docs": [
+              "Token 0 fees owed to the position, including the fees not collected from Raydium yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fees1",
+            "docs": [
+              "Token 1 fees owed to the position, including the fees not collected from Raydium yet"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "priceFeedMessage",
       "repr": {
@@ -3984,6 +20305,24 @@ export type TokenizedVaultsProgram = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPriceAge",
+            "docs": [
+              "Maximum age in seconds of the Pyth prices used for valuations"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceConf",
+            "docs": [
+              "Maximum Pyth confidence interval relative to the price, in BPS scale (100% = 1_000_000)"
+            ],
+            "type": "u32"
           }
         ]
       }
@@ -4000,6 +20339,10 @@ export type TokenizedVaultsProgram = {
             "name": "adminAuthority",
             "type": "pubkey"
           },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
           {
             "name": "protocolFees",
             "type": "u32"
@@ -4016,18 +20359,134 @@ export type TokenizedVaultsProgram = {
       }
     },
     {
-      "name": "protocolStatus",
+      "name": "protocolFeesAccruedEvent",
+      "docs": [
+        "Emitted when protocol fees are moved into a protocol fees escrow"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolFeesClaimedEvent",
+      "docs": [
+        "Emitted when the admin claims protocol fees from a protocol fees escrow"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolPriceBoundsEvent",
+      "docs": [
+        "Emitted when the admin updates the Pyth price bounds"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPriceAge",
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceConf",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "unknown"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "paused"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redeemPreview",
+      "docs": [
+        "Returned by `preview_redeem`"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "unknown"
+            "name": "assets",
+            "docs": [
+              "Value in USDC of the shares"
+            ],
+            "type": "u64"
           },
           {
-            "name": "active"
+            "name": "liquidity",
+            "docs": [
+              "Raydium liquidity removed for the shares"
+            ],
+            "type": "u128"
           },
           {
-            "name": "paused"
+            "name": "amount0",
+            "docs": [
+              "Token 0 returned by the liquidity removal and from the rebalance escrow"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "docs": [
+              "Token 1 returned by the liquidity removal and from the rebalance escrow"
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -4138,10 +20597,16 @@ export type TokenizedVaultsProgram = {
           },
           {
             "name": "token0Amount",
+            "docs": [
+              "Token 0 swapped, replaced by the amount added as liquidity once executed"
+            ],
             "type": "u64"
           },
           {
             "name": "token1Amount",
+            "docs": [
+              "Token 1 swapped, replaced by the amount added as liquidity once executed"
+            ],
             "type": "u64"
           },
           {
@@ -4151,6 +20616,45 @@ export type TokenizedVaultsProgram = {
         ]
       }
     },
+    {
+      "name": "swapToRatioVaultExecutedEvent",
+      "docs": [
+        "Emitted when the swapped amounts of a strategy are added as liquidity"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "type": "u64"
+          },
+          {
+            "name": "refund0",
+            "type": "u64"
+          },
+          {
+            "name": "refund1",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "tickArrayState",
       "serialization": "bytemuckunsafe",
@@ -4222,45 +20726,259 @@ export type TokenizedVaultsProgram = {
           {
             "name": "liquidityGross",
             "docs": [
-              "The total position liquidity that references this tick"
+              "The total position liquidity that references this tick"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "feeGrowthOutside0X64",
+            "docs": [
+              "Fee growth per unit of liquidity on the _other_ side of this tick (relative to the current tick)",
+              "only has relative meaning, not absolute — the value depends on when the tick is initialized"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "feeGrowthOutside1X64",
+            "type": "u128"
+          },
+          {
+            "name": "rewardGrowthsOutsideX64",
+            "type": {
+              "array": [
+                "u128",
+                3
+              ]
+            }
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u32",
+                13
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultNav",
+      "docs": [
+        "Returned by `get_vault_nav`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nav",
+            "docs": [
+              "Mark-to-market value of the strategy in USDC"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalShares",
+            "type": "u64"
+          },
+          {
+            "name": "sharePrice",
+            "docs": [
+              "Value in USDC of one whole share"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "docs": [
+              "Token 0 backing the shares, position and idle"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "docs": [
+              "Token 1 backing the shares, position and idle"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultRedeemEvent",
+      "docs": [
+        "Emitted once when an investor redeems every strategy of the vault"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "percentage",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "type": "u64"
+          },
+          {
+            "name": "strategies",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "lookUpTable",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "dexNftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint0",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint1",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAssets",
+            "docs": [
+              "Mark-to-market value of the strategy in USDC, refreshed on every deposit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalShares",
+            "type": "u64"
+          },
+          {
+            "name": "percentage",
+            "type": "u32"
+          },
+          {
+            "name": "strategyId",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "shareMint",
+            "docs": [
+              "SPL mint of the strategy shares, created with the strategy"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "priceFeedId0",
+            "docs": [
+              "Pyth feed id of token 0 in the oracle registry when the strategy was created"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "priceFeedId1",
+            "docs": [
+              "Pyth feed id of token 1 in the oracle registry when the strategy was created"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "feeGrowthPerShare0X64",
+            "docs": [
+              "Investor fees of token 0 earned per share since the strategy was created, Q64.64"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "feeGrowthPerShare1X64",
+            "docs": [
+              "Investor fees of token 1 earned per share since the strategy was created, Q64.64"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "closedAmount0",
+            "docs": [
+              "Token 0 left to be redeemed by investors once the strategy is closed"
             ],
-            "type": "u128"
+            "type": "u64"
           },
           {
-            "name": "feeGrowthOutside0X64",
+            "name": "closedAmount1",
             "docs": [
-              "Fee growth per unit of liquidity on the _other_ side of this tick (relative to the current tick)",
-              "only has relative meaning, not absolute — the value depends on when the tick is initialized"
+              "Token 1 left to be redeemed by investors once the strategy is closed"
             ],
-            "type": "u128"
+            "type": "u64"
           },
           {
-            "name": "feeGrowthOutside1X64",
-            "type": "u128"
+            "name": "isClosed",
+            "type": "bool"
           },
           {
-            "name": "rewardGrowthsOutsideX64",
-            "type": {
-              "array": [
-                "u128",
-                3
-              ]
-            }
+            "name": "tickLowerIndex",
+            "docs": [
+              "Lower tick of the current Raydium position"
+            ],
+            "type": "i32"
           },
           {
-            "name": "padding",
-            "type": {
-              "array": [
-                "u32",
-                13
-              ]
-            }
+            "name": "tickUpperIndex",
+            "docs": [
+              "Upper tick of the current Raydium position"
+            ],
+            "type": "i32"
           }
         ]
       }
     },
     {
-      "name": "vaultStrategy",
+      "name": "vaultStrategyClosedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -4269,44 +20987,52 @@ export type TokenizedVaultsProgram = {
             "type": "pubkey"
           },
           {
-            "name": "lookUpTable",
+            "name": "vaultStrategyConfigKey",
             "type": "pubkey"
           },
           {
-            "name": "vaultStrategyConfigKey",
-            "type": "pubkey"
+            "name": "amount0",
+            "type": "u64"
           },
           {
-            "name": "dexNftMint",
-            "type": "pubkey"
+            "name": "amount1",
+            "type": "u64"
           },
           {
-            "name": "mint0",
-            "type": "pubkey"
+            "name": "totalShares",
+            "type": "u64"
           },
           {
-            "name": "mint1",
+            "name": "strategyId",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyCompoundedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultStrategyConfigKey",
             "type": "pubkey"
           },
           {
-            "name": "totalAssets",
-            "type": "u64"
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
           },
           {
-            "name": "totalShares",
+            "name": "fees0",
             "type": "u64"
           },
           {
-            "name": "percentage",
-            "type": "u32"
-          },
-          {
-            "name": "strategyId",
-            "type": "u8"
+            "name": "fees1",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "liquidity",
+            "type": "u128"
           }
         ]
       }
@@ -4359,6 +21085,21 @@ export type TokenizedVaultsProgram = {
           {
             "name": "name",
             "type": "string"
+          },
+          {
+            "name": "keeper",
+            "docs": [
+              "Optional operator allowed to rebalance the strategies, Pubkey::default() when unset"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPriceDeviation",
+            "docs": [
+              "Maximum deviation of the pool price from the Pyth price allowed to deposit or withdraw,",
+              "in BPS scale (100% = 1_000_000), 0 disables the check"
+            ],
+            "type": "u32"
           }
         ]
       }
@@ -4398,6 +21139,80 @@ export type TokenizedVaultsProgram = {
         ]
       }
     },
+    {
+      "name": "vaultStrategyConfigKeeperEvent",
+      "docs": [
+        "Emitted when the creator updates the keeper"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "keeper",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyConfigPriceDeviationEvent",
+      "docs": [
+        "Emitted when the creator updates the maximum pool price deviation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPriceDeviation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyFeesAccruedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "fees0",
+            "type": "u64"
+          },
+          {
+            "name": "fees1",
+            "type": "u64"
+          },
+          {
+            "name": "feeGrowthPerShare0X64",
+            "type": "u128"
+          },
+          {
+            "name": "feeGrowthPerShare1X64",
+            "type": "u128"
+          },
+          {
+            "name": "strategyId",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "vaultStrategyInitializeEvent",
       "type": {
@@ -4442,6 +21257,125 @@ export type TokenizedVaultsProgram = {
         ]
       }
     },
+    {
+      "name": "vaultStrategyNavEvent",
+      "docs": [
+        "Emitted on deposit with the NAV the new shares were priced against"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "nav",
+            "type": "u64"
+          },
+          {
+            "name": "totalShares",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyRebalancedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousDexNftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "dexNftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tickLowerIndex",
+            "type": "i32"
+          },
+          {
+            "name": "tickUpperIndex",
+            "type": "i32"
+          },
+          {
+            "name": "liquidity",
+            "type": "u128"
+          },
+          {
+            "name": "strategyId",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyRedeemEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "investor",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "amount0",
+            "type": "u64"
+          },
+          {
+            "name": "amount1",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultStrategyShareMintEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultStrategyConfigKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "shareMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "strategyId",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "vaultStrategyStatus",
       "type": {
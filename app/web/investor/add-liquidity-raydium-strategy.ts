@@ -16,7 +16,16 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { createLookUpTable } from "../../utils";
-import { CLMM_PROGRAM_ID } from "../../constants";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  swapAccounts,
+} from "../raydium-strategy";
+import {
+  CLMM_PROGRAM_ID,
+  PYTH_SOL_USD_FEED_ACCOUNT,
+  PYTH_USDC_USD_FEED_ACCOUNT,
+} from "../../constants";
 
 export type AddLiquidityRaydiumStrategyParams = {
   provider: anchor.AnchorProvider;
@@ -24,6 +33,11 @@ export type AddLiquidityRaydiumStrategyParams = {
   vaultStrategyPda: PublicKey;
   strategyConfigName: string;
   amount: number;
+  // When given, the remainder of each leg is swapped back to USDC through these
+  // Token / USDC pools instead of being refunded in tokens
+  refundSwapPools?: { token0: PublicKey; token1: PublicKey };
+  refund0UsdcAmountOutMin?: BN;
+  refund1UsdcAmountOutMin?: BN;
 };
 
 export const addLiquidityRaydiumStrategyTx = async ({
@@ -32,6 +46,9 @@ export const addLiquidityRaydiumStrategyTx = async ({
   vaultStrategyPda,
   strategyConfigName,
   amount,
+  refundSwapPools,
+  refund0UsdcAmountOutMin = new BN(0),
+  refund1UsdcAmountOutMin = new BN(0),
 }: AddLiquidityRaydiumStrategyParams) => {
   const ammConfig = raydiumAmmConfig();
   const program = getProgram(provider);
@@ -114,6 +131,33 @@ export const addLiquidityRaydiumStrategyTx = async ({
     USDC,
   });
 
+  // amm_config, pool_state, input_vault, output_vault, observation_state and tick arrays,
+  // the remainders are small so the tick arrays of a small swap are enough
+  const refundSwapGroup = async (poolState: PublicKey, inputMint: PublicKey) => {
+    const { ammConfig, inputVault, outputVault, observationState, tickArrays } =
+      await swapAccounts({
+        raydium,
+        poolState,
+        inputMint,
+        amountIn: new BN(amountToAdd / 100),
+      });
+    return [
+      ammConfig,
+      poolState,
+      inputVault,
+      outputVault,
+      observationState,
+      ...tickArrays,
+    ];
+  };
+
+  const refundSwapAccounts0 = refundSwapPools
+    ? await refundSwapGroup(refundSwapPools.token0, mint0)
+    : [];
+  const refundSwapAccounts1 = refundSwapPools
+    ? await refundSwapGroup(refundSwapPools.token1, mint1)
+    : [];
+
   const { lookupTableAccount } = await createLookUpTable({
     connection: provider.connection as any,
     payer: _masterWallet,
@@ -133,6 +177,8 @@ export const addLiquidityRaydiumStrategyTx = async ({
       raydiumObservationState1,
       ...remainingAccountsA,
       ...remainingAccountsB,
+      ...refundSwapAccounts0,
+      ...refundSwapAccounts1,
     ],
   });
 
@@ -185,6 +231,8 @@ export const addLiquidityRaydiumStrategyTx = async ({
       raydiumObservationState1: raydiumObservationState1,
       raydiumTokenVault0: openPositionTokenVault0,
       raydiumTokenVault1: openPositionTokenVault1,
+      pythToken0PriceUpdate: PYTH_SOL_USD_FEED_ACCOUNT,
+      pythToken1PriceUpdate: PYTH_USDC_USD_FEED_ACCOUNT,
     })
     // .signers([investor])
     .remainingAccounts([
@@ -207,7 +255,11 @@ export const addLiquidityRaydiumStrategyTx = async ({
     .instruction();
 
   const addLiquidityIx = await program.methods
-    .addLiquidityRaydiumVaultStrategy(vaultStrategyAccount.strategyId)
+    .addLiquidityRaydiumVaultStrategy(
+      vaultStrategyAccount.strategyId,
+      refund0UsdcAmountOutMin,
+      refund1UsdcAmountOutMin
+    )
     .accounts({
       investor,
       vaultStrategyConfig: vaultStrategyConfigPda,
@@ -221,6 +273,10 @@ export const addLiquidityRaydiumStrategyTx = async ({
       raydiumTokenVault1: openPositionTokenVault1,
       raydiumVault0Mint: mint0,
       raydiumVault1Mint: mint1,
+      shareMint: vaultStrategyAccount.shareMint,
+      usdcMint: USDC,
+      pythToken0PriceUpdate: PYTH_SOL_USD_FEED_ACCOUNT,
+      pythToken1PriceUpdate: PYTH_USDC_USD_FEED_ACCOUNT,
     })
     // .signers([investor])
     .remainingAccounts([
@@ -229,6 +285,14 @@ export const addLiquidityRaydiumStrategyTx = async ({
         isSigner: false,
         isWritable: true,
       },
+      ...(refundSwapPools
+        ? [
+            SEPARATOR_ACCOUNT,
+            ...accountMetas(refundSwapAccounts0),
+            SEPARATOR_ACCOUNT,
+            ...accountMetas(refundSwapAccounts1),
+          ]
+        : []),
     ])
     .instruction();
 
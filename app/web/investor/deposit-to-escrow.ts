@@ -1,6 +1,6 @@
 import { PublicKey } from "@solana/web3.js";
 import * as anchor from "@coral-xyz/anchor";
-import { getProgram } from "../program";
+import { getProgram, getProgramId } from "../program";
 import { getTokens } from "../tokens";
 import { BN } from "@coral-xyz/anchor";
 
@@ -18,10 +18,16 @@ export const depositToEscrowTx = async ({
   const program = getProgram(provider);
   const { USDC } = await getTokens();
 
+  const [protocolConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config:")],
+    getProgramId()
+  );
+
   const tx = await program.methods
     .depositToEscrow(new BN(amount * 1e6))
-    .accounts({
+    .accountsPartial({
       investor,
+      protocolConfig,
       usdcMint: USDC,
     })
     .transaction();
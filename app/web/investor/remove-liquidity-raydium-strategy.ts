@@ -21,7 +21,11 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { createLookUpTable } from "../../utils";
-import { CLMM_PROGRAM_ID } from "../../constants";
+import {
+  CLMM_PROGRAM_ID,
+  PYTH_SOL_USD_FEED_ACCOUNT,
+  PYTH_USDC_USD_FEED_ACCOUNT,
+} from "../../constants";
 
 export type RemoveLiquidityRaydiumStrategyParams = {
   provider: anchor.AnchorProvider;
@@ -152,6 +156,9 @@ export const removeLiquidityRaydiumStrategyTx = async ({
       raydiumTokenVault1: openPositionTokenVault1,
       raydiumVault0Mint: mint0,
       raydiumVault1Mint: mint1,
+      shareMint: vaultStrategyAccount.shareMint,
+      pythToken0PriceUpdate: PYTH_SOL_USD_FEED_ACCOUNT,
+      pythToken1PriceUpdate: PYTH_USDC_USD_FEED_ACCOUNT,
     })
     // .signers([investor])
     .remainingAccounts([
@@ -0,0 +1,274 @@
+import {
+  AccountMeta,
+  ComputeBudgetProgram,
+  PublicKey,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { PoolUtils, Raydium } from "@raydium-io/raydium-sdk-v2";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { getProgram } from "./program";
+import { getTokens } from "./tokens";
+import { _masterWallet, initSdkWithoutOwner } from "../config";
+import { raydiumPDAs, raydiumAmmConfig } from "../raydium-helpers";
+import { protocolPDAs } from "../protocol-pdas";
+import { createLookUpTable } from "../utils";
+import {
+  CLMM_PROGRAM_ID,
+  PYTH_SOL_USD_FEED_ACCOUNT,
+  PYTH_USDC_USD_FEED_ACCOUNT,
+} from "../constants";
+
+// Separates the groups of remaining accounts, see the program instructions
+export const SEPARATOR_ACCOUNT: AccountMeta = {
+  pubkey: PublicKey.default,
+  isSigner: false,
+  isWritable: false,
+};
+
+export const accountMetas = (
+  pubkeys: PublicKey[],
+  isWritable = true
+): AccountMeta[] =>
+  pubkeys.map((pubkey) => ({ pubkey, isSigner: false, isWritable }));
+
+export type RaydiumStrategyAccountsParams = {
+  provider: anchor.AnchorProvider;
+  investor: PublicKey;
+  vaultStrategyPda: PublicKey;
+  strategyConfigName: string;
+};
+
+// Accounts of a vault strategy and of its Raydium position, read from the chain
+export const raydiumStrategyAccounts = async ({
+  provider,
+  investor,
+  vaultStrategyPda,
+  strategyConfigName,
+}: RaydiumStrategyAccountsParams) => {
+  const ammConfig = raydiumAmmConfig();
+  const program = getProgram(provider);
+  const raydium = await initSdkWithoutOwner({ loadToken: true });
+  const { USDC, token0, token1 } = getTokens();
+
+  const vaultStrategyAccount =
+    await program.account.vaultStrategy.fetch(vaultStrategyPda);
+  const vaultStrategyConfigPda = vaultStrategyAccount.vaultStrategyConfigKey;
+  const vaultStrategyConfigAccount =
+    await program.account.vaultStrategyConfig.fetch(vaultStrategyConfigPda);
+
+  // The position moves with every rebalance, its ticks are read from the strategy
+  const raydiumAccounts = await raydiumPDAs({
+    ammConfig,
+    raydium,
+    nft: vaultStrategyAccount.dexNftMint,
+    tickLower: vaultStrategyAccount.tickLowerIndex,
+    tickUpper: vaultStrategyAccount.tickUpperIndex,
+    mint0: token0,
+    mint1: token1,
+  });
+  const { mint0, mint1 } = raydiumAccounts;
+
+  const protocolAccounts = protocolPDAs({
+    strategyCreator: vaultStrategyConfigAccount.creator,
+    investor,
+    strategyConfigName,
+    strategyId: vaultStrategyAccount.strategyId,
+    mint0,
+    mint1,
+  });
+
+  const positionNftAccount = getAssociatedTokenAddressSync(
+    vaultStrategyAccount.dexNftMint,
+    vaultStrategyConfigPda,
+    true, // allowOwnerOffCurve
+    TOKEN_2022_PROGRAM_ID // Only using if using OpenPositionWithToken22Nft
+  );
+
+  const investorShareAccount = getAssociatedTokenAddressSync(
+    vaultStrategyAccount.shareMint,
+    investor,
+    false,
+    TOKEN_PROGRAM_ID
+  );
+
+  const { lookupTableAccount } = await createLookUpTable({
+    connection: provider.connection as any,
+    payer: _masterWallet,
+    authority: _masterWallet,
+    reuseTable: vaultStrategyAccount.lookUpTable,
+    addresses: [],
+  });
+
+  // Strategy position accounts, shared by every instruction of the position
+  const positionAccounts = {
+    vaultStrategyConfig: vaultStrategyConfigPda,
+    raydiumPositionNftAccount: positionNftAccount,
+    raydiumPoolState: raydiumAccounts.poolStateMint0WithMint1,
+    raydiumPersonalPosition: raydiumAccounts.personalPosition,
+    raydiumProtocolPosition: raydiumAccounts.protocolPosition,
+    raydiumTickArrayLower: raydiumAccounts.tickLowerArrayAddress,
+    raydiumTickArrayUpper: raydiumAccounts.tickUpperArrayAddress,
+    raydiumTokenVault0: raydiumAccounts.openPositionTokenVault0,
+    raydiumTokenVault1: raydiumAccounts.openPositionTokenVault1,
+    raydiumVault0Mint: mint0,
+    raydiumVault1Mint: mint1,
+  };
+
+  // Pyth price update accounts of the strategy tokens
+  const oracleAccounts = {
+    pythToken0PriceUpdate: PYTH_SOL_USD_FEED_ACCOUNT,
+    pythToken1PriceUpdate: PYTH_USDC_USD_FEED_ACCOUNT,
+  };
+
+  return {
+    program,
+    raydium,
+    ammConfig,
+    USDC,
+    mint0,
+    mint1,
+    vaultStrategyAccount,
+    vaultStrategyConfigPda,
+    vaultStrategyConfigAccount,
+    raydiumAccounts,
+    protocolAccounts,
+    positionNftAccount,
+    investorShareAccount,
+    lookupTableAccount,
+    positionAccounts,
+    oracleAccounts,
+  };
+};
+
+export type SwapAccountsParams = {
+  raydium: Raydium;
+  poolState: PublicKey;
+  inputMint: PublicKey;
+  amountIn: BN;
+};
+
+// Accounts of a swap of `amountIn` of `inputMint` in a Raydium CLMM pool:
+// the vaults and observation state of the pool and the tick arrays crossed by the swap
+export const swapAccounts = async ({
+  raydium,
+  poolState,
+  inputMint,
+  amountIn,
+}: SwapAccountsParams) => {
+  const data = await raydium.clmm.getPoolInfoFromRpc(poolState.toBase58());
+  const baseIn = data.poolInfo.mintA.address === inputMint.toBase58();
+  const outputMint = new PublicKey(
+    data.poolInfo[baseIn ? "mintB" : "mintA"].address
+  );
+
+  const { remainingAccounts: tickArrays } =
+    await PoolUtils.computeAmountOutFormat({
+      poolInfo: data.computePoolInfo,
+      tickArrayCache: data.tickData[poolState.toBase58()],
+      amountIn,
+      tokenOut: data.poolInfo[baseIn ? "mintB" : "mintA"],
+      slippage: 0,
+      epochInfo: await raydium.fetchEpochInfo(),
+    });
+
+  const [inputVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_vault"), poolState.toBuffer(), inputMint.toBuffer()],
+    new PublicKey(CLMM_PROGRAM_ID)
+  );
+  const [outputVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_vault"), poolState.toBuffer(), outputMint.toBuffer()],
+    new PublicKey(CLMM_PROGRAM_ID)
+  );
+
+  return {
+    ammConfig: new PublicKey(data.poolInfo.config.id),
+    inputVault,
+    outputVault,
+    observationState: data.computePoolInfo.observationId,
+    tickArrays,
+  };
+};
+
+export type BuildTxParams = {
+  provider: anchor.AnchorProvider;
+  payer: PublicKey;
+  instructions: TransactionInstruction[];
+  lookupTables?: anchor.web3.AddressLookupTableAccount[];
+  computeUnits?: number;
+};
+
+// Addresses added to a lookup table by one transaction
+const LOOKUP_TABLE_CHUNK_SIZE = 20;
+
+// Builds a v0 transaction of the instructions, the accounts missing from
+// `lookupTables` are put in new lookup tables so that the instructions
+// with many accounts fit in a transaction
+export const buildV0Tx = async ({
+  provider,
+  payer,
+  instructions,
+  lookupTables = [],
+  computeUnits = 600_000,
+}: BuildTxParams) => {
+  const tableAddresses = new Set(
+    lookupTables.flatMap((table) =>
+      table.state.addresses.map((address) => address.toBase58())
+    )
+  );
+  const programIds = new Set(
+    instructions.map((instruction) => instruction.programId.toBase58())
+  );
+  const missingAddresses = [
+    ...new Set(
+      instructions.flatMap((instruction) =>
+        instruction.keys
+          .filter((key) => !key.isSigner)
+          .map((key) => key.pubkey.toBase58())
+      )
+    ),
+  ]
+    .filter(
+      (address) =>
+        !tableAddresses.has(address) &&
+        !programIds.has(address) &&
+        address !== PublicKey.default.toBase58()
+    )
+    .map((address) => new PublicKey(address));
+
+  const newLookupTables: anchor.web3.AddressLookupTableAccount[] = [];
+  for (
+    let i = 0;
+    i < missingAddresses.length;
+    i += LOOKUP_TABLE_CHUNK_SIZE
+  ) {
+    const { lookupTableAccount } = await createLookUpTable({
+      connection: provider.connection as any,
+      payer: _masterWallet,
+      authority: _masterWallet,
+      addresses: missingAddresses.slice(i, i + LOOKUP_TABLE_CHUNK_SIZE),
+    });
+    newLookupTables.push(lookupTableAccount);
+  }
+
+  const latestBlockhash = await provider.connection.getLatestBlockhash();
+
+  const txMessage = new anchor.web3.TransactionMessage({
+    payerKey: payer,
+    recentBlockhash: latestBlockhash.blockhash,
+    instructions: [
+      ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 100 }),
+      ComputeBudgetProgram.setComputeUnitLimit({ units: computeUnits }),
+      ...instructions,
+    ],
+  }).compileToV0Message([...lookupTables, ...newLookupTables]);
+
+  const tx = new anchor.web3.VersionedTransaction(txMessage);
+  console.log("Transaction raw size", tx.serialize().length);
+  return tx;
+};
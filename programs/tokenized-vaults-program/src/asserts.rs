@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::require;
 
 use crate::{
-    error::TokenizedVaultsErrorCode, VaultStrategyType, MAX_PERCENTAGE, MAX_PERFORMANCE_FEE,
-    MIN_PERCENTAGE, MIN_PERFORMANCE_FEE,
+    error::TokenizedVaultsErrorCode, ProtocolStatus, VaultStrategyType, MAX_PERCENTAGE,
+    MAX_PERFORMANCE_FEE, MIN_PERCENTAGE, MIN_PERFORMANCE_FEE,
 };
 
 pub fn assert_vault_strategy_name(name: &str) -> Result<()> {
@@ -48,3 +48,46 @@ pub fn assert_vault_strategy_type(vault_strategy_type: &VaultStrategyType) -> Re
         VaultStrategyType::Aggressive => Ok(()),
     }
 }
+
+/// Vault closing instructions can be signed by the vault creator or the protocol admin
+pub fn assert_creator_or_admin(
+    authority: &Pubkey,
+    creator: &Pubkey,
+    admin_authority: &Pubkey,
+) -> Result<()> {
+    require!(
+        authority == creator || authority == admin_authority,
+        TokenizedVaultsErrorCode::Unauthorized
+    );
+    Ok(())
+}
+
+/// Protocol pause policy: new risk is blocked, withdrawals stay open.
+///
+/// Every instruction that brings funds into the protocol or creates new exposure
+/// must call this check: `init_vault_strategy_config`, `activate_vault_strategy_config`,
+/// `create_raydium_vault_strategy`, `deposit_to_escrow`, `invest_reserve`,
+/// `swap_to_ratio_raydium_vault_strategy`, `add_liquidity_raydium_vault_strategy`,
+/// `deposit_raydium_vault` and `deposit_tokens_raydium_vault`.
+///
+/// Exceptions that keep working while the protocol is paused:
+/// - `withdraw_from_escrow` and `cancel_invest_reserve`
+/// - `remove_liquidity_raydium_vault_strategy`, `redeem_raydium_vault_strategy`,
+///   `withdraw_raydium_vault_strategy`, `withdraw_to_usdc_raydium_vault_strategy`
+///   and `redeem_vault`
+/// - `claim_investor_fees`
+/// - `close_vault`, `close_raydium_vault_strategy`, `redeem_closed_vault_strategy`
+///   and `close_raydium_position`
+/// - `claim_performance_fees`
+/// - `claim_protocol_fees` and the admin instructions
+/// - the read-only views `get_vault_nav`, `preview_deposit`, `preview_redeem`
+///   and `get_position_value`
+pub fn assert_protocol_active(status: &ProtocolStatus) -> Result<()> {
+    match status {
+        ProtocolStatus::Active => Ok(()),
+        ProtocolStatus::Paused => Err(TokenizedVaultsErrorCode::ProtocolPaused.into()),
+        ProtocolStatus::Unknown => {
+            Err(TokenizedVaultsErrorCode::ProtocolConfigNotInitialized.into())
+        }
+    }
+}
@@ -42,6 +42,24 @@ pub const MAX_PERCENTAGE: u32 = 100u32 * BPS; // 1_000_000
 /// Min performance fee: 10%
 pub const MIN_PERCENTAGE: u32 = 10u32 * BPS; // 100_000
 
+/// Max slippage of the compound swap against the pool TWAP: 1%
+pub const MAX_COMPOUND_SLIPPAGE: u32 = BPS; // 10_000
+
+/// Window of the strategy pool TWAP checked by the compound and the rebalance: 5 minutes
+pub const STRATEGY_POOL_TWAP_WINDOW: u32 = 300;
+
+/// Default maximum age of a Pyth price: ten minutes
+pub const DEFAULT_MAX_PRICE_AGE: u64 = 600;
+
+/// Default maximum Pyth confidence interval relative to the price: 2%
+pub const DEFAULT_MAX_PRICE_CONF: u32 = 2u32 * BPS; // 20_000
+
+/// Default maximum deviation of the pool price from the Pyth price: 2%
+pub const DEFAULT_MAX_PRICE_DEVIATION: u32 = 2u32 * BPS; // 20_000
+
+/// Maximum number of price sources of a mint, fallbacks included
+pub const MAX_ORACLE_SOURCES: usize = 3;
+
 pub const DISC_INIT_PROTOCOL_CONFIG_IX: &[u8] = &[0];
 pub const DISC_PAUSE_PROTOCOL_IX: &[u8] = &[1];
 pub const DISC_UNPAUSE_PROTOCOL_IX: &[u8] = &[2];
@@ -56,6 +74,37 @@ pub const DISC_INVEST_RESERVE_IX: &[u8] = &[10];
 pub const DISC_SWAP_TO_RATIO_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[11];
 pub const DISC_ADD_LIQUIDITY_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[12];
 pub const DISC_REMOVE_LIQUIDITY_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[13];
+pub const DISC_CLAIM_PROTOCOL_FEES_IX: &[u8] = &[14];
+pub const DISC_PROPOSE_ADMIN_IX: &[u8] = &[15];
+pub const DISC_ACCEPT_ADMIN_IX: &[u8] = &[16];
+pub const DISC_CANCEL_ADMIN_PROPOSAL_IX: &[u8] = &[17];
+pub const DISC_CLOSE_VAULT_IX: &[u8] = &[18];
+pub const DISC_CLOSE_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[19];
+pub const DISC_REDEEM_CLOSED_VAULT_STRATEGY_IX: &[u8] = &[20];
+pub const DISC_CLOSE_RAYDIUM_POSITION_IX: &[u8] = &[21];
+pub const DISC_CLAIM_PERFORMANCE_FEES_IX: &[u8] = &[22];
+pub const DISC_SET_KEEPER_IX: &[u8] = &[23];
+pub const DISC_REBALANCE_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[24];
+pub const DISC_COMPOUND_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[25];
+pub const DISC_INIT_VAULT_STRATEGY_SHARE_MINT_IX: &[u8] = &[26];
+pub const DISC_DEPOSIT_RAYDIUM_VAULT_IX: &[u8] = &[27];
+pub const DISC_WITHDRAW_TO_USDC_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[28];
+pub const DISC_REDEEM_VAULT_IX: &[u8] = &[29];
+pub const DISC_CANCEL_INVEST_RESERVE_IX: &[u8] = &[30];
+pub const DISC_CLAIM_INVESTOR_FEES_IX: &[u8] = &[31];
+pub const DISC_REDEEM_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[32];
+pub const DISC_WITHDRAW_RAYDIUM_VAULT_STRATEGY_IX: &[u8] = &[33];
+pub const DISC_GET_VAULT_NAV_IX: &[u8] = &[34];
+pub const DISC_PREVIEW_DEPOSIT_IX: &[u8] = &[35];
+pub const DISC_PREVIEW_REDEEM_IX: &[u8] = &[36];
+pub const DISC_GET_POSITION_VALUE_IX: &[u8] = &[37];
+pub const DISC_DEPOSIT_TOKENS_RAYDIUM_VAULT_IX: &[u8] = &[38];
+pub const DISC_SET_PRICE_BOUNDS_IX: &[u8] = &[39];
+pub const DISC_SET_ORACLE_FEED_IX: &[u8] = &[40];
+pub const DISC_SET_MAX_PRICE_DEVIATION_IX: &[u8] = &[41];
+pub const DISC_SET_ORACLE_SOURCES_IX: &[u8] = &[42];
+pub const DISC_MIGRATE_ACCOUNT_IX: &[u8] = &[43];
+pub const DISC_MIGRATE_INVESTOR_SHARES_IX: &[u8] = &[44];
 
 pub const DENOMINATOR_MULTIPLIER: u64 = 1_000_000_000;
 
@@ -63,3 +112,6 @@ pub const DENOMINATOR_MULTIPLIER: u64 = 1_000_000_000;
 pub const MAX_REMOVE_PERCENTAGE: u64 = 100 * DENOMINATOR_MULTIPLIER;
 /// Min remove percentage: 10% (10 * 1e9)
 pub const MIN_REMOVE_PERCENTAGE: u64 = 10 * DENOMINATOR_MULTIPLIER;
+
+/// Dust guard of the redeem and withdraw by shares or assets: 0.01 share
+pub const MIN_REDEEM_SHARES: u64 = 10_000;
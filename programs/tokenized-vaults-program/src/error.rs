@@ -26,6 +26,9 @@ pub enum TokenizedVaultsErrorCode {
     #[msg("Protocol is not paused")]
     ProtocolNotPaused,
 
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
     #[msg("VaultStrategyConfig is already initialized")]
     VaultStrategyConfigInitialized,
 
@@ -38,6 +41,12 @@ pub enum TokenizedVaultsErrorCode {
     #[msg("VaultStrategyConfig is not in paused status")]
     VaultStrategyConfigNotPaused,
 
+    #[msg("VaultStrategyConfig is in draft status, only the creator can operate it")]
+    VaultStrategyConfigCreatorOnly,
+
+    #[msg("Operation not allowed for the current VaultStrategyConfig status")]
+    VaultOperationNotAllowed,
+
     #[msg("VaultStrategyConfig max strategies reached")]
     VaultStrategyConfigMaxStrategiesReached,
 
@@ -151,4 +160,117 @@ pub enum TokenizedVaultsErrorCode {
 
     #[msg("Unauthorized")]
     Unauthorized,
+
+    #[msg("Invalid pending admin")]
+    InvalidPendingAdmin,
+
+    #[msg("No pending admin proposal")]
+    NoPendingAdmin,
+
+    #[msg("VaultStrategyConfig is already closed")]
+    VaultStrategyConfigAlreadyClosed,
+
+    #[msg("VaultStrategyConfig is not in closed status")]
+    VaultStrategyConfigNotClosed,
+
+    #[msg("VaultStrategy is already closed")]
+    VaultStrategyAlreadyClosed,
+
+    #[msg("VaultStrategy is not closed")]
+    VaultStrategyNotClosed,
+
+    #[msg("VaultStrategy still has outstanding shares")]
+    VaultStrategyHasOutstandingShares,
+
+    #[msg("Raydium position does not belong to the vault strategy")]
+    InvalidRaydiumPosition,
+
+    #[msg("Raydium position is still in range")]
+    RaydiumPositionInRange,
+
+    #[msg("Liquidity calculated to zero")]
+    LiquidityCalculatedToZero,
+
+    #[msg("VaultStrategy share mint already initialized")]
+    VaultStrategyShareMintInitialized,
+
+    #[msg("Invalid vault strategy share mint")]
+    InvalidShareMint,
+
+    #[msg("Minted shares are below the minimum shares out")]
+    SharesBelowMinimum,
+
+    #[msg("Amount out is below the minimum amount out")]
+    AmountOutBelowMinimum,
+
+    #[msg("No fees to claim")]
+    NoFeesToClaim,
+
+    #[msg("Shares to burn are below the dust threshold")]
+    SharesBelowDust,
+
+    #[msg("Shares left in the position are below the dust threshold")]
+    RemainingSharesBelowDust,
+
+    #[msg("Shares to burn are above the maximum shares")]
+    SharesAboveMaximum,
+
+    #[msg("Investor strategy position account is required")]
+    MissingInvestorStrategyPosition,
+
+    #[msg("Oracle price is older than the maximum price age")]
+    PriceTooOld,
+
+    #[msg("Oracle price confidence interval is too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Invalid price bounds")]
+    InvalidPriceBounds,
+
+    #[msg("Invalid oracle feed")]
+    InvalidOracleFeed,
+
+    #[msg("Pyth price exponent differs from the oracle registry")]
+    PythExponentMismatch,
+
+    #[msg("Pool price deviates too much from the oracle price")]
+    PoolPriceDeviationTooHigh,
+
+    #[msg("Invalid maximum price deviation")]
+    InvalidPriceDeviation,
+
+    #[msg("Oracle observations do not cover the TWAP window")]
+    InsufficientTwapObservations,
+
+    #[msg("Invalid TWAP window")]
+    InvalidTwapWindow,
+
+    #[msg("Invalid oracle sources")]
+    InvalidOracleSources,
+
+    #[msg("Accounts of the oracle source are missing")]
+    OracleSourceUnavailable,
+
+    #[msg("Oracle account differs from the oracle registry")]
+    InvalidOracleAccount,
+
+    #[msg("Invalid Switchboard feed account")]
+    InvalidSwitchboardFeed,
+
+    #[msg("Account type cannot be migrated")]
+    InvalidMigrationAccount,
+
+    #[msg("Pass either a fees destination or the creator account")]
+    InvalidFeesDestination,
+
+    #[msg("Position shares are not minted as share tokens yet")]
+    SharesNotMinted,
+
+    #[msg("Position shares are already minted as share tokens")]
+    SharesAlreadyMinted,
+
+    #[msg("Invalid vault strategy rebalance escrow")]
+    InvalidRebalanceEscrow,
+    #[msg("Swap pool does not trade the expected mints")]
+    InvalidSwapPool,
 }
@@ -0,0 +1,30 @@
+#![allow(deprecated, unexpected_cfgs)]
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    // The pending admin that is accepting the admin authority.
+    #[account(mut)]
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept(&mut self) -> Result<()> {
+        self.protocol_config
+            .accept_admin(self.pending_admin.key())?;
+        Ok(())
+    }
+}
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    ctx.accounts.accept()
+}
@@ -0,0 +1,30 @@
+#![allow(deprecated, unexpected_cfgs)]
+use crate::{error::TokenizedVaultsErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    // The current admin authority that is cancelling the pending admin proposal.
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelAdminProposal<'info> {
+    pub fn cancel(&mut self) -> Result<()> {
+        self.protocol_config.cancel_admin_proposal()?;
+        Ok(())
+    }
+}
+pub fn handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    ctx.accounts.cancel()
+}
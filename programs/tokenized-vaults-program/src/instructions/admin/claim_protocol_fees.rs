@@ -0,0 +1,85 @@
+#![allow(deprecated, unexpected_cfgs)]
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::TokenizedVaultsErrorCode, state::*, utils::transfer_token, ProtocolFeesClaimedEvent,
+};
+
+#[derive(Accounts)]
+pub struct ClaimProtocolFees<'info> {
+    // The admin authority that is claiming the protocol fees.
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// The protocol fees escrow account for the mint
+    #[account(
+        mut,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_fees_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token account that receives the claimed protocol fees
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimProtocolFees<'info> {
+    pub fn claim(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, TokenizedVaultsErrorCode::InvalidAmount);
+        require!(
+            self.protocol_fees_escrow.amount >= amount,
+            TokenizedVaultsErrorCode::InsufficientFunds
+        );
+
+        let bump_seed = &[self.protocol_config.bump];
+        let seeds = &[ProtocolConfig::SEED.as_bytes(), bump_seed];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer_token(
+            &self.protocol_fees_escrow,
+            &self.destination_token_account,
+            amount,
+            &self.mint,
+            &self.protocol_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        emit!(ProtocolFeesClaimedEvent {
+            admin_authority: self.admin_authority.key(),
+            mint: self.mint.key(),
+            destination: self.destination_token_account.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ClaimProtocolFees>, amount: u64) -> Result<()> {
+    ctx.accounts.claim(amount)
+}
@@ -1,12 +1,26 @@
 #![allow(unused_imports, ambiguous_glob_reexports)]
+pub mod accept_admin;
+pub mod cancel_admin_proposal;
+pub mod claim_protocol_fees;
 pub mod init_protocol_config;
 pub mod pause_protocol;
 pub mod pause_vault;
+pub mod propose_admin;
+pub mod set_oracle_feed;
+pub mod set_oracle_sources;
+pub mod set_price_bounds;
 pub mod unpause_protocol;
 pub mod unpause_vault;
 
+pub use accept_admin::*;
+pub use cancel_admin_proposal::*;
+pub use claim_protocol_fees::*;
 pub use init_protocol_config::*;
 pub use pause_protocol::*;
 pub use pause_vault::*;
+pub use propose_admin::*;
+pub use set_oracle_feed::*;
+pub use set_oracle_sources::*;
+pub use set_price_bounds::*;
 pub use unpause_protocol::*;
 pub use unpause_vault::*;
@@ -0,0 +1,30 @@
+#![allow(deprecated, unexpected_cfgs)]
+use crate::{error::TokenizedVaultsErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    // The current admin authority that is proposing a new admin.
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.protocol_config.propose_admin(new_admin)?;
+        Ok(())
+    }
+}
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    ctx.accounts.propose(new_admin)
+}
@@ -0,0 +1,67 @@
+#![allow(deprecated, unexpected_cfgs)]
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{error::TokenizedVaultsErrorCode, state::*};
+
+#[derive(Accounts)]
+pub struct SetOracleFeed<'info> {
+    // The admin authority that is registering the oracle feed.
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// The mint priced by the oracle feed
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Oracle registry entry of the mint, created on first registration
+    #[account(
+        init_if_needed,
+        payer = admin_authority,
+        space = OracleFeed::DISCRIMINATOR.len() + OracleFeed::INIT_SPACE,
+        seeds = [OracleFeed::SEED.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub oracle_feed: Account<'info, OracleFeed>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetOracleFeed<'info> {
+    pub fn set(
+        &mut self,
+        feed_id: [u8; 32],
+        exponent: i32,
+        max_price_age: u64,
+        max_price_conf: u32,
+        bump: u8,
+    ) -> Result<()> {
+        self.oracle_feed.set(
+            self.admin_authority.key(),
+            self.mint.key(),
+            feed_id,
+            exponent,
+            max_price_age,
+            max_price_conf,
+            bump,
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetOracleFeed>,
+    feed_id: [u8; 32],
+    exponent: i32,
+    max_price_age: u64,
+    max_price_conf: u32,
+) -> Result<()> {
+    let bump = ctx.bumps.oracle_feed;
+    ctx.accounts
+        .set(feed_id, exponent, max_price_age, max_price_conf, bump)
+}
@@ -0,0 +1,74 @@
+#![allow(deprecated, unexpected_cfgs)]
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{error::TokenizedVaultsErrorCode, state::*, utils::realloc_account};
+
+#[derive(Accounts)]
+pub struct SetOracleSources<'info> {
+    // The admin authority that is setting the price sources.
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// The mint priced by the oracle feed
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Oracle registry entry of the mint, registered with `set_oracle_feed`.
+    /// Entries registered before the price sources existed are smaller than `OracleFeed`,
+    /// they are grown before being deserialized
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [OracleFeed::SEED.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub oracle_feed: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetOracleSources<'info> {
+    pub fn set(
+        &mut self,
+        sources: &[OracleSource],
+        switchboard_feed: Pubkey,
+        twap_pool: Pubkey,
+        twap_window: u32,
+    ) -> Result<()> {
+        realloc_account(
+            &self.oracle_feed,
+            &self.admin_authority,
+            &self.system_program,
+            OracleFeed::DISCRIMINATOR.len() + OracleFeed::INIT_SPACE,
+        )?;
+
+        let mut oracle_feed =
+            OracleFeed::try_deserialize(&mut &self.oracle_feed.try_borrow_data()?[..])?;
+        oracle_feed.set_sources(
+            self.admin_authority.key(),
+            sources,
+            switchboard_feed,
+            twap_pool,
+            twap_window,
+        )?;
+        oracle_feed.try_serialize(&mut &mut self.oracle_feed.try_borrow_mut_data()?[..])
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetOracleSources>,
+    sources: Vec<OracleSource>,
+    switchboard_feed: Pubkey,
+    twap_pool: Pubkey,
+    twap_window: u32,
+) -> Result<()> {
+    ctx.accounts
+        .set(&sources, switchboard_feed, twap_pool, twap_window)
+}
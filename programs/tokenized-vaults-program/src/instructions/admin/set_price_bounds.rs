@@ -0,0 +1,30 @@
+use crate::{error::TokenizedVaultsErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPriceBounds<'info> {
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin_authority @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> SetPriceBounds<'info> {
+    pub fn set(&mut self, max_price_age: u64, max_price_conf: u32) -> Result<()> {
+        self.protocol_config
+            .set_price_bounds(max_price_age, max_price_conf)
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetPriceBounds>,
+    max_price_age: u64,
+    max_price_conf: u32,
+) -> Result<()> {
+    ctx.accounts.set(max_price_age, max_price_conf)
+}
@@ -1,5 +1,5 @@
 #![allow(deprecated, unexpected_cfgs)]
-use crate::state::*;
+use crate::{error::TokenizedVaultsErrorCode, state::*};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -10,8 +10,12 @@ pub struct UnpauseVault<'info> {
 
     #[account(
         mut,
-        has_one = creator,
-        seeds= [VaultStrategyConfig::SEED.as_bytes()],
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
         bump = vault_strategy_config.bump
     )]
     pub vault_strategy_config: Account<'info, VaultStrategyConfig>,
@@ -0,0 +1,492 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::tick_math;
+use crate::{
+    assert_protocol_active, get_liquidity_from_amounts, get_swap_amount_to_position_ratio,
+    ProtocolConfig, RaydiumPositionCpi, RaydiumSwapCpi, RaydiumSwapSide, StrategyFeesTransfer,
+    Twap, VaultStrategy, VaultStrategyCompoundedEvent, VaultStrategyConfig, VaultStrategyStatus,
+    MAX_COMPOUND_SLIPPAGE, RAYDIUM_CLMM_ID, STRATEGY_POOL_TWAP_WINDOW,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct CompoundRaydiumVaultStrategy<'info> {
+    /// Anyone can crank the compound, pays the escrows rent on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The escrow account for the token 0
+    /// Holds the token 0 fees until they are added back to the position
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Holds the token 1 fees until they are added back to the position
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the collected token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the collected token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// The factory state of the pool, used by the swap
+    pub raydium_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The program account for the most recent oracle observation, used by the swap
+    /// and for the TWAP its minimum output is derived from
+    #[account(mut, address = raydium_pool_state.load()?.observation_key)]
+    pub raydium_observation_state: AccountLoader<'info, ObservationState>,
+
+    /// CHECK: Token account that holds the raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // Pubkey::default() as separator
+    // swap tick_array_account_1, required whenever the escrows need a swap to the position ratio
+    // swap tick_array_account_...
+}
+
+impl<'info> CompoundRaydiumVaultStrategy<'info> {
+    /// Permissionless crank that reinvests the collected fees:
+    /// 1. collect the fees with a zero liquidity decrease, like remove liquidity does
+    /// 2. pay the protocol and performance cuts
+    /// 3. swap the investors part to the position ratio
+    /// 4. increase the position liquidity
+    /// Shares are untouched, so the value of every share grows.
+    pub fn process(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        require!(
+            self.vault_strategy_config.status == VaultStrategyStatus::Active,
+            TokenizedVaultsErrorCode::VaultStrategyConfigNotActive
+        );
+
+        let (position_remaining_accounts, swap_remaining_accounts) = match remaining_accounts
+            .iter()
+            .position(|account_info| account_info.key() == Pubkey::default())
+        {
+            Some(split_index) => (
+                &remaining_accounts[..split_index],
+                &remaining_accounts[split_index + 1..],
+            ),
+            None => (remaining_accounts, &remaining_accounts[..0]),
+        };
+
+        let strategy_fees = self.strategy_fees();
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            position_remaining_accounts,
+        )?;
+
+        // the investors part is added back to the position from the rebalance escrows
+        strategy_fees.transfer(
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+            &self.vault_strategy_cfg_mint_0_rebalance_escrow,
+            &self.vault_strategy_cfg_mint_1_rebalance_escrow,
+        )?;
+
+        self.vault_strategy_cfg_mint_0_rebalance_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_rebalance_escrow.reload()?;
+
+        let (swap_amount_in, zero_for_one) = {
+            let pool_state = self.raydium_pool_state.load()?;
+            get_swap_amount_to_position_ratio(
+                pool_state.sqrt_price_x64,
+                self.raydium_personal_position.tick_lower_index,
+                self.raydium_personal_position.tick_upper_index,
+                self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+                self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+            )?
+        };
+
+        if swap_amount_in > 0 {
+            require!(
+                !swap_remaining_accounts.is_empty(),
+                TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+            );
+            self.swap(swap_amount_in, zero_for_one, swap_remaining_accounts)?;
+        }
+
+        let liquidity = self.increase_liquidity(position_remaining_accounts)?;
+
+        emit!(VaultStrategyCompoundedEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_0: strategy_fees_owed_0,
+            fees_1: strategy_fees_owed_1,
+            liquidity,
+        });
+
+        msg!("Vault strategy compounded successfully");
+        Ok(())
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    fn strategy_fees(&self) -> StrategyFeesTransfer<'_, 'info> {
+        StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        }
+    }
+
+    /// The crank is permissionless, the minimum output is derived from the pool TWAP
+    /// with a MAX_COMPOUND_SLIPPAGE tolerance instead of being supplied by the caller.
+    /// The spot price can be moved within the same transaction, the TWAP can't
+    fn swap(
+        &mut self,
+        amount_in: u64,
+        zero_for_one: bool,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        let amount_out_min = Twap::from_pool(
+            &*self.raydium_pool_state.load()?,
+            &*self.raydium_observation_state.load()?,
+            STRATEGY_POOL_TWAP_WINDOW,
+        )?
+        .swap_amount_out_min(amount_in, zero_for_one, MAX_COMPOUND_SLIPPAGE)?;
+        msg!("amount_out_min: {}", amount_out_min);
+
+        let side_0 = RaydiumSwapSide {
+            token_account: self
+                .vault_strategy_cfg_mint_0_rebalance_escrow
+                .to_account_info(),
+            vault: self.raydium_token_vault_0.to_account_info(),
+            mint: self.raydium_vault_0_mint.to_account_info(),
+        };
+        let side_1 = RaydiumSwapSide {
+            token_account: self
+                .vault_strategy_cfg_mint_1_rebalance_escrow
+                .to_account_info(),
+            vault: self.raydium_token_vault_1.to_account_info(),
+            mint: self.raydium_vault_1_mint.to_account_info(),
+        };
+        let (input, output, before_balance) = if zero_for_one {
+            (
+                side_0,
+                side_1,
+                self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+            )
+        } else {
+            (
+                side_1,
+                side_0,
+                self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+            )
+        };
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            observation_state: self.raydium_observation_state.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &input,
+            &output,
+            amount_in,
+            amount_out_min,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        self.vault_strategy_cfg_mint_0_rebalance_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_rebalance_escrow.reload()?;
+
+        let after_balance = if zero_for_one {
+            self.vault_strategy_cfg_mint_1_rebalance_escrow.amount
+        } else {
+            self.vault_strategy_cfg_mint_0_rebalance_escrow.amount
+        };
+        let amount_out = after_balance.saturating_sub(before_balance);
+
+        msg!(
+            "Compound swap: zero_for_one={}, amount_in={}, amount_out={}",
+            zero_for_one,
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    /// Adds the rebalance escrows balances to the position,
+    /// what can't be added at the current ratio stays for the next compound
+    fn increase_liquidity(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<u128> {
+        let amount_0_max = self.vault_strategy_cfg_mint_0_rebalance_escrow.amount;
+        let amount_1_max = self.vault_strategy_cfg_mint_1_rebalance_escrow.amount;
+
+        let liquidity = {
+            let pool_state = self.raydium_pool_state.load()?;
+            let current_sqrt_price = pool_state.sqrt_price_x64;
+            let low_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_lower_index)?;
+            let high_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_upper_index)?;
+
+            get_liquidity_from_amounts(
+                current_sqrt_price,
+                low_sqrt_price,
+                high_sqrt_price,
+                amount_0_max,
+                amount_1_max,
+            )
+        };
+
+        msg!("compound liquidity: {}", liquidity);
+        if liquidity == 0 {
+            return Ok(0);
+        }
+
+        self.raydium_position().increase_liquidity(
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            &self
+                .vault_strategy_cfg_mint_0_rebalance_escrow
+                .to_account_info(),
+            &self
+                .vault_strategy_cfg_mint_1_rebalance_escrow
+                .to_account_info(),
+            remaining_accounts,
+        )?;
+
+        Ok(liquidity)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CompoundRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.process(ctx.remaining_accounts)
+}
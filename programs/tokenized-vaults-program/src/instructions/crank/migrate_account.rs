@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::TokenizedVaultsErrorCode, state::*, utils::realloc_account};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Anyone can migrate an account, the payer funds the rent of the appended fields
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Program account created with an older layout, typed from its discriminator once grown
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    /// Grows an account to the current layout of its type. Fields are only appended to the
+    /// accounts, so the existing data is kept and the new fields are zeroed, then set to
+    /// their defaults. An account already at the current layout is left untouched.
+    /// A grown strategy gets its share mint from `init_vault_strategy_share_mint`
+    /// and the shares of its positions from `migrate_investor_shares`
+    pub fn migrate(&mut self) -> Result<()> {
+        let discriminator = self
+            .account
+            .try_borrow_data()?
+            .first()
+            .copied()
+            .ok_or(TokenizedVaultsErrorCode::InvalidMigrationAccount)?;
+
+        let space = match &[discriminator][..] {
+            ProtocolConfig::DISCRIMINATOR => {
+                ProtocolConfig::DISCRIMINATOR.len() + ProtocolConfig::INIT_SPACE
+            }
+            VaultStrategyConfig::DISCRIMINATOR => {
+                VaultStrategyConfig::DISCRIMINATOR.len() + VaultStrategyConfig::INIT_SPACE
+            }
+            VaultStrategy::DISCRIMINATOR => {
+                VaultStrategy::DISCRIMINATOR.len() + VaultStrategy::INIT_SPACE
+            }
+            InvestorStrategyPosition::DISCRIMINATOR => {
+                InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE
+            }
+            _ => return err!(TokenizedVaultsErrorCode::InvalidMigrationAccount),
+        };
+
+        msg!(
+            "account size: {}, current layout: {}",
+            self.account.data_len(),
+            space
+        );
+        if self.account.data_len() >= space {
+            return Ok(());
+        }
+
+        realloc_account(&self.account, &self.payer, &self.system_program, space)?;
+
+        match &[discriminator][..] {
+            ProtocolConfig::DISCRIMINATOR => self.set_defaults(ProtocolConfig::migrate),
+            VaultStrategyConfig::DISCRIMINATOR => self.set_defaults(VaultStrategyConfig::migrate),
+            _ => Ok(()),
+        }
+    }
+
+    fn set_defaults<T: AccountSerialize + AccountDeserialize>(
+        &self,
+        migrate: impl FnOnce(&mut T),
+    ) -> Result<()> {
+        let mut account = T::try_deserialize(&mut &self.account.try_borrow_data()?[..])?;
+        migrate(&mut account);
+        account.try_serialize(&mut &mut self.account.try_borrow_mut_data()?[..])
+    }
+}
+
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    ctx.accounts.migrate()
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::mint_shares;
+use crate::{InvestorStrategyPosition, VaultStrategy, VaultStrategyConfig};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct MigrateInvestorShares<'info> {
+    /// Anyone can migrate a position, the payer funds the investor share account if needed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.mint_0.as_ref(),
+            vault_strategy.mint_1.as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// A position opened before the strategy had a share mint, grown by `migrate_account`
+    #[account(
+        mut,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump = investor_strategy_position.bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// CHECK: Owner of the position, only used as the authority of its share account
+    #[account(address = investor_strategy_position.authority)]
+    pub investor: UncheckedAccount<'info>,
+
+    /// The share mint of the vault strategy, created by `init_vault_strategy_share_mint`
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account receiving the position shares
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateInvestorShares<'info> {
+    /// Mints the shares of a position opened before the strategy had a share mint to its
+    /// investor, once. From then on the share token balance is the source of truth
+    /// of the position shares, like for any position opened since
+    pub fn migrate(&mut self) -> Result<()> {
+        let shares = self.investor_strategy_position.take_unminted_shares()?;
+        msg!("migrated shares: {}", shares);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        mint_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            shares,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn handler(ctx: Context<MigrateInvestorShares>, _strategy_id: u8) -> Result<()> {
+    ctx.accounts.migrate()
+}
@@ -0,0 +1,7 @@
+#![allow(unused_imports, ambiguous_glob_reexports)]
+pub mod compound_raydium_vault_strategy;
+pub use compound_raydium_vault_strategy::*;
+pub mod migrate_account;
+pub use migrate_account::*;
+pub mod migrate_investor_shares;
+pub use migrate_investor_shares::*;
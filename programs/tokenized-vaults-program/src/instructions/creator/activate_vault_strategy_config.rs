@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::TokenizedVaultsErrorCode, 
+    assert_protocol_active, error::TokenizedVaultsErrorCode, 
     state::{ProtocolConfig, VaultStrategyConfig}, 
-    ProtocolStatus, VaultStrategyStatus
+    VaultStrategyStatus
 };
 
 #[derive(Accounts)]
@@ -32,10 +32,7 @@ pub struct ActivateVaultStrategyConfig<'info> {
 
 impl<'info> ActivateVaultStrategyConfig<'info> {
     pub fn activate(&mut self) -> Result<()> {
-        require!(
-            self.protocol_config.status == ProtocolStatus::Active,
-            TokenizedVaultsErrorCode::ProtocolConfigNotInitialized
-        );
+        assert_protocol_active(&self.protocol_config.status)?;
 
         require!(
             self.vault_strategy_config.status == VaultStrategyStatus::Draft,
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::transfer_token;
+use crate::{PerformanceFeesClaimedEvent, VaultStrategyConfig};
+
+#[derive(Accounts)]
+pub struct ClaimPerformanceFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The creator account for the token 0, only passed, and created if needed,
+    /// when the token 0 fees go to the creator
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = vault_0_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_mint_0_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The creator account for the token 1, only passed, and created if needed,
+    /// when the token 1 fees go to the creator
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = vault_1_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_mint_1_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Optional destination for the token 0 fees, replaces the creator account
+    #[account(
+        mut,
+        token::mint = vault_0_mint,
+    )]
+    pub destination_mint_0_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Optional destination for the token 1 fees, replaces the creator account
+    #[account(
+        mut,
+        token::mint = vault_1_mint,
+    )]
+    pub destination_mint_1_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The mint of token 0
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimPerformanceFees<'info> {
+    /// Claims the creator performance fees, zero amount skips the mint.
+    /// Not gated by the vault or protocol status, the fees were already earned.
+    pub fn claim(&mut self, amount_0: u64, amount_1: u64) -> Result<()> {
+        require!(
+            amount_0 > 0 || amount_1 > 0,
+            TokenizedVaultsErrorCode::InvalidAmount
+        );
+
+        if amount_0 > 0 {
+            let destination_0 = fees_destination(
+                &self.destination_mint_0_account,
+                &self.creator_mint_0_account,
+            )?;
+            self.claim_mint(
+                &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+                destination_0,
+                &self.vault_0_mint,
+                amount_0,
+            )?;
+        }
+
+        if amount_1 > 0 {
+            let destination_1 = fees_destination(
+                &self.destination_mint_1_account,
+                &self.creator_mint_1_account,
+            )?;
+            self.claim_mint(
+                &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+                destination_1,
+                &self.vault_1_mint,
+                amount_1,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn claim_mint(
+        &self,
+        perf_fees_escrow: &InterfaceAccount<'info, TokenAccount>,
+        destination: &InterfaceAccount<'info, TokenAccount>,
+        mint: &InterfaceAccount<'info, Mint>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            perf_fees_escrow.amount >= amount,
+            TokenizedVaultsErrorCode::InsufficientFunds
+        );
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            perf_fees_escrow,
+            destination,
+            amount,
+            mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        emit!(PerformanceFeesClaimedEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            creator: self.creator.key(),
+            mint: mint.key(),
+            destination: destination.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+/// The destination of the fees of a mint, either the passed destination or the creator account.
+/// Passing both would create the creator account for nothing
+fn fees_destination<'a, 'info>(
+    destination: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    creator_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+) -> Result<&'a InterfaceAccount<'info, TokenAccount>> {
+    match (destination, creator_account) {
+        (Some(destination), None) => Ok(&**destination),
+        (None, Some(creator_account)) => Ok(&**creator_account),
+        _ => err!(TokenizedVaultsErrorCode::InvalidFeesDestination),
+    }
+}
+
+pub fn handler(ctx: Context<ClaimPerformanceFees>, amount_0: u64, amount_1: u64) -> Result<()> {
+    ctx.accounts.claim(amount_0, amount_1)
+}
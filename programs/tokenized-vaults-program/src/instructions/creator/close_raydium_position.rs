@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use raydium_clmm_cpi::program::RaydiumClmm;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::reclaim_rent;
+use crate::{RaydiumPositionCpi, VaultStrategy, VaultStrategyConfig, RAYDIUM_CLMM_ID};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct CloseRaydiumPosition<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.mint_0.as_ref(),
+            vault_strategy.mint_1.as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// CHECK: The raydium position NFT mint, checked against the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.dex_nft_mint @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_position_nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token account that holds the raydium position NFT, checked by raydium
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: raydium personal position state, checked by raydium
+    #[account(mut)]
+    pub raydium_personal_position: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// SPL program 2022, the position NFT is a Token-2022 mint
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+impl<'info> CloseRaydiumPosition<'info> {
+    /// Burns the position NFT once every investor redeemed its shares,
+    /// raydium refunds the rent to the vault strategy config which is forwarded to the creator
+    pub fn close(&mut self) -> Result<()> {
+        self.vault_strategy_config.assert_closed()?;
+
+        require!(
+            self.vault_strategy.is_closed,
+            TokenizedVaultsErrorCode::VaultStrategyNotClosed
+        );
+        require!(
+            self.vault_strategy.total_shares == 0,
+            TokenizedVaultsErrorCode::VaultStrategyHasOutstandingShares
+        );
+
+        RaydiumPositionCpi::close_position_nft(
+            &self.raydium_clmm_program.to_account_info(),
+            &self.vault_strategy_config,
+            &self.raydium_position_nft_mint.to_account_info(),
+            &self.raydium_position_nft_account.to_account_info(),
+            &self.raydium_personal_position.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.token_program_2022.to_account_info(),
+        )?;
+
+        let reclaimed_lamports = reclaim_rent(
+            &self.vault_strategy_config.to_account_info(),
+            &self.creator.to_account_info(),
+        )?;
+        msg!("reclaimed_lamports: {}", reclaimed_lamports);
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CloseRaydiumPosition>, _strategy_id: u8) -> Result<()> {
+    ctx.accounts.close()
+}
@@ -0,0 +1,400 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{PersonalPositionState, PoolState, TickArrayState};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::transfer_token;
+use crate::{
+    assert_creator_or_admin, ProtocolConfig, RaydiumPositionCpi, StrategyFeesTransfer,
+    VaultStrategy, VaultStrategyConfig, RAYDIUM_CLMM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct CloseRaydiumVaultStrategy<'info> {
+    /// The vault creator or the protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The escrow account that holds the token 0 proceeds of the closed strategy
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            VaultStrategy::CLOSED_0_ESCROW_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_closed_mint_0_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account that holds the token 1 proceeds of the closed strategy
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            VaultStrategy::CLOSED_1_ESCROW_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_closed_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded,
+    /// swept into the closed escrow
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded,
+    /// swept into the closed escrow
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the collected token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the collected token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// CHECK: Token account that holds the raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The raydium position owned by the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining account
+    // pub tick_array_bitmap: AccountLoader<'info, TickArrayBitmapExtension>,
+}
+
+impl<'info> CloseRaydiumVaultStrategy<'info> {
+    /// Pulls all the liquidity of the strategy Raydium position into the strategy closed escrows.
+    /// Accrued fees are collected first, the protocol and performance cuts are paid
+    /// and the investors part is added to the closed escrows.
+    pub fn process(
+        &mut self,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        assert_creator_or_admin(
+            &self.authority.key(),
+            &self.vault_strategy_config.creator,
+            &self.protocol_config.admin_authority,
+        )?;
+
+        self.vault_strategy_config.assert_closed()?;
+
+        let strategy_fees = self.strategy_fees();
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+
+        strategy_fees.transfer(
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+            &self.vault_strategy_closed_mint_0_escrow,
+            &self.vault_strategy_closed_mint_1_escrow,
+        )?;
+
+        let liquidity = self.raydium_personal_position.liquidity;
+        msg!("raydium_personal_position.liquidity: {}", liquidity);
+
+        if liquidity > 0 {
+            self.raydium_position().decrease_liquidity(
+                liquidity,
+                amount_0_min,
+                amount_1_min,
+                &self.vault_strategy_closed_mint_0_escrow.to_account_info(),
+                &self.vault_strategy_closed_mint_1_escrow.to_account_info(),
+                remaining_accounts,
+            )?;
+        }
+
+        self.sweep_rebalance_escrows()?;
+
+        self.vault_strategy_closed_mint_0_escrow.reload()?;
+        self.vault_strategy_closed_mint_1_escrow.reload()?;
+
+        let closed_amount_0 = self.vault_strategy_closed_mint_0_escrow.amount;
+        let closed_amount_1 = self.vault_strategy_closed_mint_1_escrow.amount;
+        msg!("closed_amount_0: {}", closed_amount_0);
+        msg!("closed_amount_1: {}", closed_amount_1);
+
+        self.vault_strategy
+            .close(closed_amount_0, closed_amount_1)?;
+
+        msg!("Vault strategy closed successfully");
+        Ok(())
+    }
+
+    /// The idle amounts waiting to be compounded back the shares too,
+    /// they join the proceeds of the closed strategy
+    fn sweep_rebalance_escrows(&self) -> Result<()> {
+        msg!(
+            "rebalance escrow amount_0: {}",
+            self.vault_strategy_cfg_mint_0_rebalance_escrow.amount
+        );
+        msg!(
+            "rebalance escrow amount_1: {}",
+            self.vault_strategy_cfg_mint_1_rebalance_escrow.amount
+        );
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_0_rebalance_escrow,
+            &self.vault_strategy_closed_mint_0_escrow,
+            self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+            &self.raydium_vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_1_rebalance_escrow,
+            &self.vault_strategy_closed_mint_1_escrow,
+            self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+            &self.raydium_vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    fn strategy_fees(&self) -> StrategyFeesTransfer<'_, 'info> {
+        StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        }
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CloseRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+    amount_0_min: u64,
+    amount_1_min: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts
+        .process(amount_0_min, amount_1_min, ctx.remaining_accounts)
+}
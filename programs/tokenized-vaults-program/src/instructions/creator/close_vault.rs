@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    assert_creator_or_admin,
+    state::{ProtocolConfig, VaultStrategyConfig},
+};
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// The vault creator or the protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Account<'info, VaultStrategyConfig>,
+}
+
+impl<'info> CloseVault<'info> {
+    /// Closing is the first step of the vault shutdown:
+    /// 1. close_vault, blocks every operation except the final redemption
+    /// 2. close_raydium_vault_strategy, once per strategy, pulls all liquidity into the strategy escrows
+    /// 3. redeem_closed_vault_strategy, investors redeem their pro-rata share
+    /// 4. close_raydium_position, burns the position NFT once all shares are redeemed
+    pub fn close(&mut self) -> Result<()> {
+        assert_creator_or_admin(
+            &self.authority.key(),
+            &self.vault_strategy_config.creator,
+            &self.protocol_config.admin_authority,
+        )?;
+
+        self.vault_strategy_config.close_vault()
+    }
+}
+
+pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
+    ctx.accounts.close()
+}
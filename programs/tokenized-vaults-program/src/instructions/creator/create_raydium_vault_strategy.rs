@@ -7,14 +7,20 @@ use anchor_spl::{
     token_interface::{Mint, Token2022, TokenAccount},
 };
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use raydium_clmm_cpi::{cpi, program::RaydiumClmm, states::PoolState};
+use raydium_clmm_cpi::{
+    cpi,
+    program::RaydiumClmm,
+    states::{ObservationState, PoolState},
+};
 
 use crate::{
+    assert_protocol_active,
     error::TokenizedVaultsErrorCode,
-    get_liquidity_from_amounts,
+    get_liquidity_from_amounts, get_price_infos,
+    oracle::TokenOracle,
     state::*,
     tick_math,
-    utils::{convert_amounts_to_usd, get_price_from_pyth_update},
+    utils::{convert_amounts_to_usd, mint_shares, ShareMintMetadataCpi},
     ProtocolStatus, VaultStrategyStatus, RAYDIUM_CLMM_ID,
 };
 
@@ -24,6 +30,12 @@ pub struct CreateRaydiumVaultStrategy<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(
         mut,
         constraint = vault_strategy_config.creator == creator.key()
@@ -58,6 +70,42 @@ pub struct CreateRaydiumVaultStrategy<'info> {
     )]
     pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
 
+    /// The share mint of the vault strategy
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            VaultStrategy::SHARE_MINT_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        mint::decimals = VaultStrategy::SHARE_MINT_DECIMALS,
+        mint::authority = vault_strategy_config,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The creator account for the shares
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = share_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata account of the share mint, created by the metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            share_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub share_mint_metadata: UncheckedAccount<'info>,
+
     /// The escrow account for the token 0
     /// Vault strategy Config receives token 0 in this account from Raydium Swap
     #[account(
@@ -149,11 +197,47 @@ pub struct CreateRaydiumVaultStrategy<'info> {
     pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /* Pyth Price Feeds */
-    /// CHECK: Pyth price update account for token 0
-    pub pyth_token_0_price_update: Box<Account<'info, PriceUpdateV2>>,
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
 
-    /// CHECK: Pyth price update account for token 1
-    pub pyth_token_1_price_update: Box<Account<'info, PriceUpdateV2>>,
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
 
     /* DEX Raydium */
     #[account(address = RAYDIUM_CLMM_ID)]
@@ -232,6 +316,8 @@ pub struct CreateRaydiumVaultStrategy<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     /// Program to create mint account and mint tokens
     pub token_program_2022: Program<'info, Token2022>,
+    /// Program to create the share mint metadata
+    pub token_metadata_program: Program<'info, Metadata>,
     // remaining accounts Raydium
     // #[account(
     //     seeds = [
@@ -254,12 +340,15 @@ impl<'a, 'b, 'c: 'info, 'info> CreateRaydiumVaultStrategy<'info> {
         tick_upper_index: i32,
         tick_array_lower_start_index: i32,
         tick_array_upper_start_index: i32,
-        token_0_feed_id: String,
-        token_1_feed_id: String,
         look_up_table: Pubkey,
+        share_name: String,
+        share_symbol: String,
+        share_uri: String,
         remaining_accounts: &'c [AccountInfo<'info>],
         bump: u8,
     ) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
         require!(
             self.vault_strategy_config.status == VaultStrategyStatus::Draft,
             TokenizedVaultsErrorCode::VaultStrategyConfigNotDraft
@@ -276,12 +365,8 @@ impl<'a, 'b, 'c: 'info, 'info> CreateRaydiumVaultStrategy<'info> {
         )?;
 
         //// Convert amounts to USDC equivalent
-        let (amount_0_max_usd, amount_1_max_usd) = self.token_amounts_in_usd(
-            amount_0_max,
-            amount_1_max,
-            &token_0_feed_id,
-            &token_1_feed_id,
-        )?;
+        let (amount_0_max_usd, amount_1_max_usd) =
+            self.token_amounts_in_usd(amount_0_max, amount_1_max)?;
 
         msg!(
             "Original amounts: token_0={}, token_1={}",
@@ -327,23 +412,71 @@ impl<'a, 'b, 'c: 'info, 'info> CreateRaydiumVaultStrategy<'info> {
             bump,
         )?;
 
+        self.vault_strategy.set_price_feed_ids(
+            self.token_0_oracle_feed.feed_id,
+            self.token_1_oracle_feed.feed_id,
+        )?;
+        self.vault_strategy
+            .set_position_ticks(tick_lower_index, tick_upper_index)?;
+
+        self.init_share_mint(share_name, share_symbol, share_uri)?;
+
         msg!("Vault strategy created successfully");
 
         Ok(())
     }
 
-    fn token_amounts_in_usd(
-        &self,
-        amount_0: u64,
-        amount_1: u64,
-        token_0_feed_id: &str,
-        token_1_feed_id: &str,
-    ) -> Result<(u64, u64)> {
-        //// Get price information from Pyth feeds
-        let token_0_price_info =
-            get_price_from_pyth_update(&self.pyth_token_0_price_update, &token_0_feed_id)?;
-        let token_1_price_info =
-            get_price_from_pyth_update(&self.pyth_token_1_price_update, &token_1_feed_id)?;
+    /// Creates the share mint metadata and mints the creator shares,
+    /// from now on shares are minted on deposit and burned on withdrawal
+    fn init_share_mint(&mut self, name: String, symbol: String, uri: String) -> Result<()> {
+        self.vault_strategy.set_share_mint(self.share_mint.key())?;
+
+        ShareMintMetadataCpi {
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            metadata: self.share_mint_metadata.to_account_info(),
+            share_mint: self.share_mint.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            payer: self.creator.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+        .create(name, symbol, uri)?;
+
+        let creator_shares = self.investor_strategy_position.shares;
+        msg!("creator_shares: {}", creator_shares);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        mint_shares(
+            &self.share_mint,
+            &self.creator_share_account,
+            creator_shares,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+        )
+    }
+
+    fn token_amounts_in_usd(&self, amount_0: u64, amount_1: u64) -> Result<(u64, u64)> {
+        //// Get price information from the registered oracle sources, stale or uncertain prices are rejected
+        let (token_0_price_info, token_1_price_info) = get_price_infos(
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )?;
 
         //// Convert amounts to USD equivalent
         convert_amounts_to_usd(
@@ -435,9 +568,10 @@ pub fn handler<'a, 'b, 'c, 'info>(
     tick_upper_index: i32,
     tick_array_lower_start_index: i32,
     tick_array_upper_start_index: i32,
-    token_0_feed_id: String,
-    token_1_feed_id: String,
     look_up_table: Pubkey,
+    share_name: String,
+    share_symbol: String,
+    share_uri: String,
 ) -> Result<()>
 where
     'c: 'info,
@@ -452,9 +586,10 @@ where
         tick_upper_index,
         tick_array_lower_start_index,
         tick_array_upper_start_index,
-        token_0_feed_id,
-        token_1_feed_id,
         look_up_table,
+        share_name,
+        share_symbol,
+        share_uri,
         ctx.remaining_accounts,
         bump,
     )
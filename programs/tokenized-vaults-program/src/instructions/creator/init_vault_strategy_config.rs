@@ -6,7 +6,7 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount},
 };
 
-use crate::{error::TokenizedVaultsErrorCode, state::*, ProtocolStatus, VaultStrategyType};
+use crate::{assert_protocol_active, state::*, VaultStrategyType};
 
 #[derive(Accounts)]
 #[instruction(name: String)]
@@ -61,10 +61,7 @@ impl<'info> InitVaultStrategyConfig<'info> {
         vault_strategy_type: VaultStrategyType,
         bump: u8,
     ) -> Result<()> {
-        require!(
-            self.protocol_config.status == ProtocolStatus::Active,
-            TokenizedVaultsErrorCode::ProtocolConfigNotInitialized
-        );
+        assert_protocol_active(&self.protocol_config.status)?;
 
         self.vault_strategy_config.initialize(
             self.creator.key(),
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::Mint;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::ShareMintMetadataCpi;
+use crate::{VaultStrategy, VaultStrategyConfig};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct InitVaultStrategyShareMint<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    /// A strategy created before the share mint existed, grown by `migrate_account`
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.mint_0.as_ref(),
+            vault_strategy.mint_1.as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The share mint of the vault strategy
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            VaultStrategy::SHARE_MINT_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        mint::decimals = VaultStrategy::SHARE_MINT_DECIMALS,
+        mint::authority = vault_strategy_config,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Metaplex metadata account of the share mint, created by the metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            share_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub share_mint_metadata: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> InitVaultStrategyShareMint<'info> {
+    /// Creates the share mint of a strategy opened before `create_raydium_vault_strategy`
+    /// created it. The shares of the existing positions are then minted to their investors
+    /// by `migrate_investor_shares`, the creator position included
+    pub fn init(&mut self, name: String, symbol: String, uri: String) -> Result<()> {
+        self.vault_strategy.set_share_mint(self.share_mint.key())?;
+
+        ShareMintMetadataCpi {
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            metadata: self.share_mint_metadata.to_account_info(),
+            share_mint: self.share_mint.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            payer: self.creator.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+        .create(name, symbol, uri)
+    }
+}
+
+pub fn handler(
+    ctx: Context<InitVaultStrategyShareMint>,
+    _strategy_id: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    ctx.accounts.init(name, symbol, uri)
+}
@@ -7,3 +7,27 @@ pub use create_raydium_vault_strategy::*;
 
 pub mod activate_vault_strategy_config;
 pub use activate_vault_strategy_config::*;
+
+pub mod close_vault;
+pub use close_vault::*;
+
+pub mod close_raydium_vault_strategy;
+pub use close_raydium_vault_strategy::*;
+
+pub mod close_raydium_position;
+pub use close_raydium_position::*;
+
+pub mod claim_performance_fees;
+pub use claim_performance_fees::*;
+
+pub mod set_keeper;
+pub use set_keeper::*;
+
+pub mod rebalance_raydium_vault_strategy;
+pub use rebalance_raydium_vault_strategy::*;
+
+pub mod init_vault_strategy_share_mint;
+pub use init_vault_strategy_share_mint::*;
+
+pub mod set_max_price_deviation;
+pub use set_max_price_deviation::*;
@@ -0,0 +1,724 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use raydium_clmm_cpi::cpi;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::tick_math;
+use crate::utils::reclaim_rent;
+use crate::{
+    assert_protocol_active, get_liquidity_from_amounts, ProtocolConfig, RaydiumPositionCpi,
+    RaydiumSwapCpi, RaydiumSwapSide, StrategyFeesTransfer, Twap, VaultStrategy,
+    VaultStrategyConfig, VaultStrategyStatus, RAYDIUM_CLMM_ID, STRATEGY_POOL_TWAP_WINDOW,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct RebalanceRaydiumVaultStrategy<'info> {
+    /// The vault creator or its keeper, pays the rent of the new position
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Receives the rent raydium refunds when the current position is closed
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The escrow account for the token 0
+    /// Holds the token 0 pulled from the old position until it is added to the new one
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Holds the token 1 pulled from the old position until it is added to the new one
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the collected token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the collected token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// The factory state of the pool, used by the swap
+    pub raydium_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The program account for the most recent oracle observation, used by the swap
+    /// and for the TWAP the rebalance is checked against
+    #[account(mut, address = raydium_pool_state.load()?.observation_key)]
+    pub raydium_observation_state: AccountLoader<'info, ObservationState>,
+
+    /// CHECK: The current raydium position NFT mint, burned once the position is emptied
+    #[account(
+        mut,
+        address = vault_strategy.dex_nft_mint @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_position_nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token account that holds the current raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// The current raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Store the information of raydium market marking in range for the current position
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Tick array of the current position lower tick
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK: Tick array of the current position upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK: Unique raydium token mint address of the new position, random keypair
+    #[account(mut)]
+    pub raydium_new_position_nft_mint: Signer<'info>,
+
+    /// CHECK: Token account where the new raydium position NFT will be minted to
+    #[account(mut)]
+    pub raydium_new_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: raydium personal position state of the new position
+    #[account(mut)]
+    pub raydium_new_personal_position: UncheckedAccount<'info>,
+
+    /// CHECK: Store the information of raydium market marking in range for the new position
+    #[account(mut)]
+    pub raydium_new_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Tick array of the new position lower tick
+    #[account(mut)]
+    pub raydium_new_tick_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Tick array of the new position upper tick
+    #[account(mut)]
+    pub raydium_new_tick_array_upper: UncheckedAccount<'info>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Sysvar for token mint and ATA creation
+    pub rent: Sysvar<'info, Rent>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// Program to create an ATA for receiving position NFT
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // Pubkey::default() as separator
+    // swap tick_array_account_1
+    // swap tick_array_account_...
+}
+
+impl<'a, 'b, 'c: 'info, 'info> RebalanceRaydiumVaultStrategy<'info> {
+    /// Moves the strategy liquidity to a new tick range once the price left the current one:
+    /// 1. collect the fees, pay the protocol and performance cuts, the investors part is compounded
+    /// 2. decrease all the liquidity of the current position and close it, its rent goes to the authority
+    /// 3. swap the proceeds to the new ratio, amounts and direction are computed off-chain
+    /// 4. open a new position at the new ticks and add all the liquidity to it
+    /// Investor shares and vault assets are not changed.
+    pub fn process(
+        &mut self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        swap_amount_in: u64,
+        swap_amount_out_min: u64,
+        swap_zero_for_one: bool,
+        remaining_accounts: &'c [AccountInfo<'info>],
+    ) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        self.vault_strategy_config
+            .assert_creator_or_keeper(&self.authority.key())?;
+
+        require!(
+            self.vault_strategy_config.status != VaultStrategyStatus::Closed,
+            TokenizedVaultsErrorCode::VaultStrategyConfigAlreadyClosed
+        );
+
+        self.assert_rebalance_ticks(tick_lower_index, tick_upper_index)?;
+
+        let (position_remaining_accounts, swap_remaining_accounts) =
+            Self::split_remaining_accounts(remaining_accounts);
+
+        let strategy_fees = self.strategy_fees();
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            position_remaining_accounts,
+        )?;
+
+        // the investors part is added to the new position from the rebalance escrows
+        strategy_fees.transfer(
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+            &self.vault_strategy_cfg_mint_0_rebalance_escrow,
+            &self.vault_strategy_cfg_mint_1_rebalance_escrow,
+        )?;
+
+        let liquidity = self.raydium_personal_position.liquidity;
+        msg!("old position liquidity: {}", liquidity);
+
+        if liquidity > 0 {
+            self.raydium_position().decrease_liquidity(
+                liquidity,
+                amount_0_min,
+                amount_1_min,
+                &self
+                    .vault_strategy_cfg_mint_0_rebalance_escrow
+                    .to_account_info(),
+                &self
+                    .vault_strategy_cfg_mint_1_rebalance_escrow
+                    .to_account_info(),
+                position_remaining_accounts,
+            )?;
+        }
+
+        self.close_position()?;
+
+        if swap_amount_in > 0 {
+            require!(
+                !swap_remaining_accounts.is_empty(),
+                TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+            );
+            self.swap(
+                swap_amount_in,
+                swap_amount_out_min,
+                swap_zero_for_one,
+                swap_remaining_accounts,
+            )?;
+        }
+
+        self.open_position(
+            tick_lower_index,
+            tick_upper_index,
+            tick_array_lower_start_index,
+            tick_array_upper_start_index,
+            position_remaining_accounts,
+        )?;
+
+        let new_liquidity = self.increase_liquidity(
+            tick_lower_index,
+            tick_upper_index,
+            position_remaining_accounts,
+        )?;
+
+        self.vault_strategy.rebalance(
+            self.raydium_new_position_nft_mint.key(),
+            tick_lower_index,
+            tick_upper_index,
+            new_liquidity,
+        )?;
+
+        msg!("Vault strategy rebalanced successfully");
+        Ok(())
+    }
+
+    /// The current position must be out of range and the new one must contain the price,
+    /// both at the pool TWAP so that a short-lived move can't trigger the rebalance.
+    /// The new position is opened at the spot price, which must not deviate from the TWAP
+    /// by more than the maximum of the vault
+    fn assert_rebalance_ticks(&self, tick_lower_index: i32, tick_upper_index: i32) -> Result<()> {
+        let pool_state = self.raydium_pool_state.load()?;
+        let twap = Twap::from_pool(
+            &pool_state,
+            &*self.raydium_observation_state.load()?,
+            STRATEGY_POOL_TWAP_WINDOW,
+        )?;
+        msg!("twap tick: {}", twap.tick);
+
+        require!(
+            twap.is_out_of_range(
+                self.raydium_personal_position.tick_lower_index,
+                self.raydium_personal_position.tick_upper_index,
+            ),
+            TokenizedVaultsErrorCode::RaydiumPositionInRange
+        );
+
+        require!(
+            tick_lower_index < tick_upper_index
+                && !twap.is_out_of_range(tick_lower_index, tick_upper_index),
+            TokenizedVaultsErrorCode::InvalidTickRange
+        );
+
+        if self.vault_strategy_config.has_price_deviation_check() {
+            self.vault_strategy_config.check_pool_price_deviation(
+                self.vault_strategy.key(),
+                pool_state.sqrt_price_x64,
+                twap.deviation_of(pool_state.sqrt_price_x64)?,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Remaining accounts of the position instructions and of the swap are separated by Pubkey::default()
+    fn split_remaining_accounts(
+        remaining_accounts: &'c [AccountInfo<'info>],
+    ) -> (&'c [AccountInfo<'info>], &'c [AccountInfo<'info>]) {
+        match remaining_accounts
+            .iter()
+            .position(|account_info| account_info.key() == Pubkey::default())
+        {
+            Some(split_index) => (
+                &remaining_accounts[..split_index],
+                &remaining_accounts[split_index + 1..],
+            ),
+            None => (remaining_accounts, &[]),
+        }
+    }
+
+    /// Burns the emptied position NFT and closes its personal position,
+    /// the authority pays the rent of the new one and gets the rent of the old one back
+    fn close_position(&mut self) -> Result<()> {
+        self.raydium_position().close_position(
+            &self.raydium_position_nft_mint.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        let reclaimed_lamports = reclaim_rent(
+            &self.vault_strategy_config.to_account_info(),
+            &self.authority.to_account_info(),
+        )?;
+        msg!("reclaimed_lamports: {}", reclaimed_lamports);
+        Ok(())
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    fn raydium_new_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_new_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_new_protocol_position.to_account_info(),
+            personal_position: self.raydium_new_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_new_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_new_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    fn strategy_fees(&self) -> StrategyFeesTransfer<'_, 'info> {
+        StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        }
+    }
+
+    fn swap(
+        &mut self,
+        amount_in: u64,
+        amount_out_min: u64,
+        zero_for_one: bool,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        let side_0 = RaydiumSwapSide {
+            token_account: self
+                .vault_strategy_cfg_mint_0_rebalance_escrow
+                .to_account_info(),
+            vault: self.raydium_token_vault_0.to_account_info(),
+            mint: self.raydium_vault_0_mint.to_account_info(),
+        };
+        let side_1 = RaydiumSwapSide {
+            token_account: self
+                .vault_strategy_cfg_mint_1_rebalance_escrow
+                .to_account_info(),
+            vault: self.raydium_token_vault_1.to_account_info(),
+            mint: self.raydium_vault_1_mint.to_account_info(),
+        };
+        let (input, output, before_balance) = if zero_for_one {
+            (
+                side_0,
+                side_1,
+                self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+            )
+        } else {
+            (
+                side_1,
+                side_0,
+                self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+            )
+        };
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            observation_state: self.raydium_observation_state.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &input,
+            &output,
+            amount_in,
+            amount_out_min,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        self.vault_strategy_cfg_mint_0_rebalance_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_rebalance_escrow.reload()?;
+
+        let after_balance = if zero_for_one {
+            self.vault_strategy_cfg_mint_1_rebalance_escrow.amount
+        } else {
+            self.vault_strategy_cfg_mint_0_rebalance_escrow.amount
+        };
+        let amount_out = after_balance.saturating_sub(before_balance);
+
+        msg!(
+            "Rebalance swap: zero_for_one={}, amount_in={}, amount_out={}",
+            zero_for_one,
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    /// Opens the new position NFT without liquidity, the liquidity is added by
+    /// increase_liquidity with the vault strategy config as token owner
+    fn open_position(
+        &mut self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        remaining_accounts: &'c [AccountInfo<'info>],
+    ) -> Result<()> {
+        let cpi_accounts = cpi::accounts::OpenPositionWithToken22Nft {
+            payer: self.authority.to_account_info(),
+            position_nft_owner: self.vault_strategy_config.to_account_info(),
+            position_nft_mint: self.raydium_new_position_nft_mint.to_account_info(),
+            position_nft_account: self.raydium_new_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_new_protocol_position.to_account_info(),
+            tick_array_lower: self.raydium_new_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_new_tick_array_upper.to_account_info(),
+            personal_position: self.raydium_new_personal_position.to_account_info(),
+            token_account_0: self
+                .vault_strategy_cfg_mint_0_rebalance_escrow
+                .to_account_info(),
+            token_account_1: self
+                .vault_strategy_cfg_mint_1_rebalance_escrow
+                .to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            rent: self.rent.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+        };
+
+        let cpi_context =
+            CpiContext::new(self.raydium_clmm_program.to_account_info(), cpi_accounts)
+                .with_remaining_accounts(remaining_accounts.to_vec());
+
+        cpi::open_position_with_token22_nft(
+            cpi_context,
+            tick_lower_index,
+            tick_upper_index,
+            tick_array_lower_start_index,
+            tick_array_upper_start_index,
+            0,
+            0,
+            0,
+            false,
+            None,
+        )
+    }
+
+    fn increase_liquidity(
+        &mut self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u128> {
+        self.vault_strategy_cfg_mint_0_rebalance_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_rebalance_escrow.reload()?;
+
+        let amount_0_max = self.vault_strategy_cfg_mint_0_rebalance_escrow.amount;
+        let amount_1_max = self.vault_strategy_cfg_mint_1_rebalance_escrow.amount;
+
+        let liquidity = {
+            let pool_state = self.raydium_pool_state.load()?;
+            let current_sqrt_price = pool_state.sqrt_price_x64;
+            let low_sqrt_price = tick_math::get_sqrt_price_at_tick(tick_lower_index)?;
+            let high_sqrt_price = tick_math::get_sqrt_price_at_tick(tick_upper_index)?;
+
+            get_liquidity_from_amounts(
+                current_sqrt_price,
+                low_sqrt_price,
+                high_sqrt_price,
+                amount_0_max,
+                amount_1_max,
+            )
+        };
+
+        msg!("new position liquidity: {}", liquidity);
+        require!(
+            liquidity > 0,
+            TokenizedVaultsErrorCode::LiquidityCalculatedToZero
+        );
+
+        self.raydium_new_position().increase_liquidity(
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            &self
+                .vault_strategy_cfg_mint_0_rebalance_escrow
+                .to_account_info(),
+            &self
+                .vault_strategy_cfg_mint_1_rebalance_escrow
+                .to_account_info(),
+            remaining_accounts,
+        )?;
+
+        Ok(liquidity)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RebalanceRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_array_lower_start_index: i32,
+    tick_array_upper_start_index: i32,
+    amount_0_min: u64,
+    amount_1_min: u64,
+    swap_amount_in: u64,
+    swap_amount_out_min: u64,
+    swap_zero_for_one: bool,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.process(
+        tick_lower_index,
+        tick_upper_index,
+        tick_array_lower_start_index,
+        tick_array_upper_start_index,
+        amount_0_min,
+        amount_1_min,
+        swap_amount_in,
+        swap_amount_out_min,
+        swap_zero_for_one,
+        ctx.remaining_accounts,
+    )
+}
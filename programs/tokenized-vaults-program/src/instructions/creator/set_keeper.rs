@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::TokenizedVaultsErrorCode, state::VaultStrategyConfig};
+
+#[derive(Accounts)]
+pub struct SetKeeper<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized
+    )]
+    pub vault_strategy_config: Account<'info, VaultStrategyConfig>,
+}
+
+impl<'info> SetKeeper<'info> {
+    /// Pubkey::default() removes the keeper
+    pub fn set_keeper(&mut self, keeper: Pubkey) -> Result<()> {
+        self.vault_strategy_config.set_keeper(keeper)
+    }
+}
+
+pub fn handler(ctx: Context<SetKeeper>, keeper: Pubkey) -> Result<()> {
+    ctx.accounts.set_keeper(keeper)
+}
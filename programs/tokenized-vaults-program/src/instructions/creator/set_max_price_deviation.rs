@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::TokenizedVaultsErrorCode, state::VaultStrategyConfig};
+
+#[derive(Accounts)]
+pub struct SetMaxPriceDeviation<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            creator.key().as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+        has_one = creator @ TokenizedVaultsErrorCode::Unauthorized
+    )]
+    pub vault_strategy_config: Account<'info, VaultStrategyConfig>,
+}
+
+impl<'info> SetMaxPriceDeviation<'info> {
+    /// 0 disables the pool price deviation check
+    pub fn set_max_price_deviation(&mut self, max_price_deviation: u32) -> Result<()> {
+        self.vault_strategy_config
+            .set_max_price_deviation(max_price_deviation)
+    }
+}
+
+pub fn handler(ctx: Context<SetMaxPriceDeviation>, max_price_deviation: u32) -> Result<()> {
+    ctx.accounts.set_max_price_deviation(max_price_deviation)
+}
@@ -3,26 +3,30 @@ use std::ops::Mul;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::memo::Memo;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::{self, Token};
 
 use crate::libraries::tick_math;
-use crate::utils::transfer_token;
+use crate::utils::{mint_shares, transfer_token, TokenPriceInfo};
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use num_bigint::BigInt;
-use raydium_clmm_cpi::cpi;
-use raydium_clmm_cpi::cpi::accounts::SwapSingleV2;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use raydium_clmm_cpi::program::RaydiumClmm;
 use raydium_clmm_cpi::states::{
-    AmmConfig, ObservationState, PersonalPositionState, PoolState, POSITION_SEED,
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState, POSITION_SEED,
 };
 
 use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
 use crate::{
-    get_delta_amounts_signed, get_liquidity_from_amount_0, get_liquidity_from_amount_1,
-    get_liquidity_from_amounts, vault_strategy_config, InvestReserveVault, InvestorEscrow,
-    InvestorStrategyPosition, SwapToRatioVault, VaultStrategy, VaultStrategyConfig,
-    DENOMINATOR_MULTIPLIER, MAX_PERCENTAGE, RAYDIUM_CLMM_ID, U256, USDC_MINT,
+    assert_protocol_active, get_delta_amounts_signed, get_liquidity_from_amount_0,
+    get_liquidity_from_amount_1, get_liquidity_from_amounts, get_pool_checked_price_infos,
+    get_vault_strategy_holdings, vault_strategy_config, InvestReserveVault, InvestorEscrow,
+    InvestorStrategyPosition, OracleFeed, PoolPricedInstruction, ProtocolConfig,
+    RaydiumPositionCpi, RaydiumSwapCpi, RaydiumSwapSide, StrategyFeesTransfer, SwapToRatioVault,
+    SwapToRatioVaultExecutedEvent, VaultOperation, VaultStrategy, VaultStrategyConfig,
+    VaultStrategyHoldings, VaultStrategyNavEvent, DENOMINATOR_MULTIPLIER, MAX_PERCENTAGE,
+    RAYDIUM_CLMM_ID, U256, USDC_MINT,
 };
 
 #[derive(Accounts)]
@@ -31,6 +35,12 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(mut)]
     pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
 
@@ -67,6 +77,22 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
     )]
     pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
 
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account receiving the minted shares
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
     /// The escrow account for the token 0
     /// Vault strategy Config receives token 0 in this account from Raydium Swap
     #[account(
@@ -91,6 +117,147 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
     )]
     pub vault_strategy_cfg_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// The investor USDC escrow, receives the remainder swapped back to USDC
+    #[account(
+        mut,
+        seeds = [
+            InvestorEscrow::VAULT_SEED.as_bytes(),
+            investor.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 0, receives the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1, receives the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ TokenizedVaultsErrorCode::InvalidMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The fees escrow account for the token 0
+    /// Vault strategy Config collects the token 0 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 1
+    /// Vault strategy Config collects the token 1 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    /// Protocol Config receives its cut of the token 0 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    /// Protocol Config receives its cut of the token 1 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
     /* DEX Raydium */
     #[account(address = RAYDIUM_CLMM_ID)]
     pub raydium_clmm_program: Program<'info, RaydiumClmm>,
@@ -118,11 +285,11 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
 
     /// CHECK: Account to mark the raydium position's lower tick as initialized
     #[account(mut)]
-    pub raydium_tick_array_lower: UncheckedAccount<'info>,
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
 
     /// CHECK:Account to store data for the raydium position's upper tick
     #[account(mut)]
-    pub raydium_tick_array_upper: UncheckedAccount<'info>,
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
 
     /// The address that holds raydium pool tokens for token_0
     #[account(mut)]
@@ -146,6 +313,49 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
     #[account()]
     pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
     /// SPL program for token transfers
     pub token_program: Program<'info, Token>,
 
@@ -159,7 +369,15 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
 
     /// System program
     pub system_program: Program<'info, System>,
-    // remaining account
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // Pubkey::default() as separator
+    // optional Token 0 / USDC swap: amm_config, pool_state, input_vault, output_vault,
+    // observation_state, tick_array_account_...
+    // Pubkey::default() as separator
+    // optional Token 1 / USDC swap: same layout as Token 0
+    // The remainder of a leg is swapped back to USDC when its swap accounts are given,
+    // otherwise it is refunded to the investor token account
     // #[account(
     //     seeds = [
     //         POOL_TICK_ARRAY_BITMAP_SEED.as_bytes(),
@@ -171,11 +389,20 @@ pub struct AddLiquidityRaydiumVaultStrategy<'info> {
 }
 
 impl<'info> AddLiquidityRaydiumVaultStrategy<'info> {
+    /// The remainder of each leg swapped back to USDC has to return at least
+    /// `refund_0_usdc_amount_out_min` and `refund_1_usdc_amount_out_min`
     pub fn process(
         &mut self,
         investor_strategy_position_bump: u8,
-        remaining_accounts: &[AccountInfo<'info>],
+        refund_0_usdc_amount_out_min: u64,
+        refund_1_usdc_amount_out_min: u64,
+        remaining_accounts: &'info [AccountInfo<'info>],
     ) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Deposit, &self.investor.key())?;
+
         let (amount_in, amount_0_max, amount_1_max) = self
             .invest_reserve_vault
             .swap_to_ratio_vaults
@@ -197,7 +424,82 @@ impl<'info> AddLiquidityRaydiumVaultStrategy<'info> {
             amount_1_max
         );
 
-        self.increase_liquidity(amount_0_max, amount_1_max, remaining_accounts)?;
+        let mut groups =
+            remaining_accounts.split(|account_info| account_info.key() == Pubkey::default());
+        let position_remaining_accounts = groups.next().unwrap_or_default();
+        let token_0_for_usdc_remaining_accounts = groups.next().unwrap_or_default();
+        let token_1_for_usdc_remaining_accounts = groups.next().unwrap_or_default();
+
+        // Fees earned so far belong to the current shares, they are allocated
+        // before the new shares exist
+        self.accrue_fees(position_remaining_accounts)?;
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            investor_strategy_position_bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        let (token_0_price_info, token_1_price_info) = self
+            .checked_price_infos()?
+            .ok_or(TokenizedVaultsErrorCode::InvalidPrice)?;
+        let nav = self.nav(&token_0_price_info, &token_1_price_info)?;
+        msg!("nav: {}", nav);
+
+        let before_balance_0 = self.vault_strategy_cfg_mint_0_escrow.amount;
+        let before_balance_1 = self.vault_strategy_cfg_mint_1_escrow.amount;
+
+        self.increase_liquidity(amount_0_max, amount_1_max, position_remaining_accounts)?;
+
+        self.vault_strategy_cfg_mint_0_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_escrow.reload()?;
+
+        let amount_0 =
+            before_balance_0.saturating_sub(self.vault_strategy_cfg_mint_0_escrow.amount);
+        let amount_1 =
+            before_balance_1.saturating_sub(self.vault_strategy_cfg_mint_1_escrow.amount);
+        let refund_0 = amount_0_max.saturating_sub(amount_0);
+        let refund_1 = amount_1_max.saturating_sub(amount_1);
+
+        msg!("consumed amount_0: {}, refund_0: {}", amount_0, refund_0);
+        msg!("consumed amount_1: {}, refund_1: {}", amount_1, refund_1);
+
+        self.refund_remainder(
+            refund_0,
+            &self.vault_strategy_cfg_mint_0_escrow,
+            &self.investor_mint_0_account,
+            &self.raydium_vault_0_mint,
+            refund_0_usdc_amount_out_min,
+            token_0_for_usdc_remaining_accounts,
+        )?;
+        self.refund_remainder(
+            refund_1,
+            &self.vault_strategy_cfg_mint_1_escrow,
+            &self.investor_mint_1_account,
+            &self.raydium_vault_1_mint,
+            refund_1_usdc_amount_out_min,
+            token_1_for_usdc_remaining_accounts,
+        )?;
+
+        self.invest_reserve_vault.set_swap_to_ratio_consumed(
+            self.vault_strategy.key(),
+            amount_0,
+            amount_1,
+        )?;
+
+        emit!(SwapToRatioVaultExecutedEvent {
+            investor: self.investor.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            amount_in,
+            amount_0,
+            amount_1,
+            refund_0,
+            refund_1,
+        });
 
         if let Some(strategy) = self.vault_strategy_config.strategies.last() {
             if self.vault_strategy.key() == strategy.key() {
@@ -211,33 +513,259 @@ impl<'info> AddLiquidityRaydiumVaultStrategy<'info> {
                 .set_swap_to_ratio_executed(self.vault_strategy.key(), true)?;
         }
 
+        // The deposit is valued at what was actually added to the position
+        let deposited_assets = VaultStrategyHoldings { amount_0, amount_1 }.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            &token_0_price_info,
+            &token_1_price_info,
+        )?;
+        msg!("deposited_assets: {}", deposited_assets);
+
+        self.vault_strategy.mark_to_market(nav)?;
+
         let total_assets = self.vault_strategy.total_assets;
         let total_shares = self.vault_strategy.total_shares;
+        let shares_before = self.investor_strategy_position.shares;
 
-        if self.investor_strategy_position.vault_strategy_key == Pubkey::default() {
-            self.investor_strategy_position.initialize(
-                self.investor.key(),
-                self.vault_strategy.key(),
-                amount_in,
-                total_assets,
-                total_shares,
-                investor_strategy_position_bump,
-            )?;
-        } else {
-            self.investor_strategy_position.deposit_assets(
-                amount_in,
-                total_assets,
-                total_shares,
-            )?;
-        }
-        self.vault_strategy.add_assets(amount_in)?;
-        self.vault_strategy
-            .add_shares(self.investor_strategy_position.shares)?;
+        self.investor_strategy_position.deposit_assets(
+            deposited_assets,
+            total_assets,
+            total_shares,
+        )?;
+        let minted_shares = self
+            .investor_strategy_position
+            .shares
+            .saturating_sub(shares_before);
+
+        self.vault_strategy.add_assets(deposited_assets)?;
+        self.vault_strategy.add_shares(minted_shares)?;
+
+        emit!(VaultStrategyNavEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            nav,
+            total_shares,
+        });
+
+        self.mint_shares(minted_shares)?;
 
         msg!("Liquidity added successfully");
         Ok(())
     }
 
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::AddLiquidity,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    /// Mark-to-market value of the strategy in USDC, before the deposit is added.
+    /// The rebalance escrows belong to the strategy, their whole balance backs its shares
+    fn nav(
+        &self,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        let holdings = {
+            let pool_state = self.raydium_pool_state.load()?;
+
+            get_vault_strategy_holdings(
+                &pool_state,
+                &self.raydium_personal_position,
+                (
+                    self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+                    self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+                ),
+            )?
+        };
+
+        holdings.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            token_0_price_info,
+            token_1_price_info,
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    /// Collects the position fees into the fees escrows, the creator and protocol cuts leave
+    /// them right away and the investor part is spread over the current shares
+    fn accrue_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let strategy_fees = StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        };
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        strategy_fees.accrue(
+            &mut self.vault_strategy,
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+        )
+    }
+
+    /// Sends the part of a leg that was not added as liquidity back to the investor,
+    /// so it is not left in the shared swap escrow. With swap accounts it is swapped
+    /// to USDC into the investor escrow, in a {mint, USDC} pool and for at least
+    /// `usdc_amount_out_min`
+    fn refund_remainder(
+        &self,
+        amount: u64,
+        escrow: &InterfaceAccount<'info, TokenAccount>,
+        investor_account: &InterfaceAccount<'info, TokenAccount>,
+        mint: &InterfaceAccount<'info, Mint>,
+        usdc_amount_out_min: u64,
+        swap_remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        if mint.key() == self.usdc_mint.key() {
+            return transfer_token(
+                escrow,
+                &self.escrow_vault,
+                amount,
+                mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            );
+        }
+
+        if swap_remaining_accounts.is_empty() {
+            return transfer_token(
+                escrow,
+                investor_account,
+                amount,
+                mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            );
+        }
+
+        // amm_config, pool_state, input_vault, output_vault, observation_state and tick arrays
+        require!(
+            swap_remaining_accounts.len() > 5,
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        {
+            let swap_pool_state =
+                AccountLoader::<PoolState>::try_from(&swap_remaining_accounts[1])?;
+            let swap_pool_state = swap_pool_state.load()?;
+            let pool_mints = (swap_pool_state.token_mint_0, swap_pool_state.token_mint_1);
+            require!(
+                pool_mints == (mint.key(), self.usdc_mint.key())
+                    || pool_mints == (self.usdc_mint.key(), mint.key()),
+                TokenizedVaultsErrorCode::InvalidSwapPool
+            );
+        }
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: swap_remaining_accounts[0].clone(),
+            pool_state: swap_remaining_accounts[1].clone(),
+            observation_state: swap_remaining_accounts[4].clone(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: escrow.to_account_info(),
+                vault: swap_remaining_accounts[2].clone(),
+                mint: mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: self.escrow_vault.to_account_info(),
+                vault: swap_remaining_accounts[3].clone(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            amount,
+            usdc_amount_out_min,
+            &swap_remaining_accounts[5..],
+            Some(signer_seeds),
+        )
+    }
+
+    fn mint_shares(&mut self, shares: u64) -> Result<()> {
+        msg!("minted_shares: {}", shares);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        mint_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            shares,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+        )
+    }
+
     fn increase_liquidity(
         &mut self,
         amount_0_max: u64,
@@ -263,63 +791,13 @@ impl<'info> AddLiquidityRaydiumVaultStrategy<'info> {
             )
         };
 
-        let vault_strategy_config_bump_seed = &[self.vault_strategy_config.bump];
-        let vault_strategy_config_seeds = &[
-            VaultStrategyConfig::SEED.as_bytes(),
-            self.vault_strategy_config.creator.as_ref(),
-            self.vault_strategy_config.name.as_ref(),
-            vault_strategy_config_bump_seed,
-        ];
-
-        let signer_seeds = &[&vault_strategy_config_seeds[..]];
-
-        let cpi_accounts = cpi::accounts::IncreaseLiquidityV2 {
-            nft_owner: self.vault_strategy_config.to_account_info(),
-            nft_account: self.raydium_position_nft_account.to_account_info(),
-            pool_state: self.raydium_pool_state.to_account_info(),
-            protocol_position: self.raydium_protocol_position.to_account_info(),
-            personal_position: self.raydium_personal_position.to_account_info(),
-            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
-            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
-            token_account_0: self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
-            token_account_1: self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
-            token_vault_0: self.raydium_token_vault_0.to_account_info(),
-            token_vault_1: self.raydium_token_vault_1.to_account_info(),
-            token_program: self.token_program.to_account_info(),
-            token_program_2022: self.token_program_2022.to_account_info(),
-            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
-            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
-        };
-        let cpi_context = CpiContext::new_with_signer(
-            self.raydium_clmm_program.to_account_info(),
-            cpi_accounts,
-            signer_seeds,
-        )
-        .with_remaining_accounts(remaining_accounts.to_vec());
-
-        // let percentage: f64 = 5.0;
-        // let slippage_tolerance = U256::from(percentage.mul(1e6) as u64);
-        // let amount_0_slippage = U256::from(amount_0_max)
-        //     .checked_mul(slippage_tolerance)
-        //     .unwrap()
-        //     .checked_div(U256::from(100_000_000))
-        //     .unwrap();
-        // let amount_1_slippage = U256::from(amount_1_max)
-        //     .checked_mul(slippage_tolerance)
-        //     .unwrap()
-        //     .checked_div(U256::from(100_000_000))
-        //     .unwrap();
-
-        // let amount_0_max = amount_0_max.checked_add(amount_0_slippage.as_u64()).unwrap();
-        // let amount_1_max = amount_1_max.checked_add(amount_1_slippage.as_u64()).unwrap();
-        // msg!("amount_0_max with slippage : {}", amount_0_max);
-        // msg!("amount_1_max with slippage : {}", amount_1_max);
-        cpi::increase_liquidity_v2(
-            cpi_context,
+        self.raydium_position().increase_liquidity(
             liquidity,
             amount_0_max,
             amount_1_max,
-            Some(true),
+            &self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
+            &self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
+            remaining_accounts,
         )?;
 
         Ok(())
@@ -329,10 +807,16 @@ impl<'info> AddLiquidityRaydiumVaultStrategy<'info> {
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, AddLiquidityRaydiumVaultStrategy<'info>>,
     _strategy_id: u8,
+    refund_0_usdc_amount_out_min: u64,
+    refund_1_usdc_amount_out_min: u64,
 ) -> Result<()>
 where
     'c: 'info,
 {
-    ctx.accounts
-        .process(ctx.bumps.investor_strategy_position, ctx.remaining_accounts)
+    ctx.accounts.process(
+        ctx.bumps.investor_strategy_position,
+        refund_0_usdc_amount_out_min,
+        refund_1_usdc_amount_out_min,
+        ctx.remaining_accounts,
+    )
 }
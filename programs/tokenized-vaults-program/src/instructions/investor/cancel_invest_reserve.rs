@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::transfer_token;
+use crate::{
+    InvestReserveCancelledEvent, InvestReserveVault, InvestorEscrow, VaultStrategyConfig, USDC_MINT,
+};
+
+#[derive(Accounts)]
+pub struct CancelInvestReserve<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            InvestReserveVault::SEED.as_bytes(),
+            investor.key().as_ref(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub invest_reserve_vault: Box<Account<'info, InvestReserveVault>>,
+
+    /// The investor escrow vault, receives the USDC that was never swapped
+    #[account(
+        mut,
+        seeds = [
+            InvestorEscrow::VAULT_SEED.as_bytes(),
+            investor.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the USDC
+    /// Holds the reserved USDC until it is swapped
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_STRATEGY_CFG_USDC_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_usdc_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Holds the swapped token 0 until the liquidity is added
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Holds the swapped token 1 until the liquidity is added
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 0
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ TokenizedVaultsErrorCode::InvalidMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelInvestReserve<'info> {
+    /// Refunds the part of the reserve that was not added as liquidity.
+    /// It is the investor's own money, so it works whatever the protocol and vault status
+    pub fn cancel(&mut self) -> Result<()> {
+        let (usdc_amount, amount_0, amount_1) = self.invest_reserve_vault.unexecuted_amounts()?;
+
+        msg!("refund usdc_amount: {}", usdc_amount);
+        msg!("refund amount_0: {}", amount_0);
+        msg!("refund amount_1: {}", amount_1);
+
+        require!(
+            usdc_amount > 0 || amount_0 > 0 || amount_1 > 0,
+            TokenizedVaultsErrorCode::NoReservedAmount
+        );
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        if usdc_amount > 0 {
+            transfer_token(
+                &self.vault_strategy_cfg_usdc_escrow,
+                &self.escrow_vault,
+                usdc_amount,
+                &self.usdc_mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            )?;
+        }
+
+        if amount_0 > 0 {
+            transfer_token(
+                &self.vault_strategy_cfg_mint_0_escrow,
+                &self.investor_mint_0_account,
+                amount_0,
+                &self.raydium_vault_0_mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            )?;
+        }
+
+        if amount_1 > 0 {
+            transfer_token(
+                &self.vault_strategy_cfg_mint_1_escrow,
+                &self.investor_mint_1_account,
+                amount_1,
+                &self.raydium_vault_1_mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            )?;
+        }
+
+        self.invest_reserve_vault.clean_up()?;
+
+        emit!(InvestReserveCancelledEvent {
+            investor: self.investor.key(),
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            usdc_amount,
+            amount_0,
+            amount_1,
+        });
+
+        msg!("Invest reserve cancelled successfully");
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CancelInvestReserve>) -> Result<()> {
+    ctx.accounts.cancel()
+}
@@ -0,0 +1,427 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
+use crate::utils::{transfer_token, TokenPriceInfo};
+use crate::{
+    get_pool_checked_price_infos, InvestorFeesClaimedEvent, InvestorStrategyPosition, OracleFeed,
+    PoolPricedInstruction, ProtocolConfig, RaydiumPositionCpi, StrategyFeesTransfer,
+    VaultOperation, VaultStrategy, VaultStrategyConfig, RAYDIUM_CLMM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct ClaimInvestorFees<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    #[account(
+        mut,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump = investor_strategy_position.bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// The share mint of the vault strategy
+    #[account(address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint)]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account holding the shares, the fees are claimed on its balance
+    #[account(
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 0, receives the claimed fees
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1, receives the claimed fees
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 0
+    /// Vault strategy Config collects the token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 1
+    /// Vault strategy Config collects the token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    /// Protocol Config receives its cut of the token 0 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    /// Protocol Config receives its cut of the token 1 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// CHECK: Token account holding the raydium position NFT, checked by Raydium
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+}
+
+impl<'info> ClaimInvestorFees<'info> {
+    /// Collects the position fees and pays the investor every fee earned by their shares,
+    /// liquidity and shares are left untouched
+    pub fn claim(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::ClaimFees, &self.investor.key())?;
+        self.checked_price_infos()?;
+
+        self.accrue_fees(remaining_accounts)?;
+
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+        let (fees_owed_0, fees_owed_1) = self.investor_strategy_position.take_fees_owed();
+
+        msg!("investor fees_owed_0: {}", fees_owed_0);
+        msg!("investor fees_owed_1: {}", fees_owed_1);
+
+        require!(
+            fees_owed_0 > 0 || fees_owed_1 > 0,
+            TokenizedVaultsErrorCode::NoFeesToClaim
+        );
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_0_fees_escrow,
+            &self.investor_mint_0_account,
+            fees_owed_0,
+            &self.raydium_vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_1_fees_escrow,
+            &self.investor_mint_1_account,
+            fees_owed_1,
+            &self.raydium_vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        emit!(InvestorFeesClaimedEvent {
+            investor: self.investor.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            amount_0: fees_owed_0,
+            amount_1: fees_owed_1,
+        });
+
+        msg!("Investor fees claimed successfully");
+        Ok(())
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::ClaimInvestorFees,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    /// Collects the position fees into the fees escrows, the creator and protocol cuts leave
+    /// them right away and the investor part is spread over the current shares
+    fn accrue_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let strategy_fees = StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        };
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        strategy_fees.accrue(
+            &mut self.vault_strategy,
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+        )
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimInvestorFees<'info>>,
+    _strategy_id: u8,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.claim(ctx.remaining_accounts)
+}
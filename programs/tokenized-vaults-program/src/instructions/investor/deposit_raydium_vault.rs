@@ -0,0 +1,962 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::tick_math;
+use crate::oracle::TokenOracle;
+use crate::utils::{mint_shares, transfer_token, TokenPriceInfo};
+use crate::{
+    assert_protocol_active, get_liquidity_from_amounts, get_pool_checked_price_infos,
+    get_vault_strategy_holdings, split_amount_to_position_ratio, InvestorEscrow,
+    InvestorStrategyPosition, OracleFeed, PoolPricedInstruction, ProtocolConfig,
+    RaydiumPositionCpi, RaydiumSwapCpi, RaydiumSwapSide, StrategyFeesTransfer, VaultOperation,
+    VaultStrategy, VaultStrategyConfig, VaultStrategyHoldings, VaultStrategyNavEvent,
+    RAYDIUM_CLMM_ID, USDC_MINT,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct DepositRaydiumVault<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// The investor USDC escrow, funded by `deposit_to_escrow`
+    #[account(
+        mut,
+        seeds = [
+            InvestorEscrow::VAULT_SEED.as_bytes(),
+            investor.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = investor,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the USDC
+    /// Vault strategy Config receives USDC in this account from User's escrow vault
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_STRATEGY_CFG_USDC_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_usdc_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives token 0 in this account from Raydium Swap
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives token 1 in this account from Raydium Swap
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 0, receives the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1, receives the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 0
+    /// Vault strategy Config collects the token 0 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 1
+    /// Vault strategy Config collects the token 1 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    /// Protocol Config receives its cut of the token 0 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    /// Protocol Config receives its cut of the token 1 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account receiving the minted shares
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ TokenizedVaultsErrorCode::InvalidMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Token account that holds the raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK:
+    /// The factory state to read protocol fees
+    #[account(mut)]
+    pub raydium_amm_config_usdc_for_token_0: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK:
+    /// The program account of the pool in which the swap will be performed
+    #[account(mut)]
+    pub raydium_pool_state_usdc_for_token_0: AccountLoader<'info, PoolState>,
+
+    /// CHECK:
+    /// The factory state to read protocol fees
+    #[account(mut)]
+    pub raydium_amm_config_usdc_for_token_1: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK:
+    /// The program account of the pool in which the swap will be performed
+    #[account(mut)]
+    pub raydium_pool_state_usdc_for_token_1: AccountLoader<'info, PoolState>,
+
+    /// The vault token account for input token
+    /// Pool: USDC / Token 0
+    #[account(mut)]
+    pub raydium_vault_0_input: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for input token
+    /// Pool: USDC / Token 1
+    #[account(mut)]
+    pub raydium_vault_1_input: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    /// Pool: USDC / Token 0
+    #[account(mut)]
+    pub raydium_vault_0_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    /// Pool: USDC / Token 1
+    #[account(mut)]
+    pub raydium_vault_1_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The program account for the most recent oracle observation
+    /// USDC/Token 0
+    #[account(mut)]
+    pub raydium_observation_state_0: AccountLoader<'info, ObservationState>,
+
+    /// The program account for the most recent oracle observation
+    /// USDC/Token 1
+    #[account(mut)]
+    pub raydium_observation_state_1: AccountLoader<'info, ObservationState>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // USDC / Token 0 swap tick_array_account_...
+    // Pubkey::default() as separator
+    // USDC / Token 1 swap tick_array_account_...
+    // Pubkey::default() as separator
+    // tickarray_bitmap_extension: must add account if need
+}
+
+impl<'info> DepositRaydiumVault<'info> {
+    /// Moves `amount` USDC from the investor escrow, swaps it to the position ratio
+    /// and adds the liquidity in one instruction, the whole deposit reverts
+    /// if less than `min_shares_out` shares are minted
+    pub fn deposit(
+        &mut self,
+        amount: u64,
+        token_0_amount_out_min: u64,
+        token_1_amount_out_min: u64,
+        min_shares_out: u64,
+        investor_strategy_position_bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        require!(amount > 0, TokenizedVaultsErrorCode::InvalidAmount);
+
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Deposit, &self.investor.key())?;
+
+        require!(
+            self.escrow_vault.amount >= amount,
+            TokenizedVaultsErrorCode::InsufficientFunds
+        );
+
+        let mut groups =
+            remaining_accounts.split(|account_info| account_info.key() == Pubkey::default());
+        let remaining_accounts_usdc_for_token_0 = groups.next().unwrap_or_default();
+        let remaining_accounts_usdc_for_token_1 = groups.next().unwrap_or_default();
+        let position_remaining_accounts = groups.next().unwrap_or_default();
+
+        // Fees earned so far belong to the current shares, they are allocated
+        // before the new shares exist
+        self.accrue_fees(position_remaining_accounts)?;
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            investor_strategy_position_bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        // Valued before the swaps, they can move the strategy pool
+        let (token_0_price_info, token_1_price_info) = self
+            .checked_price_infos()?
+            .ok_or(TokenizedVaultsErrorCode::InvalidPrice)?;
+        let nav = self.nav(&token_0_price_info, &token_1_price_info)?;
+        msg!("nav: {}", nav);
+
+        transfer_token(
+            &self.escrow_vault,
+            &self.vault_strategy_cfg_usdc_escrow,
+            amount,
+            &self.usdc_mint,
+            &self.investor,
+            &self.token_program,
+            None,
+        )?;
+        self.vault_strategy_cfg_usdc_escrow.reload()?;
+
+        let (usdc_for_token_0_amount, usdc_for_token_1_amount) = {
+            let pool_state = self.raydium_pool_state.load()?;
+            split_amount_to_position_ratio(
+                pool_state.sqrt_price_x64,
+                self.raydium_personal_position.tick_lower_index,
+                self.raydium_personal_position.tick_upper_index,
+                amount,
+            )?
+        };
+
+        msg!("usdc_for_token_0_amount: {}", usdc_for_token_0_amount);
+        msg!("usdc_for_token_1_amount: {}", usdc_for_token_1_amount);
+
+        let token_0_amount = self.swap_usdc_for_token_0(
+            usdc_for_token_0_amount,
+            token_0_amount_out_min,
+            remaining_accounts_usdc_for_token_0,
+        )?;
+
+        let token_1_amount = self.swap_usdc_for_token_1(
+            usdc_for_token_1_amount,
+            token_1_amount_out_min,
+            remaining_accounts_usdc_for_token_1,
+        )?;
+
+        msg!("Token 0 amount swapped: {}", token_0_amount);
+        msg!("Token 1 amount swapped: {}", token_1_amount);
+
+        let before_balance_0 = self.vault_strategy_cfg_mint_0_escrow.amount;
+        let before_balance_1 = self.vault_strategy_cfg_mint_1_escrow.amount;
+
+        self.increase_liquidity(token_0_amount, token_1_amount, position_remaining_accounts)?;
+
+        self.vault_strategy_cfg_mint_0_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_escrow.reload()?;
+
+        let amount_0 =
+            before_balance_0.saturating_sub(self.vault_strategy_cfg_mint_0_escrow.amount);
+        let amount_1 =
+            before_balance_1.saturating_sub(self.vault_strategy_cfg_mint_1_escrow.amount);
+
+        msg!("consumed amount_0: {}", amount_0);
+        msg!("consumed amount_1: {}", amount_1);
+
+        self.refund_remainder(
+            token_0_amount.saturating_sub(amount_0),
+            &self.vault_strategy_cfg_mint_0_escrow,
+            &self.investor_mint_0_account,
+            &self.raydium_vault_0_mint,
+        )?;
+        self.refund_remainder(
+            token_1_amount.saturating_sub(amount_1),
+            &self.vault_strategy_cfg_mint_1_escrow,
+            &self.investor_mint_1_account,
+            &self.raydium_vault_1_mint,
+        )?;
+
+        // The deposit is valued at what was actually added to the position
+        let deposited_assets = VaultStrategyHoldings { amount_0, amount_1 }.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            &token_0_price_info,
+            &token_1_price_info,
+        )?;
+        msg!("deposited_assets: {}", deposited_assets);
+
+        self.vault_strategy.mark_to_market(nav)?;
+
+        let total_assets = self.vault_strategy.total_assets;
+        let total_shares = self.vault_strategy.total_shares;
+        let shares_before = self.investor_strategy_position.shares;
+
+        self.investor_strategy_position.deposit_assets(
+            deposited_assets,
+            total_assets,
+            total_shares,
+        )?;
+
+        let minted_shares = self
+            .investor_strategy_position
+            .shares
+            .saturating_sub(shares_before);
+
+        msg!("minted_shares: {}", minted_shares);
+        require!(
+            minted_shares >= min_shares_out,
+            TokenizedVaultsErrorCode::SharesBelowMinimum
+        );
+
+        self.vault_strategy.add_assets(deposited_assets)?;
+        self.vault_strategy.add_shares(minted_shares)?;
+
+        emit!(VaultStrategyNavEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            nav,
+            total_shares,
+        });
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        mint_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            minted_shares,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+        )?;
+
+        msg!("Deposit executed successfully");
+        Ok(())
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::DepositRaydiumVault,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    /// Mark-to-market value of the strategy in USDC, before the deposit is added.
+    /// The rebalance escrows belong to the strategy, their whole balance backs its shares
+    fn nav(
+        &self,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        let holdings = {
+            let pool_state = self.raydium_pool_state.load()?;
+
+            get_vault_strategy_holdings(
+                &pool_state,
+                &self.raydium_personal_position,
+                (
+                    self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+                    self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+                ),
+            )?
+        };
+
+        holdings.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            token_0_price_info,
+            token_1_price_info,
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    /// Collects the position fees into the fees escrows, the creator and protocol cuts leave
+    /// them right away and the investor part is spread over the current shares
+    fn accrue_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let strategy_fees = StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        };
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        strategy_fees.accrue(
+            &mut self.vault_strategy,
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+        )
+    }
+
+    /// Sends the part of a leg that was not added as liquidity back to the investor,
+    /// a USDC leg goes back to the investor escrow
+    fn refund_remainder(
+        &self,
+        amount: u64,
+        escrow: &InterfaceAccount<'info, TokenAccount>,
+        investor_account: &InterfaceAccount<'info, TokenAccount>,
+        mint: &InterfaceAccount<'info, Mint>,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        msg!("refund {}: {}", mint.key(), amount);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        let destination = if mint.key() == self.usdc_mint.key() {
+            self.escrow_vault.as_ref()
+        } else {
+            investor_account
+        };
+
+        transfer_token(
+            escrow,
+            destination,
+            amount,
+            mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )
+    }
+
+    fn swap_usdc_for_token_0(
+        &mut self,
+        amount_in: u64,
+        amount_out_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        if amount_in == 0 {
+            return Ok(0);
+        }
+
+        let seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&seeds[..]];
+
+        if self.usdc_mint.key() == self.vault_strategy_cfg_mint_0_escrow.mint {
+            transfer_token(
+                &self.vault_strategy_cfg_usdc_escrow,
+                &self.vault_strategy_cfg_mint_0_escrow,
+                amount_in,
+                &self.usdc_mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            )?;
+            self.vault_strategy_cfg_mint_0_escrow.reload()?;
+            return Ok(amount_in);
+        }
+
+        require!(
+            !remaining_accounts.is_empty(),
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        let before_balance = self.vault_strategy_cfg_mint_0_escrow.amount;
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config_usdc_for_token_0.to_account_info(),
+            pool_state: self.raydium_pool_state_usdc_for_token_0.to_account_info(),
+            observation_state: self.raydium_observation_state_0.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
+                vault: self.raydium_vault_0_input.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
+                vault: self.raydium_vault_0_output.to_account_info(),
+                mint: self.raydium_vault_0_mint.to_account_info(),
+            },
+            amount_in,
+            amount_out_min,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        self.vault_strategy_cfg_mint_0_escrow.reload()?;
+        let amount_out = self
+            .vault_strategy_cfg_mint_0_escrow
+            .amount
+            .saturating_sub(before_balance);
+
+        msg!(
+            "Token 0 swap: amount_in={}, amount_out={}",
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    fn swap_usdc_for_token_1(
+        &mut self,
+        amount_in: u64,
+        amount_out_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        if amount_in == 0 {
+            return Ok(0);
+        }
+
+        let seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&seeds[..]];
+
+        if self.usdc_mint.key() == self.vault_strategy_cfg_mint_1_escrow.mint {
+            transfer_token(
+                &self.vault_strategy_cfg_usdc_escrow,
+                &self.vault_strategy_cfg_mint_1_escrow,
+                amount_in,
+                &self.usdc_mint,
+                &self.vault_strategy_config.to_account_info(),
+                &self.token_program,
+                Some(signer_seeds),
+            )?;
+            self.vault_strategy_cfg_mint_1_escrow.reload()?;
+            return Ok(amount_in);
+        }
+
+        require!(
+            !remaining_accounts.is_empty(),
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        let before_balance = self.vault_strategy_cfg_mint_1_escrow.amount;
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config_usdc_for_token_1.to_account_info(),
+            pool_state: self.raydium_pool_state_usdc_for_token_1.to_account_info(),
+            observation_state: self.raydium_observation_state_1.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
+                vault: self.raydium_vault_1_input.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
+                vault: self.raydium_vault_1_output.to_account_info(),
+                mint: self.raydium_vault_1_mint.to_account_info(),
+            },
+            amount_in,
+            amount_out_min,
+            remaining_accounts,
+            Some(signer_seeds),
+        )?;
+
+        self.vault_strategy_cfg_mint_1_escrow.reload()?;
+        let amount_out = self
+            .vault_strategy_cfg_mint_1_escrow
+            .amount
+            .saturating_sub(before_balance);
+
+        msg!(
+            "Token 1 swap: amount_in={}, amount_out={}",
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    fn increase_liquidity(
+        &mut self,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let liquidity = {
+            let pool_state = self.raydium_pool_state.load()?;
+            let current_sqrt_price = pool_state.sqrt_price_x64;
+            let low_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_lower_index)?;
+            let high_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_upper_index)?;
+
+            get_liquidity_from_amounts(
+                current_sqrt_price,
+                low_sqrt_price,
+                high_sqrt_price,
+                amount_0_max,
+                amount_1_max,
+            )
+        };
+
+        msg!("deposit liquidity: {}", liquidity);
+        require!(
+            liquidity > 0,
+            TokenizedVaultsErrorCode::LiquidityCalculatedToZero
+        );
+
+        self.raydium_position().increase_liquidity(
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            &self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
+            &self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
+            remaining_accounts,
+        )
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DepositRaydiumVault<'info>>,
+    _strategy_id: u8,
+    amount: u64,
+    token_0_amount_out_min: u64,
+    token_1_amount_out_min: u64,
+    min_shares_out: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.deposit(
+        amount,
+        token_0_amount_out_min,
+        token_1_amount_out_min,
+        min_shares_out,
+        ctx.bumps.investor_strategy_position,
+        ctx.remaining_accounts,
+    )
+}
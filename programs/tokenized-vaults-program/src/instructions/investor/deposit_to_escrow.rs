@@ -5,13 +5,19 @@ use anchor_spl::token_interface::{self, Mint, TokenAccount, Transfer};
 
 use crate::error::TokenizedVaultsErrorCode;
 use crate::utils::transfer_token;
-use crate::{InvestorEscrow, USDC_MINT};
+use crate::{assert_protocol_active, InvestorEscrow, ProtocolConfig, USDC_MINT};
 
 #[derive(Accounts)]
 pub struct DepositToEscrow<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     #[account(
         init_if_needed,
         payer = investor,
@@ -44,6 +50,8 @@ pub struct DepositToEscrow<'info> {
 
 impl<'info> DepositToEscrow<'info> {
     pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
         self.transfer_to_escrow(amount)?;
 
         Ok(())
@@ -0,0 +1,825 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::tick_math;
+use crate::oracle::TokenOracle;
+use crate::utils::{mint_shares, transfer_token, TokenPriceInfo};
+use crate::{
+    assert_protocol_active, get_liquidity_from_amounts, get_pool_checked_price_infos,
+    get_swap_amount_to_position_ratio, get_vault_strategy_holdings, InvestorStrategyPosition,
+    OracleFeed, PoolPricedInstruction, ProtocolConfig, RaydiumPositionCpi, RaydiumSwapCpi,
+    RaydiumSwapSide, StrategyFeesTransfer, VaultOperation, VaultStrategy, VaultStrategyConfig,
+    VaultStrategyHoldings, VaultStrategyNavEvent, RAYDIUM_CLMM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct DepositTokensRaydiumVault<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// The investor account for the token 0, funds the deposit and receives
+    /// the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1, funds the deposit and receives
+    /// the remainder not added as liquidity
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config adds the token 0 to the position from this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config adds the token 1 to the position from this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_SWAP_TO_RATIO_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 0
+    /// Vault strategy Config collects the token 0 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 1
+    /// Vault strategy Config collects the token 1 fees in this account before the deposit
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    /// Protocol Config receives its cut of the token 0 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    /// Protocol Config receives its cut of the token 1 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account receiving the minted shares
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// The factory state of the pool, used by the swap
+    pub raydium_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The program account for the most recent oracle observation, used by the swap
+    #[account(mut)]
+    pub raydium_observation_state: AccountLoader<'info, ObservationState>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition,
+        constraint = raydium_personal_position.pool_id == raydium_pool_state.key()
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Token account that holds the raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // Pubkey::default() as separator
+    // swap tick_array_account_1
+    // swap tick_array_account_...
+}
+
+impl<'info> DepositTokensRaydiumVault<'info> {
+    /// Deposits `amount_0` of token 0 and `amount_1` of token 1 without going through USDC.
+    /// Only the imbalance with the position ratio is swapped, in the strategy pool itself,
+    /// then the liquidity is added and the shares are minted at the NAV like `deposit_raydium_vault`.
+    /// The whole deposit reverts if less than `min_shares_out` shares are minted
+    pub fn deposit_tokens(
+        &mut self,
+        amount_0: u64,
+        amount_1: u64,
+        swap_amount_out_min: u64,
+        min_shares_out: u64,
+        investor_strategy_position_bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        require!(
+            amount_0 > 0 || amount_1 > 0,
+            TokenizedVaultsErrorCode::InvalidAmount
+        );
+
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Deposit, &self.investor.key())?;
+
+        require!(
+            self.investor_mint_0_account.amount >= amount_0
+                && self.investor_mint_1_account.amount >= amount_1,
+            TokenizedVaultsErrorCode::InsufficientFunds
+        );
+
+        let (position_remaining_accounts, swap_remaining_accounts) = match remaining_accounts
+            .iter()
+            .position(|account_info| account_info.key() == Pubkey::default())
+        {
+            Some(split_index) => (
+                &remaining_accounts[..split_index],
+                &remaining_accounts[split_index + 1..],
+            ),
+            None => (remaining_accounts, &remaining_accounts[..0]),
+        };
+
+        // Fees earned so far belong to the current shares, they are allocated
+        // before the new shares exist
+        self.accrue_fees(position_remaining_accounts)?;
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            investor_strategy_position_bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        // Valued before the swap, it moves the strategy pool
+        let (token_0_price_info, token_1_price_info) = self
+            .checked_price_infos()?
+            .ok_or(TokenizedVaultsErrorCode::InvalidPrice)?;
+        let nav = self.nav(&token_0_price_info, &token_1_price_info)?;
+        msg!("nav: {}", nav);
+
+        let (swap_amount_in, zero_for_one) = {
+            let pool_state = self.raydium_pool_state.load()?;
+            get_swap_amount_to_position_ratio(
+                pool_state.sqrt_price_x64,
+                self.raydium_personal_position.tick_lower_index,
+                self.raydium_personal_position.tick_upper_index,
+                amount_0,
+                amount_1,
+            )?
+        };
+
+        let (token_0_amount, token_1_amount) = if swap_amount_in > 0 {
+            let amount_out = self.swap(
+                swap_amount_in,
+                swap_amount_out_min,
+                zero_for_one,
+                swap_remaining_accounts,
+            )?;
+            if zero_for_one {
+                (
+                    amount_0 - swap_amount_in,
+                    amount_1.saturating_add(amount_out),
+                )
+            } else {
+                (
+                    amount_0.saturating_add(amount_out),
+                    amount_1 - swap_amount_in,
+                )
+            }
+        } else {
+            (amount_0, amount_1)
+        };
+
+        msg!("token_0_amount: {}", token_0_amount);
+        msg!("token_1_amount: {}", token_1_amount);
+
+        transfer_token(
+            &self.investor_mint_0_account,
+            &self.vault_strategy_cfg_mint_0_escrow,
+            token_0_amount,
+            &self.raydium_vault_0_mint,
+            &self.investor,
+            &self.token_program,
+            None,
+        )?;
+        transfer_token(
+            &self.investor_mint_1_account,
+            &self.vault_strategy_cfg_mint_1_escrow,
+            token_1_amount,
+            &self.raydium_vault_1_mint,
+            &self.investor,
+            &self.token_program,
+            None,
+        )?;
+        self.vault_strategy_cfg_mint_0_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_escrow.reload()?;
+
+        let before_balance_0 = self.vault_strategy_cfg_mint_0_escrow.amount;
+        let before_balance_1 = self.vault_strategy_cfg_mint_1_escrow.amount;
+
+        self.increase_liquidity(token_0_amount, token_1_amount, position_remaining_accounts)?;
+
+        self.vault_strategy_cfg_mint_0_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_escrow.reload()?;
+
+        let consumed_amount_0 =
+            before_balance_0.saturating_sub(self.vault_strategy_cfg_mint_0_escrow.amount);
+        let consumed_amount_1 =
+            before_balance_1.saturating_sub(self.vault_strategy_cfg_mint_1_escrow.amount);
+
+        msg!("consumed amount_0: {}", consumed_amount_0);
+        msg!("consumed amount_1: {}", consumed_amount_1);
+
+        self.refund_remainder(
+            token_0_amount.saturating_sub(consumed_amount_0),
+            &self.vault_strategy_cfg_mint_0_escrow,
+            &self.investor_mint_0_account,
+            &self.raydium_vault_0_mint,
+        )?;
+        self.refund_remainder(
+            token_1_amount.saturating_sub(consumed_amount_1),
+            &self.vault_strategy_cfg_mint_1_escrow,
+            &self.investor_mint_1_account,
+            &self.raydium_vault_1_mint,
+        )?;
+
+        // The deposit is valued at what was actually added to the position
+        let deposited_assets = VaultStrategyHoldings {
+            amount_0: consumed_amount_0,
+            amount_1: consumed_amount_1,
+        }
+        .to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            &token_0_price_info,
+            &token_1_price_info,
+        )?;
+        msg!("deposited_assets: {}", deposited_assets);
+
+        self.vault_strategy.mark_to_market(nav)?;
+
+        let total_assets = self.vault_strategy.total_assets;
+        let total_shares = self.vault_strategy.total_shares;
+        let shares_before = self.investor_strategy_position.shares;
+
+        self.investor_strategy_position.deposit_assets(
+            deposited_assets,
+            total_assets,
+            total_shares,
+        )?;
+
+        let minted_shares = self
+            .investor_strategy_position
+            .shares
+            .saturating_sub(shares_before);
+
+        msg!("minted_shares: {}", minted_shares);
+        require!(
+            minted_shares >= min_shares_out,
+            TokenizedVaultsErrorCode::SharesBelowMinimum
+        );
+
+        self.vault_strategy.add_assets(deposited_assets)?;
+        self.vault_strategy.add_shares(minted_shares)?;
+
+        emit!(VaultStrategyNavEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            nav,
+            total_shares,
+        });
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        mint_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            minted_shares,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+        )?;
+
+        msg!("Token deposit executed successfully");
+        Ok(())
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::DepositTokensRaydiumVault,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    /// Mark-to-market value of the strategy in USDC, before the deposit is added.
+    /// The rebalance escrows belong to the strategy, their whole balance backs its shares
+    fn nav(
+        &self,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        let holdings = {
+            let pool_state = self.raydium_pool_state.load()?;
+
+            get_vault_strategy_holdings(
+                &pool_state,
+                &self.raydium_personal_position,
+                (
+                    self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+                    self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+                ),
+            )?
+        };
+
+        holdings.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            token_0_price_info,
+            token_1_price_info,
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    /// Collects the position fees into the fees escrows, the creator and protocol cuts leave
+    /// them right away and the investor part is spread over the current shares
+    fn accrue_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let strategy_fees = StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        };
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        strategy_fees.accrue(
+            &mut self.vault_strategy,
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+        )
+    }
+
+    /// Sends the part of a leg that was not added as liquidity back to the investor
+    fn refund_remainder(
+        &self,
+        amount: u64,
+        escrow: &InterfaceAccount<'info, TokenAccount>,
+        investor_account: &InterfaceAccount<'info, TokenAccount>,
+        mint: &InterfaceAccount<'info, Mint>,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        msg!("refund {}: {}", mint.key(), amount);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            escrow,
+            investor_account,
+            amount,
+            mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )
+    }
+
+    /// Swaps the imbalance in the strategy pool, straight between the investor token accounts
+    fn swap(
+        &mut self,
+        amount_in: u64,
+        amount_out_min: u64,
+        zero_for_one: bool,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        let side_0 = RaydiumSwapSide {
+            token_account: self.investor_mint_0_account.to_account_info(),
+            vault: self.raydium_token_vault_0.to_account_info(),
+            mint: self.raydium_vault_0_mint.to_account_info(),
+        };
+        let side_1 = RaydiumSwapSide {
+            token_account: self.investor_mint_1_account.to_account_info(),
+            vault: self.raydium_token_vault_1.to_account_info(),
+            mint: self.raydium_vault_1_mint.to_account_info(),
+        };
+        let (input, output, before_balance) = if zero_for_one {
+            (side_0, side_1, self.investor_mint_1_account.amount)
+        } else {
+            (side_1, side_0, self.investor_mint_0_account.amount)
+        };
+
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.investor.to_account_info(),
+            amm_config: self.raydium_amm_config.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            observation_state: self.raydium_observation_state.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &input,
+            &output,
+            amount_in,
+            amount_out_min,
+            remaining_accounts,
+            None,
+        )?;
+
+        self.investor_mint_0_account.reload()?;
+        self.investor_mint_1_account.reload()?;
+
+        let after_balance = if zero_for_one {
+            self.investor_mint_1_account.amount
+        } else {
+            self.investor_mint_0_account.amount
+        };
+        let amount_out = after_balance.saturating_sub(before_balance);
+
+        msg!(
+            "Deposit swap: zero_for_one={}, amount_in={}, amount_out={}",
+            zero_for_one,
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    fn increase_liquidity(
+        &mut self,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let liquidity = {
+            let pool_state = self.raydium_pool_state.load()?;
+            let current_sqrt_price = pool_state.sqrt_price_x64;
+            let low_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_lower_index)?;
+            let high_sqrt_price =
+                tick_math::get_sqrt_price_at_tick(self.raydium_personal_position.tick_upper_index)?;
+
+            get_liquidity_from_amounts(
+                current_sqrt_price,
+                low_sqrt_price,
+                high_sqrt_price,
+                amount_0_max,
+                amount_1_max,
+            )
+        };
+
+        msg!("deposit liquidity: {}", liquidity);
+        require!(
+            liquidity > 0,
+            TokenizedVaultsErrorCode::LiquidityCalculatedToZero
+        );
+
+        self.raydium_position().increase_liquidity(
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            &self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
+            &self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
+            remaining_accounts,
+        )
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DepositTokensRaydiumVault<'info>>,
+    _strategy_id: u8,
+    amount_0: u64,
+    amount_1: u64,
+    swap_amount_out_min: u64,
+    min_shares_out: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.deposit_tokens(
+        amount_0,
+        amount_1,
+        swap_amount_out_min,
+        min_shares_out,
+        ctx.bumps.investor_strategy_position,
+        ctx.remaining_accounts,
+    )
+}
@@ -5,8 +5,8 @@ use anchor_spl::token_interface::{self, Mint, TokenAccount, Transfer};
 use crate::error::TokenizedVaultsErrorCode;
 use crate::utils::transfer_token;
 use crate::{
-    InvestReserveVault, InvestorEscrow, InvestorStrategyPosition, VaultStrategyConfig,
-    VaultStrategyStatus, USDC_MINT,
+    assert_protocol_active, InvestReserveVault, InvestorEscrow, InvestorStrategyPosition,
+    ProtocolConfig, VaultOperation, VaultStrategyConfig, USDC_MINT,
 };
 
 #[derive(Accounts)]
@@ -14,6 +14,12 @@ pub struct InvestReserve<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     #[account()]
     pub vault_strategy_config: Account<'info, VaultStrategyConfig>,
 
@@ -65,18 +71,25 @@ pub struct InvestReserve<'info> {
 
 impl<'info> InvestReserve<'info> {
     pub fn invest(&mut self, amount: u64, escrow_vault_bump: u8) -> Result<()> {
+        assert_protocol_active(&self.protocol_config.status)?;
+
         require!(amount > 0, TokenizedVaultsErrorCode::InvalidAmount);
 
-        // require!(
-        //     self.vault_strategy_config.status == VaultStrategyStatus::Active,
-        //     TokenizedVaultsErrorCode::VaultStrategyConfigNotActive
-        // );
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Deposit, &self.investor.key())?;
 
         require!(
             self.escrow_vault.amount >= amount,
             TokenizedVaultsErrorCode::InsufficientFunds
         );
 
+        // A pending reserve has to be executed or cancelled before a new one,
+        // otherwise its amount would be overwritten
+        require!(
+            self.invest_reserve_vault.reserved_amount == 0,
+            TokenizedVaultsErrorCode::InvestReserveVaultAlreadyInitialized
+        );
+
         self.transfer_to_vault_strategy_cfg_usdc_escrow(amount, escrow_vault_bump)?;
 
         self.invest_reserve_vault
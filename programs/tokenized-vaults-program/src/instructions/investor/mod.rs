@@ -17,3 +17,30 @@ pub use add_liquidity_raydium_vault_strategy::*;
 
 pub mod remove_liquidity_raydium_vault_strategy;
 pub use remove_liquidity_raydium_vault_strategy::*;
+
+pub mod redeem_closed_vault_strategy;
+pub use redeem_closed_vault_strategy::*;
+
+pub mod deposit_raydium_vault;
+pub use deposit_raydium_vault::*;
+
+pub mod deposit_tokens_raydium_vault;
+pub use deposit_tokens_raydium_vault::*;
+
+pub mod withdraw_to_usdc_raydium_vault_strategy;
+pub use withdraw_to_usdc_raydium_vault_strategy::*;
+
+pub mod redeem_vault;
+pub use redeem_vault::*;
+
+pub mod cancel_invest_reserve;
+pub use cancel_invest_reserve::*;
+
+pub mod claim_investor_fees;
+pub use claim_investor_fees::*;
+
+pub mod redeem_raydium_vault_strategy;
+pub use redeem_raydium_vault_strategy::*;
+
+pub mod withdraw_raydium_vault_strategy;
+pub use withdraw_raydium_vault_strategy::*;
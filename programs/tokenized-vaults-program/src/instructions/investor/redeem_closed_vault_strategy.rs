@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::{burn_shares, transfer_token};
+use crate::{
+    InvestorStrategyPosition, VaultOperation, VaultStrategy, VaultStrategyConfig,
+    VaultStrategyRedeemEvent,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct RedeemClosedVaultStrategy<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_0_mint.key().as_ref(),
+            vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The investor position, created for shares received by transfer
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account holding the shares to burn
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account that holds the token 0 proceeds of the closed strategy
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::CLOSED_0_ESCROW_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_closed_mint_0_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account that holds the token 1 proceeds of the closed strategy
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::CLOSED_1_ESCROW_SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_closed_mint_1_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 0, holds the fees still owed to investors
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fees escrow account for the token 1, holds the fees still owed to investors
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 0
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemClosedVaultStrategy<'info> {
+    /// Redeems all the investor shares for its pro-rata part of the closed strategy proceeds
+    pub fn redeem(&mut self, investor_strategy_position_bump: u8) -> Result<()> {
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Redeem, &self.investor.key())?;
+
+        self.sync_investor_shares(investor_strategy_position_bump)?;
+
+        let shares = self.investor_strategy_position.shares;
+        require!(shares > 0, TokenizedVaultsErrorCode::InsufficientShares);
+
+        let (amount_0, amount_1) = self
+            .vault_strategy
+            .convert_shares_to_closed_amounts(shares)?;
+
+        msg!("redeem shares: {}", shares);
+        msg!("redeem amount_0: {}", amount_0);
+        msg!("redeem amount_1: {}", amount_1);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            &self.vault_strategy_closed_mint_0_escrow,
+            &self.investor_mint_0_account,
+            amount_0,
+            &self.vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_closed_mint_1_escrow,
+            &self.investor_mint_1_account,
+            amount_1,
+            &self.vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        // fees accrued while the strategy was open are paid with the proceeds
+        self.investor_strategy_position.settle_fees(
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+        let (fees_owed_0, fees_owed_1) = self.investor_strategy_position.take_fees_owed();
+
+        msg!("redeem fees_owed_0: {}", fees_owed_0);
+        msg!("redeem fees_owed_1: {}", fees_owed_1);
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_0_fees_escrow,
+            &self.investor_mint_0_account,
+            fees_owed_0,
+            &self.vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_1_fees_escrow,
+            &self.investor_mint_1_account,
+            fees_owed_1,
+            &self.vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        let total_vault_assets = self.vault_strategy.total_assets;
+        let total_vault_shares = self.vault_strategy.total_shares;
+
+        let burn_assets = self.investor_strategy_position.convert_shares_to_assets(
+            shares,
+            total_vault_assets,
+            total_vault_shares,
+        )?;
+        self.investor_strategy_position.remove_shares(
+            shares,
+            total_vault_assets,
+            total_vault_shares,
+        )?;
+
+        self.vault_strategy
+            .remove_closed_amounts(amount_0, amount_1)?;
+        self.vault_strategy.remove_assets(burn_assets)?;
+        self.vault_strategy.remove_shares(shares)?;
+
+        burn_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            shares,
+            &self.investor.to_account_info(),
+            &self.token_program,
+        )?;
+
+        emit!(VaultStrategyRedeemEvent {
+            investor: self.investor.key(),
+            vault_strategy_key: self.vault_strategy.key(),
+            shares,
+            amount_0,
+            amount_1,
+        });
+
+        Ok(())
+    }
+
+    /// Refreshes the investor shares from their share token balance,
+    /// see `InvestorStrategyPosition::sync_shares`
+    fn sync_investor_shares(&mut self, bump: u8) -> Result<()> {
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )
+    }
+}
+
+pub fn handler(ctx: Context<RedeemClosedVaultStrategy>, _strategy_id: u8) -> Result<()> {
+    let investor_strategy_position_bump = ctx.bumps.investor_strategy_position;
+    ctx.accounts.redeem(investor_strategy_position_bump)
+}
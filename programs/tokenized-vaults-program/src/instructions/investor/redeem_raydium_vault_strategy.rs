@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{PoolPricedInstruction, RemoveLiquidityRaydiumVaultStrategy};
+
+impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
+    /// Burns exactly `shares` and removes the liquidity backing them,
+    /// the amounts out are bounded by `amount_0_min` and `amount_1_min`
+    pub fn redeem(
+        &mut self,
+        shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+        investor_strategy_position_bump: u8,
+    ) -> Result<()> {
+        msg!("redeem shares: {}", shares);
+
+        self.checked_price_infos(PoolPricedInstruction::RemoveLiquidity)?;
+        self.sync_investor_shares(investor_strategy_position_bump)?;
+        self.investor_strategy_position
+            .assert_redeemable_shares(shares)?;
+
+        self.remove_shares_liquidity(shares, amount_0_min, amount_1_min, remaining_accounts)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RemoveLiquidityRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+    shares: u64,
+    amount_0_min: u64,
+    amount_1_min: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let investor_strategy_position_bump = ctx.bumps.investor_strategy_position;
+    ctx.accounts.redeem(
+        shares,
+        amount_0_min,
+        amount_1_min,
+        ctx.remaining_accounts,
+        investor_strategy_position_bump,
+    )
+}
@@ -0,0 +1,603 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
+use crate::utils::TokenPriceInfo;
+use crate::{
+    get_pool_checked_price_infos, InvestorStrategyPosition, OracleFeed, PoolPricedInstruction,
+    ProtocolConfig, RaydiumPositionCpi, StrategyFeesTransfer, StrategyLiquidityRemoval,
+    VaultOperation, VaultRedeemEvent, VaultStrategy, VaultStrategyConfig, MAX_REMOVE_PERCENTAGE,
+    MIN_REMOVE_PERCENTAGE, RAYDIUM_CLMM_ID,
+};
+
+/// Number of remaining accounts expected for each strategy of the vault,
+/// before the remaining accounts of its position instructions
+pub const REDEEM_VAULT_ACCOUNTS_PER_STRATEGY: usize = 14;
+
+#[derive(Accounts)]
+pub struct RedeemVault<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    /// The investor account for the token 0
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_0_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor account for the token 1
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = raydium_vault_1_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_mint_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the collected token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the collected token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// The mint of token 0, shared by every strategy of the vault
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1, shared by every strategy of the vault
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts, one group per strategy in the order of
+    // vault_strategy_config.strategies, the groups are separated by Pubkey::default().
+    // Each group starts with its REDEEM_VAULT_ACCOUNTS_PER_STRATEGY accounts:
+    // vault_strategy, investor_strategy_position, share_mint, investor_share_account,
+    // raydium_pool_state, raydium_personal_position, raydium_protocol_position,
+    // raydium_tick_array_lower, raydium_tick_array_upper, raydium_position_nft_account,
+    // raydium_token_vault_0, raydium_token_vault_1, vault_strategy_cfg_mint_0_rebalance_escrow,
+    // vault_strategy_cfg_mint_1_rebalance_escrow
+    // followed by the remaining accounts of its position instructions, if any
+    // (tick array bitmap extension)
+}
+
+/// Accounts of one strategy, loaded from the remaining accounts
+struct RedeemVaultStrategyAccounts<'info> {
+    vault_strategy: Box<Account<'info, VaultStrategy>>,
+    investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+    share_mint: Box<InterfaceAccount<'info, Mint>>,
+    investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    raydium_pool_state: AccountLoader<'info, PoolState>,
+    raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+    raydium_protocol_position: &'info AccountInfo<'info>,
+    raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+    raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+    raydium_position_nft_account: &'info AccountInfo<'info>,
+    raydium_token_vault_0: &'info AccountInfo<'info>,
+    raydium_token_vault_1: &'info AccountInfo<'info>,
+    rebalance_escrow_0: Box<InterfaceAccount<'info, TokenAccount>>,
+    rebalance_escrow_1: Box<InterfaceAccount<'info, TokenAccount>>,
+    position_remaining_accounts: &'info [AccountInfo<'info>],
+}
+
+impl<'info> RedeemVaultStrategyAccounts<'info> {
+    fn try_from_accounts(
+        accounts: &'info [AccountInfo<'info>],
+        investor: &Pubkey,
+        vault_strategy_config_key: &Pubkey,
+        strategy_key: &Pubkey,
+    ) -> Result<Self> {
+        require!(
+            accounts.len() >= REDEEM_VAULT_ACCOUNTS_PER_STRATEGY,
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        let vault_strategy = Box::new(Account::<VaultStrategy>::try_from(&accounts[0])?);
+        require_keys_eq!(
+            vault_strategy.key(),
+            *strategy_key,
+            TokenizedVaultsErrorCode::InvalidStrategyIndex
+        );
+        require_keys_eq!(
+            vault_strategy.vault_strategy_config_key,
+            *vault_strategy_config_key,
+            TokenizedVaultsErrorCode::InvalidStrategyIndex
+        );
+
+        let investor_strategy_position =
+            Box::new(Account::<InvestorStrategyPosition>::try_from(&accounts[1])?);
+        require_keys_eq!(
+            investor_strategy_position.authority,
+            *investor,
+            TokenizedVaultsErrorCode::Unauthorized
+        );
+        require_keys_eq!(
+            investor_strategy_position.vault_strategy_key,
+            vault_strategy.key(),
+            TokenizedVaultsErrorCode::Unauthorized
+        );
+
+        let share_mint = Box::new(InterfaceAccount::<Mint>::try_from(&accounts[2])?);
+        require_keys_eq!(
+            share_mint.key(),
+            vault_strategy.share_mint,
+            TokenizedVaultsErrorCode::InvalidShareMint
+        );
+
+        let investor_share_account =
+            Box::new(InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?);
+        require_keys_eq!(
+            investor_share_account.owner,
+            *investor,
+            TokenizedVaultsErrorCode::Unauthorized
+        );
+        require_keys_eq!(
+            investor_share_account.mint,
+            share_mint.key(),
+            TokenizedVaultsErrorCode::InvalidShareMint
+        );
+
+        let raydium_pool_state = AccountLoader::<PoolState>::try_from(&accounts[4])?;
+
+        let raydium_personal_position =
+            Box::new(Account::<PersonalPositionState>::try_from(&accounts[5])?);
+        require_keys_eq!(
+            raydium_personal_position.nft_mint,
+            vault_strategy.dex_nft_mint,
+            TokenizedVaultsErrorCode::InvalidRaydiumPosition
+        );
+        require_keys_eq!(
+            raydium_personal_position.pool_id,
+            raydium_pool_state.key(),
+            TokenizedVaultsErrorCode::InvalidRaydiumPosition
+        );
+
+        Ok(Self {
+            vault_strategy,
+            investor_strategy_position,
+            share_mint,
+            investor_share_account,
+            raydium_pool_state,
+            raydium_personal_position,
+            raydium_protocol_position: &accounts[6],
+            raydium_tick_array_lower: AccountLoader::<TickArrayState>::try_from(&accounts[7])?,
+            raydium_tick_array_upper: AccountLoader::<TickArrayState>::try_from(&accounts[8])?,
+            raydium_position_nft_account: &accounts[9],
+            raydium_token_vault_0: &accounts[10],
+            raydium_token_vault_1: &accounts[11],
+            rebalance_escrow_0: Self::load_rebalance_escrow(
+                &accounts[12],
+                VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED,
+                vault_strategy_config_key,
+                strategy_key,
+            )?,
+            rebalance_escrow_1: Self::load_rebalance_escrow(
+                &accounts[13],
+                VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED,
+                vault_strategy_config_key,
+                strategy_key,
+            )?,
+            position_remaining_accounts: &accounts[REDEEM_VAULT_ACCOUNTS_PER_STRATEGY..],
+        })
+    }
+
+    /// The rebalance escrows of the strategy, created by its first deposit
+    fn load_rebalance_escrow(
+        account: &'info AccountInfo<'info>,
+        seed: &str,
+        vault_strategy_config_key: &Pubkey,
+        strategy_key: &Pubkey,
+    ) -> Result<Box<InterfaceAccount<'info, TokenAccount>>> {
+        let (rebalance_escrow_key, _) = Pubkey::find_program_address(
+            &[
+                seed.as_bytes(),
+                vault_strategy_config_key.as_ref(),
+                strategy_key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            account.key(),
+            rebalance_escrow_key,
+            TokenizedVaultsErrorCode::InvalidRebalanceEscrow
+        );
+
+        Ok(Box::new(InterfaceAccount::<TokenAccount>::try_from(
+            account,
+        )?))
+    }
+}
+
+impl<'info> RedeemVault<'info> {
+    /// Removes the same percentage from every strategy position of the investor.
+    /// percentage has to be between 10%(10*1e9) and 100%(100*1e9)
+    pub fn redeem(
+        &mut self,
+        percentage: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Withdraw, &self.investor.key())?;
+
+        require!(
+            percentage >= MIN_REMOVE_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidRemovePercentage
+        );
+        require!(
+            percentage <= MAX_REMOVE_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidRemovePercentage
+        );
+
+        let strategies = self.vault_strategy_config.strategies.clone();
+        require!(
+            !strategies.is_empty(),
+            TokenizedVaultsErrorCode::VaultStrategyConfigNoStrategies
+        );
+        let strategies_accounts: Vec<&'info [AccountInfo<'info>]> = remaining_accounts
+            .split(|account_info| account_info.key() == Pubkey::default())
+            .collect();
+        require!(
+            strategies_accounts.len() == strategies.len(),
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        let before_balance_0 = self.investor_mint_0_account.amount;
+        let before_balance_1 = self.investor_mint_1_account.amount;
+        let mut total_burned_shares: u64 = 0;
+
+        for (strategy_key, accounts) in strategies.iter().zip(strategies_accounts) {
+            let mut strategy = RedeemVaultStrategyAccounts::try_from_accounts(
+                accounts,
+                &self.investor.key(),
+                &self.vault_strategy_config.key(),
+                strategy_key,
+            )?;
+
+            require_keys_eq!(
+                strategy.vault_strategy.mint_0,
+                self.raydium_vault_0_mint.key(),
+                TokenizedVaultsErrorCode::InvalidMint
+            );
+            require_keys_eq!(
+                strategy.vault_strategy.mint_1,
+                self.raydium_vault_1_mint.key(),
+                TokenizedVaultsErrorCode::InvalidMint
+            );
+
+            self.checked_price_infos(&strategy)?;
+            let burned_shares = self.redeem_strategy(&mut strategy, percentage)?;
+            total_burned_shares = total_burned_shares.saturating_add(burned_shares);
+
+            strategy.vault_strategy.exit(&crate::ID)?;
+            strategy.investor_strategy_position.exit(&crate::ID)?;
+        }
+
+        self.investor_mint_0_account.reload()?;
+        self.investor_mint_1_account.reload()?;
+
+        let amount_0 = self
+            .investor_mint_0_account
+            .amount
+            .saturating_sub(before_balance_0);
+        let amount_1 = self
+            .investor_mint_1_account
+            .amount
+            .saturating_sub(before_balance_1);
+
+        msg!("redeemed amount_0: {}", amount_0);
+        msg!("redeemed amount_1: {}", amount_1);
+
+        require!(
+            amount_0 >= amount_0_min && amount_1 >= amount_1_min,
+            TokenizedVaultsErrorCode::AmountOutBelowMinimum
+        );
+
+        emit!(VaultRedeemEvent {
+            investor: self.investor.key(),
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            percentage,
+            shares: total_burned_shares,
+            amount_0,
+            amount_1,
+            strategies: strategies.len() as u8,
+        });
+
+        msg!("Vault redeemed successfully");
+        Ok(())
+    }
+
+    /// Same steps as `remove_liquidity_raydium_vault_strategy` for one strategy,
+    /// returns the burned shares
+    fn redeem_strategy(
+        &self,
+        strategy: &mut RedeemVaultStrategyAccounts<'info>,
+        percentage: u64,
+    ) -> Result<u64> {
+        // the share token balance is the source of truth of the redeemable shares
+        strategy.investor_strategy_position.sync_shares(
+            strategy.investor_share_account.amount,
+            strategy.vault_strategy.fee_growth_per_share_0_x64,
+            strategy.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        let burn_shares_amount = (percentage as u128)
+            .checked_mul(strategy.investor_strategy_position.shares as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(MAX_REMOVE_PERCENTAGE as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)? as u64;
+
+        require!(
+            burn_shares_amount > 0,
+            TokenizedVaultsErrorCode::SharesCalculatedToZero
+        );
+
+        let removal = StrategyLiquidityRemoval {
+            investor: &self.investor,
+            strategy_fees: self.strategy_fees(strategy.vault_strategy.key()),
+            raydium_position: self.raydium_position(strategy),
+            raydium_pool_state: &strategy.raydium_pool_state,
+            raydium_tick_array_lower: &strategy.raydium_tick_array_lower,
+            raydium_tick_array_upper: &strategy.raydium_tick_array_upper,
+            raydium_personal_position: &strategy.raydium_personal_position,
+            share_mint: &strategy.share_mint,
+            investor_share_account: &strategy.investor_share_account,
+            investor_mint_0_account: &self.investor_mint_0_account,
+            investor_mint_1_account: &self.investor_mint_1_account,
+            rebalance_escrow_0: &strategy.rebalance_escrow_0,
+            rebalance_escrow_1: &strategy.rebalance_escrow_1,
+        };
+
+        // The aggregate minimum is checked once every strategy is redeemed
+        removal.remove(
+            &mut strategy.vault_strategy,
+            &mut strategy.investor_strategy_position,
+            burn_shares_amount,
+            0,
+            0,
+            strategy.position_remaining_accounts,
+        )?;
+
+        Ok(burn_shares_amount)
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(
+        &self,
+        strategy: &RedeemVaultStrategyAccounts<'info>,
+    ) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::RedeemVault,
+            &self.vault_strategy_config,
+            strategy.vault_strategy.key(),
+            &*strategy.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    fn raydium_position<'a>(
+        &'a self,
+        strategy: &RedeemVaultStrategyAccounts<'info>,
+    ) -> RaydiumPositionCpi<'a, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: strategy.raydium_position_nft_account.clone(),
+            pool_state: strategy.raydium_pool_state.to_account_info(),
+            protocol_position: strategy.raydium_protocol_position.clone(),
+            personal_position: strategy.raydium_personal_position.to_account_info(),
+            tick_array_lower: strategy.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: strategy.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: strategy.raydium_token_vault_0.clone(),
+            token_vault_1: strategy.raydium_token_vault_1.clone(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    fn strategy_fees(&self, vault_strategy_key: Pubkey) -> StrategyFeesTransfer<'_, 'info> {
+        StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key,
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        }
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RedeemVault<'info>>,
+    percentage: u64,
+    amount_0_min: u64,
+    amount_1_min: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    ctx.accounts.redeem(
+        percentage,
+        amount_0_min,
+        amount_1_min,
+        ctx.remaining_accounts,
+    )
+}
@@ -3,28 +3,28 @@ use std::ops::Mul;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::memo::Memo;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::{self, Token};
 
-use crate::libraries::tick_math;
-use crate::utils::transfer_token;
+use crate::utils::{burn_shares as burn_shares_tokens, transfer_token, TokenPriceInfo};
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use num_bigint::BigInt;
-use raydium_clmm_cpi::cpi;
-use raydium_clmm_cpi::cpi::accounts::SwapSingleV2;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use raydium_clmm_cpi::program::RaydiumClmm;
 use raydium_clmm_cpi::states::{
     AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState, POSITION_SEED,
 };
 
 use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
 use crate::{
-    get_delta_amounts_signed, get_liquidity_from_amount_0, get_liquidity_from_amount_1,
-    get_liquidity_from_amounts, get_raydium_owed_fees, vault_strategy_config, InvestReserveVault,
-    InvestorEscrow, InvestorStrategyPosition, RaydiumTickArrayState, SwapToRatioVault,
-    VaultStrategy, VaultStrategyConfig, BPS, DENOMINATOR_MULTIPLIER, MAX_PERCENTAGE,
-    MAX_PERFORMANCE_FEE, MAX_REMOVE_PERCENTAGE, MIN_REMOVE_PERCENTAGE, RAYDIUM_CLMM_ID, U256,
-    USDC_MINT,
+    get_delta_amounts_signed, get_liquidity_for_shares, get_liquidity_from_amount_0,
+    get_liquidity_from_amount_1, get_liquidity_from_amounts, get_pool_checked_price_infos,
+    get_vault_strategy_holdings, vault_strategy_config, InvestReserveVault, InvestorEscrow,
+    InvestorStrategyPosition, OracleFeed, PoolPricedInstruction, ProtocolConfig,
+    RaydiumPositionCpi, StrategyFeesTransfer, SwapToRatioVault, VaultOperation, VaultStrategy,
+    VaultStrategyConfig, VaultStrategyHoldings, BPS, DENOMINATOR_MULTIPLIER, MAX_PERCENTAGE,
+    MAX_PERFORMANCE_FEE, MAX_REMOVE_PERCENTAGE, MIN_REMOVE_PERCENTAGE, RAYDIUM_CLMM_ID, USDC_MINT,
 };
 
 #[derive(Accounts)]
@@ -33,6 +33,12 @@ pub struct RemoveLiquidityRaydiumVaultStrategy<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(mut)]
     pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
 
@@ -49,8 +55,11 @@ pub struct RemoveLiquidityRaydiumVaultStrategy<'info> {
     )]
     pub vault_strategy: Box<Account<'info, VaultStrategy>>,
 
+    /// The investor position, created for shares received by transfer
     #[account(
-        mut,
+        init_if_needed,
+        payer = investor,
+        space = InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE,
         seeds = [
             InvestorStrategyPosition::SEED.as_bytes(),
             vault_strategy.key().as_ref(),
@@ -60,6 +69,21 @@ pub struct RemoveLiquidityRaydiumVaultStrategy<'info> {
     )]
     pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
 
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account holding the shares to burn
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
     // /// The escrow account for the token 0
     // /// Vault strategy Config receives token 0 in this account from Raydium Swap
     // #[account(
@@ -151,6 +175,68 @@ pub struct RemoveLiquidityRaydiumVaultStrategy<'info> {
     )]
     pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded,
+    /// the investor receives the part backing the burned shares
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded,
+    /// the investor receives the part backing the burned shares
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    /// Protocol Config receives its cut of the token 0 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    /// Protocol Config receives its cut of the token 1 fees in this account
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
     /* DEX Raydium */
     #[account(address = RAYDIUM_CLMM_ID)]
     pub raydium_clmm_program: Program<'info, RaydiumClmm>,
@@ -200,6 +286,49 @@ pub struct RemoveLiquidityRaydiumVaultStrategy<'info> {
     #[account()]
     pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
     /// SPL program for token transfers
     pub token_program: Program<'info, Token>,
 
@@ -232,7 +361,7 @@ impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
         amount_0_min: u64,
         amount_1_min: u64,
         remaining_accounts: &[AccountInfo<'info>],
-        _bumps: &RemoveLiquidityRaydiumVaultStrategyBumps,
+        bumps: &RemoveLiquidityRaydiumVaultStrategyBumps,
     ) -> Result<()> {
         require!(
             percentage >= MIN_REMOVE_PERCENTAGE,
@@ -245,6 +374,9 @@ impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
 
         msg!("removing percentage: {}", percentage);
 
+        self.checked_price_infos(PoolPricedInstruction::RemoveLiquidity)?;
+        self.sync_investor_shares(bumps.investor_strategy_position)?;
+
         let burn_shares = (percentage as u128)
             .checked_mul(self.investor_strategy_position.shares as u128)
             .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
@@ -256,21 +388,207 @@ impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
             TokenizedVaultsErrorCode::SharesCalculatedToZero
         );
 
-        let (strategy_fees_collected_0, strategy_fees_collected_1) =
-            self.collect_fees(remaining_accounts)?;
+        self.remove_shares_liquidity(burn_shares, amount_0_min, amount_1_min, remaining_accounts)
+    }
+
+    /// Refreshes the investor shares from their share token balance,
+    /// see `InvestorStrategyPosition::sync_shares`
+    pub(crate) fn sync_investor_shares(&mut self, bump: u8) -> Result<()> {
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )
+    }
+
+    /// Burns `burn_shares` of the investor and sends them the same part of the position
+    /// liquidity, with the fees settled on the position. The caller checks the pool price
+    /// with `checked_price_infos`
+    pub(crate) fn remove_shares_liquidity(
+        &mut self,
+        burn_shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Withdraw, &self.investor.key())?;
+
+        let removal = StrategyLiquidityRemoval {
+            investor: &self.investor,
+            strategy_fees: StrategyFeesTransfer {
+                protocol_config: &self.protocol_config,
+                vault_strategy_config: &self.vault_strategy_config,
+                vault_strategy_key: self.vault_strategy.key(),
+                fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+                fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+                perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+                perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+                protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+                protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+                mint_0: &self.raydium_vault_0_mint,
+                mint_1: &self.raydium_vault_1_mint,
+                token_program: &self.token_program,
+            },
+            raydium_position: RaydiumPositionCpi {
+                raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+                vault_strategy_config: &self.vault_strategy_config,
+                nft_account: self.raydium_position_nft_account.to_account_info(),
+                pool_state: self.raydium_pool_state.to_account_info(),
+                protocol_position: self.raydium_protocol_position.to_account_info(),
+                personal_position: self.raydium_personal_position.to_account_info(),
+                tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+                tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+                token_vault_0: self.raydium_token_vault_0.to_account_info(),
+                token_vault_1: self.raydium_token_vault_1.to_account_info(),
+                vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+                vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                token_program_2022: self.token_program_2022.to_account_info(),
+                memo_program: self.memo_program.to_account_info(),
+            },
+            raydium_pool_state: &self.raydium_pool_state,
+            raydium_tick_array_lower: &self.raydium_tick_array_lower,
+            raydium_tick_array_upper: &self.raydium_tick_array_upper,
+            raydium_personal_position: &self.raydium_personal_position,
+            share_mint: &self.share_mint,
+            investor_share_account: &self.investor_share_account,
+            investor_mint_0_account: &self.investor_mint_0_account,
+            investor_mint_1_account: &self.investor_mint_1_account,
+            rebalance_escrow_0: &self.vault_strategy_cfg_mint_0_rebalance_escrow,
+            rebalance_escrow_1: &self.vault_strategy_cfg_mint_1_rebalance_escrow,
+        };
+
+        removal.remove(
+            &mut self.vault_strategy,
+            &mut self.investor_strategy_position,
+            burn_shares,
+            amount_0_min,
+            amount_1_min,
+            remaining_accounts,
+        )?;
 
-        let total_vault_shares = self.vault_strategy.total_shares;
+        msg!("Liquidity removed successfully");
+        Ok(())
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    pub(crate) fn checked_price_infos(
+        &self,
+        instruction: PoolPricedInstruction,
+    ) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            instruction,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
 
-        let investor_shares_percentage = self
-            .investor_strategy_position
-            .convert_shares_to_percentage(total_vault_shares)?;
+    /// Mark-to-market value of the strategy in USDC, before the exit.
+    /// The rebalance escrows belong to the strategy, their whole balance backs its shares
+    pub(crate) fn nav(
+        &self,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        let holdings = {
+            let pool_state = self.raydium_pool_state.load()?;
+
+            get_vault_strategy_holdings(
+                &pool_state,
+                &self.raydium_personal_position,
+                (
+                    self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+                    self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+                ),
+            )?
+        };
 
-        msg!("investor_shares_percentage: {}", investor_shares_percentage);
+        holdings.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            token_0_price_info,
+            token_1_price_info,
+        )
+    }
+}
 
-        let (dex_liquidity_to_remove, _investor_remove_percentage) =
-            self.liquidity_to_remove(percentage, investor_shares_percentage)?;
+/// The accounts of the strategy position an investor exits from, the exit steps are shared
+/// by `remove_liquidity_raydium_vault_strategy`, the redeem and withdraw by shares and
+/// `redeem_vault` for each of the vault strategies
+pub(crate) struct StrategyLiquidityRemoval<'a, 'info> {
+    pub investor: &'a Signer<'info>,
+    pub strategy_fees: StrategyFeesTransfer<'a, 'info>,
+    pub raydium_position: RaydiumPositionCpi<'a, 'info>,
+    pub raydium_pool_state: &'a AccountLoader<'info, PoolState>,
+    pub raydium_tick_array_lower: &'a AccountLoader<'info, TickArrayState>,
+    pub raydium_tick_array_upper: &'a AccountLoader<'info, TickArrayState>,
+    pub raydium_personal_position: &'a PersonalPositionState,
+    pub share_mint: &'a InterfaceAccount<'info, Mint>,
+    pub investor_share_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub investor_mint_0_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub investor_mint_1_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub rebalance_escrow_0: &'a InterfaceAccount<'info, TokenAccount>,
+    pub rebalance_escrow_1: &'a InterfaceAccount<'info, TokenAccount>,
+}
 
-        self.decrease_liquidity(
+impl<'info> StrategyLiquidityRemoval<'_, 'info> {
+    /// Collects and settles the position fees, burns `burn_shares` of the investor and sends
+    /// them the same part of the position liquidity and of the idle amounts, with every fee
+    /// settled on the position
+    pub(crate) fn remove(
+        &self,
+        vault_strategy: &mut VaultStrategy,
+        investor_strategy_position: &mut InvestorStrategyPosition,
+        burn_shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            burn_shares <= investor_strategy_position.shares,
+            TokenizedVaultsErrorCode::InsufficientShares
+        );
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = self.strategy_fees.collect(
+            &self.raydium_position,
+            self.raydium_pool_state,
+            self.raydium_tick_array_lower,
+            self.raydium_tick_array_upper,
+            self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        self.strategy_fees
+            .accrue(vault_strategy, strategy_fees_owed_0, strategy_fees_owed_1)?;
+        investor_strategy_position.settle_fees(
+            vault_strategy.fee_growth_per_share_0_x64,
+            vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        let dex_liquidity_to_remove = self.liquidity_to_remove(vault_strategy, burn_shares)?;
+
+        self.raydium_position.decrease_liquidity(
             dex_liquidity_to_remove,
             amount_0_min,
             amount_1_min,
@@ -279,301 +597,109 @@ impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
             remaining_accounts,
         )?;
 
-        self.transfer_fees(
-            investor_shares_percentage,
-            strategy_fees_collected_0,
-            strategy_fees_collected_1,
+        // Pays the investor every fee settled on the position so far
+        let (fees_owed_0, fees_owed_1) = investor_strategy_position.take_fees_owed();
+        msg!("investor fees_owed_0: {}", fees_owed_0);
+        msg!("investor fees_owed_1: {}", fees_owed_1);
+        self.strategy_fees.transfer_from_fees_escrows(
+            self.investor_mint_0_account,
+            self.investor_mint_1_account,
+            fees_owed_0,
+            fees_owed_1,
         )?;
 
-        let total_vault_assets = self.vault_strategy.total_assets;
-        let total_vault_shares = self.vault_strategy.total_shares;
+        self.pay_idle_amounts(burn_shares, vault_strategy.total_shares)?;
+
+        let total_vault_assets = vault_strategy.total_assets;
+        let total_vault_shares = vault_strategy.total_shares;
         msg!("strategy total_assets: {}", total_vault_assets);
         msg!("strategy total_shares: {}", total_vault_shares);
 
-        let burn_assets = self.investor_strategy_position.convert_shares_to_assets(
+        let burn_assets = investor_strategy_position.convert_shares_to_assets(
             burn_shares,
             total_vault_assets,
             total_vault_shares,
         )?;
-        self.investor_strategy_position.remove_shares(
+        investor_strategy_position.remove_shares(
             burn_shares,
             total_vault_assets,
             total_vault_shares,
         )?;
         msg!("burn_assets: {}", burn_assets);
         msg!("burn_shares: {}", burn_shares);
-        self.vault_strategy.remove_assets(burn_assets)?;
-        self.vault_strategy.remove_shares(burn_shares)?;
-
-        msg!("Liquidity removed successfully");
-        Ok(())
-    }
-
-    fn liquidity_to_remove(
-        &self,
-        percentage: u64,
-        investor_shares_percentage: u64,
-    ) -> Result<(u128, u128)> {
-        let investor_remove_percentage = (investor_shares_percentage as u128)
-            .checked_mul(percentage as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(MAX_REMOVE_PERCENTAGE as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
-
-        msg!("investor_remove_percentage: {}", investor_remove_percentage);
-
-        msg!(
-            "raydium_personal_position.liquidity: {}",
-            self.raydium_personal_position.liquidity
-        );
-
-        let dex_liquidity_to_remove = investor_remove_percentage
-            .checked_mul(self.raydium_personal_position.liquidity)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(DENOMINATOR_MULTIPLIER as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
-
-        msg!("dex_liquidity_to_remove: {}", dex_liquidity_to_remove);
-
-        Ok((dex_liquidity_to_remove, investor_remove_percentage))
-    }
-
-    fn split_fees(
-        &mut self,
-        investor_shares_percentage: u64,
-        strategy_fees_owed_0: u64,
-        strategy_fees_owed_1: u64,
-    ) -> Result<(u64, u64, u64, u64)> {
-        // Calculate the percentage of shares to remove
-        let investor_fees_owed_0 = (investor_shares_percentage as u128)
-            .checked_mul(strategy_fees_owed_0 as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(DENOMINATOR_MULTIPLIER as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            as u64;
-
-        let investor_fees_owed_1 = (investor_shares_percentage as u128)
-            .checked_mul(strategy_fees_owed_1 as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(DENOMINATOR_MULTIPLIER as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            as u64;
-
-        msg!("investor_fees_owed_0: {}", investor_fees_owed_0);
-        msg!("investor_fees_owed_1: {}", investor_fees_owed_1);
-
-        let creator_fees_owed_0 = (investor_fees_owed_0 as u128)
-            .checked_mul(self.vault_strategy_config.performance_fee as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(MAX_PERFORMANCE_FEE as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            as u64;
-
-        let creator_fees_owed_1 = (investor_fees_owed_1 as u128)
-            .checked_mul(self.vault_strategy_config.performance_fee as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            .checked_div(MAX_PERFORMANCE_FEE as u128)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
-            as u64;
-
-        msg!("creator_fees_owed_0: {}", creator_fees_owed_0);
-        msg!("creator_fees_owed_1: {}", creator_fees_owed_1);
+        vault_strategy.remove_assets(burn_assets)?;
+        vault_strategy.remove_shares(burn_shares)?;
 
-        let investor_fees_owed_0 = investor_fees_owed_0
-            .checked_sub(creator_fees_owed_0)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
-
-        let investor_fees_owed_1 = investor_fees_owed_1
-            .checked_sub(creator_fees_owed_1)
-            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
-
-        msg!(
-            "investor_fees_owed_0 minus performance fee: {}",
-            investor_fees_owed_0
-        );
-        msg!(
-            "investor_fees_owed_1 minus performance fee: {}",
-            investor_fees_owed_1
-        );
-
-        Ok((
-            investor_fees_owed_0,
-            investor_fees_owed_1,
-            creator_fees_owed_0,
-            creator_fees_owed_1,
-        ))
+        burn_shares_tokens(
+            self.share_mint,
+            self.investor_share_account,
+            burn_shares,
+            &self.investor.to_account_info(),
+            self.strategy_fees.token_program,
+        )
     }
 
-    fn get_owed_fees(&mut self) -> Result<(u64, u64)> {
-        let tick_array_lower_loader = self.raydium_tick_array_lower.load()?;
-        let tick_array_upper_loader = self.raydium_tick_array_upper.load()?;
-
-        let tick_lower_index = self.raydium_personal_position.tick_lower_index;
-        let tick_upper_index = self.raydium_personal_position.tick_upper_index;
-        let pool_state = self.raydium_pool_state.load()?;
-
-        let tick_lower_state =
-            tick_array_lower_loader.get_tick_state(tick_lower_index, pool_state.tick_spacing)?;
-        let tick_upper_state =
-            tick_array_upper_loader.get_tick_state(tick_upper_index, pool_state.tick_spacing)?;
-
-        let (fees_owed_0, fees_owed_1) = get_raydium_owed_fees(
-            tick_lower_state,
-            tick_upper_state,
-            &self.raydium_personal_position,
-            pool_state.tick_current,
-            pool_state.fee_growth_global_0_x64,
-            pool_state.fee_growth_global_1_x64,
-        );
-        Ok((fees_owed_0, fees_owed_1))
-    }
+    /// The rebalance escrows count in the NAV of the strategy,
+    /// the investor receives the part of their balance backing `burn_shares`
+    fn pay_idle_amounts(&self, burn_shares: u64, total_shares: u64) -> Result<()> {
+        let idle_amounts = VaultStrategyHoldings {
+            amount_0: self.rebalance_escrow_0.amount,
+            amount_1: self.rebalance_escrow_1.amount,
+        }
+        .share_of(burn_shares, total_shares)?;
 
-    fn transfer_fees(
-        &mut self,
-        investor_shares_percentage: u64,
-        strategy_fees_owed_0: u64,
-        strategy_fees_owed_1: u64,
-    ) -> Result<()> {
-        let (investor_fees_owed_0, investor_fees_owed_1, creator_fees_owed_0, creator_fees_owed_1) =
-            self.split_fees(
-                investor_shares_percentage,
-                strategy_fees_owed_0,
-                strategy_fees_owed_1,
-            )?;
-
-        // let fees_escrow_0_bump_seed = &[bumps.vault_strategy_cfg_mint_0_fees_escrow];
-        // let fees_escrow_0_seeds = &[
-        //     VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
-        //     self.vault_strategy_config.to_account_info().key.as_ref(),
-        //     fees_escrow_0_bump_seed,
-        // ];
-
-        // let fees_escrow_0_signer_seeds = &[&fees_escrow_0_seeds[..]];
-
-        // let fees_escrow_1_bump_seed = &[bumps.vault_strategy_cfg_mint_1_fees_escrow];
-        // let fees_escrow_1_seeds = &[
-        //     VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
-        //     self.vault_strategy_config.to_account_info().key.as_ref(),
-        //     fees_escrow_1_bump_seed,
-        // ];
-
-        // let fees_escrow_1_signer_seeds = &[&fees_escrow_1_seeds[..]];
-
-        let vault_strategy_config_bump_seed = &[self.vault_strategy_config.bump];
-        let vault_strategy_config_seeds = &[
-            VaultStrategyConfig::SEED.as_bytes(),
-            self.vault_strategy_config.creator.as_ref(),
-            self.vault_strategy_config.name.as_ref(),
-            vault_strategy_config_bump_seed,
-        ];
+        msg!("idle amount_0: {}", idle_amounts.amount_0);
+        msg!("idle amount_1: {}", idle_amounts.amount_1);
 
+        let vault_strategy_config = self.strategy_fees.vault_strategy_config;
+        let vault_strategy_config_seeds = vault_strategy_config.signer_seeds();
         let signer_seeds = &[&vault_strategy_config_seeds[..]];
 
         transfer_token(
-            &self.vault_strategy_cfg_mint_0_fees_escrow,
-            &self.investor_mint_0_account,
-            investor_fees_owed_0,
-            &self.raydium_vault_0_mint,
-            &self.vault_strategy_config.to_account_info(),
-            &self.token_program,
+            self.rebalance_escrow_0,
+            self.investor_mint_0_account,
+            idle_amounts.amount_0,
+            self.strategy_fees.mint_0,
+            &vault_strategy_config.to_account_info(),
+            self.strategy_fees.token_program,
             Some(signer_seeds),
         )?;
 
         transfer_token(
-            &self.vault_strategy_cfg_mint_1_fees_escrow,
-            &self.investor_mint_1_account,
-            investor_fees_owed_1,
-            &self.raydium_vault_1_mint,
-            &self.vault_strategy_config.to_account_info(),
-            &self.token_program,
+            self.rebalance_escrow_1,
+            self.investor_mint_1_account,
+            idle_amounts.amount_1,
+            self.strategy_fees.mint_1,
+            &vault_strategy_config.to_account_info(),
+            self.strategy_fees.token_program,
             Some(signer_seeds),
-        )?;
-
-        transfer_token(
-            &self.vault_strategy_cfg_mint_0_fees_escrow,
-            &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
-            creator_fees_owed_0,
-            &self.raydium_vault_0_mint,
-            &self.vault_strategy_config.to_account_info(),
-            &self.token_program,
-            Some(signer_seeds),
-        )?;
-
-        transfer_token(
-            &self.vault_strategy_cfg_mint_1_fees_escrow,
-            &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
-            creator_fees_owed_1,
-            &self.raydium_vault_1_mint,
-            &self.vault_strategy_config.to_account_info(),
-            &self.token_program,
-            Some(signer_seeds),
-        )?;
-
-        Ok(())
-    }
-
-    fn collect_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<(u64, u64)> {
-        let (strategy_fees_owed_0, strategy_fees_owed_1) = self.get_owed_fees()?;
-        msg!("strategy_fees_owed_0: {}", strategy_fees_owed_0);
-        msg!("strategy_fees_owed_1: {}", strategy_fees_owed_1);
-
-        self.decrease_liquidity(
-            0,
-            0,
-            0,
-            &self.vault_strategy_cfg_mint_0_fees_escrow.to_account_info(),
-            &self.vault_strategy_cfg_mint_1_fees_escrow.to_account_info(),
-            remaining_accounts,
-        )?;
-
-        Ok((strategy_fees_owed_0, strategy_fees_owed_1))
+        )
     }
 
-    fn decrease_liquidity(
-        &mut self,
-        liquidity: u128,
-        amount_0_min: u64,
-        amount_1_min: u64,
-        recipient_token_account_0: &AccountInfo<'info>,
-        recipient_token_account_1: &AccountInfo<'info>,
-        remaining_accounts: &[AccountInfo<'info>],
-    ) -> Result<()> {
-        let vault_strategy_config_bump_seed = &[self.vault_strategy_config.bump];
-        let vault_strategy_config_seeds = &[
-            VaultStrategyConfig::SEED.as_bytes(),
-            self.vault_strategy_config.creator.as_ref(),
-            self.vault_strategy_config.name.as_ref(),
-            vault_strategy_config_bump_seed,
-        ];
+    /// Part of the position liquidity backing `burn_shares`
+    fn liquidity_to_remove(
+        &self,
+        vault_strategy: &VaultStrategy,
+        burn_shares: u64,
+    ) -> Result<u128> {
+        msg!(
+            "raydium_personal_position.liquidity: {}",
+            self.raydium_personal_position.liquidity
+        );
 
-        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+        let dex_liquidity_to_remove = get_liquidity_for_shares(
+            self.raydium_personal_position.liquidity,
+            burn_shares,
+            vault_strategy.total_shares,
+        )?;
 
-        let cpi_accounts = cpi::accounts::DecreaseLiquidityV2 {
-            nft_owner: self.vault_strategy_config.to_account_info(),
-            nft_account: self.raydium_position_nft_account.to_account_info(),
-            pool_state: self.raydium_pool_state.to_account_info(),
-            protocol_position: self.raydium_protocol_position.to_account_info(),
-            personal_position: self.raydium_personal_position.to_account_info(),
-            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
-            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
-            recipient_token_account_0: recipient_token_account_0.clone(),
-            recipient_token_account_1: recipient_token_account_1.clone(),
-            token_vault_0: self.raydium_token_vault_0.to_account_info(),
-            token_vault_1: self.raydium_token_vault_1.to_account_info(),
-            token_program: self.token_program.to_account_info(),
-            token_program_2022: self.token_program_2022.to_account_info(),
-            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
-            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
-            memo_program: self.memo_program.to_account_info(),
-        };
+        msg!("dex_liquidity_to_remove: {}", dex_liquidity_to_remove);
+        require!(
+            dex_liquidity_to_remove > 0,
+            TokenizedVaultsErrorCode::LiquidityCalculatedToZero
+        );
 
-        let cpi_context = CpiContext::new_with_signer(
-            self.raydium_clmm_program.to_account_info(),
-            cpi_accounts,
-            signer_seeds,
-        )
-        .with_remaining_accounts(remaining_accounts.to_vec());
-        cpi::decrease_liquidity_v2(cpi_context, liquidity, amount_0_min, amount_1_min)
+        Ok(dex_liquidity_to_remove)
     }
 }
 
@@ -6,22 +6,23 @@ use anchor_spl::memo::Memo;
 use anchor_spl::token::{self, Token, Transfer};
 
 use crate::libraries::tick_math;
-use crate::utils::transfer_token;
+use crate::utils::{transfer_token, TokenPriceInfo};
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use num_bigint::BigInt;
-use raydium_clmm_cpi::cpi;
-use raydium_clmm_cpi::cpi::accounts::SwapSingleV2;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use raydium_clmm_cpi::program::RaydiumClmm;
 use raydium_clmm_cpi::states::{
     AmmConfig, ObservationState, PersonalPositionState, PoolState, POSITION_SEED,
 };
 
 use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
 use crate::{
-    get_delta_amounts_signed, vault_strategy_config, InvestReserveVault, InvestorEscrow,
-    SwapToRatioVault, VaultStrategy, VaultStrategyConfig, VaultStrategyStatus, MAX_PERCENTAGE,
-    RAYDIUM_CLMM_ID, U256, USDC_MINT,
+    assert_protocol_active, get_delta_amounts_signed, get_pool_checked_price_infos,
+    vault_strategy_config, InvestReserveVault, InvestorEscrow, OracleFeed, PoolPricedInstruction,
+    ProtocolConfig, RaydiumSwapCpi, RaydiumSwapSide, SwapToRatioVault, VaultOperation,
+    VaultStrategy, VaultStrategyConfig, MAX_PERCENTAGE, RAYDIUM_CLMM_ID, U256, USDC_MINT,
 };
 
 #[derive(Accounts)]
@@ -30,6 +31,12 @@ pub struct SwapToRatioRaydiumVaultStrategy<'info> {
     #[account(mut)]
     pub investor: Signer<'info>,
 
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(mut)]
     pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
 
@@ -164,6 +171,49 @@ pub struct SwapToRatioRaydiumVaultStrategy<'info> {
     #[account()]
     pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
     /// The program account for the most recent oracle observation
     /// USDC/Token 0
     #[account(mut)]
@@ -207,10 +257,12 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
         token_1_amount_out_min: u64,
         remaining_accounts: &[AccountInfo<'info>],
     ) -> Result<()> {
-        // require!(
-        //     self.vault_strategy_config.status == VaultStrategyStatus::Active,
-        //     TokenizedVaultsErrorCode::VaultStrategyConfigNotActive
-        // );
+        assert_protocol_active(&self.protocol_config.status)?;
+
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Deposit, &self.investor.key())?;
+
+        self.checked_price_infos()?;
 
         let usdc_amount = self
             .invest_reserve_vault
@@ -309,13 +361,7 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
         amount_out_min: u64,
         remaining_accounts: &[AccountInfo<'info>],
     ) -> Result<u64> {
-        let bump_seed = &[self.vault_strategy_config.bump];
-        let seeds = &[
-            VaultStrategyConfig::SEED.as_bytes(),
-            self.vault_strategy_config.creator.as_ref(),
-            self.vault_strategy_config.name.as_ref(),
-            bump_seed,
-        ];
+        let seeds = self.vault_strategy_config.signer_seeds();
         let signer_seeds = &[&seeds[..]];
 
         let is_usdc_token_mint_0 = {
@@ -340,35 +386,31 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
 
         let before_balance = self.vault_strategy_cfg_mint_0_escrow.amount;
 
-        let cpi_usdc_for_token_0_accounts = SwapSingleV2 {
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
             payer: self.vault_strategy_config.to_account_info(),
             amm_config: self.raydium_amm_config_usdc_for_token_0.to_account_info(),
             pool_state: self.raydium_pool_state_usdc_for_token_0.to_account_info(),
-            input_token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
-            output_token_account: self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
-            input_vault: self.raydium_vault_0_input.to_account_info(),
-            output_vault: self.raydium_vault_0_output.to_account_info(),
             observation_state: self.raydium_observation_state_0.to_account_info(),
             token_program: self.token_program.to_account_info(),
             token_program_2022: self.token_program_2022.to_account_info(),
             memo_program: self.memo_program.to_account_info(),
-            input_vault_mint: self.usdc_mint.to_account_info(),
-            output_vault_mint: self.raydium_vault_0_mint.to_account_info(),
-        };
-
-        let cpi_usdc_for_token_0_context = CpiContext::new(
-            self.raydium_clmm_program.to_account_info(),
-            cpi_usdc_for_token_0_accounts,
-        )
-        .with_remaining_accounts(remaining_accounts.to_vec())
-        .with_signer(signer_seeds);
-
-        cpi::swap_v2(
-            cpi_usdc_for_token_0_context,
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
+                vault: self.raydium_vault_0_input.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_mint_0_escrow.to_account_info(),
+                vault: self.raydium_vault_0_output.to_account_info(),
+                mint: self.raydium_vault_0_mint.to_account_info(),
+            },
             amount_in,
             amount_out_min,
-            0,
-            true, // True : Base In (amount_in, amount_out_minimum)
+            remaining_accounts,
+            Some(signer_seeds),
         )?;
 
         // Reload the account to get the updated balance
@@ -391,13 +433,7 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
         amount_out_min: u64,
         remaining_accounts: &[AccountInfo<'info>],
     ) -> Result<u64> {
-        let bump_seed = &[self.vault_strategy_config.bump];
-        let seeds = &[
-            VaultStrategyConfig::SEED.as_bytes(),
-            self.vault_strategy_config.creator.as_ref(),
-            self.vault_strategy_config.name.as_ref(),
-            bump_seed,
-        ];
+        let seeds = self.vault_strategy_config.signer_seeds();
         let signer_seeds = &[&seeds[..]];
         let is_usdc_token_mint_1 = {
             let pool_state = self.raydium_pool_state_usdc_for_token_1.load()?;
@@ -421,35 +457,31 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
 
         let before_balance = self.vault_strategy_cfg_mint_1_escrow.amount;
 
-        let cpi_usdc_for_token_1_accounts = SwapSingleV2 {
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
             payer: self.vault_strategy_config.to_account_info(),
             amm_config: self.raydium_amm_config_usdc_for_token_1.to_account_info(),
             pool_state: self.raydium_pool_state_usdc_for_token_1.to_account_info(),
-            input_token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
-            output_token_account: self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
-            input_vault: self.raydium_vault_1_input.to_account_info(),
-            output_vault: self.raydium_vault_1_output.to_account_info(),
             observation_state: self.raydium_observation_state_1.to_account_info(),
             token_program: self.token_program.to_account_info(),
             token_program_2022: self.token_program_2022.to_account_info(),
             memo_program: self.memo_program.to_account_info(),
-            input_vault_mint: self.usdc_mint.to_account_info(),
-            output_vault_mint: self.raydium_vault_1_mint.to_account_info(),
-        };
-
-        let cpi_usdc_for_token_1_context = CpiContext::new(
-            self.raydium_clmm_program.to_account_info(),
-            cpi_usdc_for_token_1_accounts,
-        )
-        .with_remaining_accounts(remaining_accounts.to_vec())
-        .with_signer(signer_seeds);
-
-        cpi::swap_v2(
-            cpi_usdc_for_token_1_context,
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_usdc_escrow.to_account_info(),
+                vault: self.raydium_vault_1_input.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: self.vault_strategy_cfg_mint_1_escrow.to_account_info(),
+                vault: self.raydium_vault_1_output.to_account_info(),
+                mint: self.raydium_vault_1_mint.to_account_info(),
+            },
             amount_in,
             amount_out_min,
-            0,
-            true, // True : Base In (amount_in, amount_out_minimum)
+            remaining_accounts,
+            Some(signer_seeds),
         )?;
 
         // Reload the account to get the updated balance
@@ -466,6 +498,31 @@ impl<'info> SwapToRatioRaydiumVaultStrategy<'info> {
         Ok(amount_out)
     }
 
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::SwapToRatio,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
     fn calc_ratio_amounts_for_usdc(&self, usdc_amount: u64) -> Result<(u64, u64)> {
         let pool_state = self.raydium_pool_state.load()?;
         let current_tick = pool_state.tick_current;
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::{PoolPricedInstruction, RemoveLiquidityRaydiumVaultStrategy};
+
+impl<'info> RemoveLiquidityRaydiumVaultStrategy<'info> {
+    /// Burns the shares worth `assets` USDC at the current NAV of the strategy, rounded up,
+    /// and removes the liquidity backing them. The NAV is priced with the oracle like
+    /// for deposits, so an oracle outage rejects it. Reverts if more than `max_shares`
+    /// would be burned
+    pub fn withdraw(
+        &mut self,
+        assets: u64,
+        max_shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+        investor_strategy_position_bump: u8,
+    ) -> Result<()> {
+        require!(assets > 0, TokenizedVaultsErrorCode::InvalidAmount);
+
+        let (token_0_price_info, token_1_price_info) = self
+            .checked_price_infos(PoolPricedInstruction::WithdrawRaydiumVault)?
+            .ok_or(TokenizedVaultsErrorCode::InvalidPrice)?;
+        let nav = self.nav(&token_0_price_info, &token_1_price_info)?;
+        msg!("nav: {}", nav);
+        self.vault_strategy.mark_to_market(nav)?;
+
+        self.sync_investor_shares(investor_strategy_position_bump)?;
+
+        let shares = self
+            .investor_strategy_position
+            .convert_assets_to_shares_rounding_up(
+                assets,
+                self.vault_strategy.total_assets,
+                self.vault_strategy.total_shares,
+            )?;
+
+        msg!("withdraw assets: {}", assets);
+        msg!("withdraw shares: {}", shares);
+
+        require!(
+            shares <= max_shares,
+            TokenizedVaultsErrorCode::SharesAboveMaximum
+        );
+
+        self.investor_strategy_position
+            .assert_redeemable_shares(shares)?;
+
+        self.remove_shares_liquidity(shares, amount_0_min, amount_1_min, remaining_accounts)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RemoveLiquidityRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+    assets: u64,
+    max_shares: u64,
+    amount_0_min: u64,
+    amount_1_min: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let investor_strategy_position_bump = ctx.bumps.investor_strategy_position;
+    ctx.accounts.withdraw(
+        assets,
+        max_shares,
+        amount_0_min,
+        amount_1_min,
+        ctx.remaining_accounts,
+        investor_strategy_position_bump,
+    )
+}
@@ -0,0 +1,911 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::{transfer_checked, Token, TransferChecked};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::program::RaydiumClmm;
+use raydium_clmm_cpi::states::{
+    AmmConfig, ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
+use crate::utils::{burn_shares, transfer_token, TokenPriceInfo};
+use crate::{
+    get_pool_checked_price_infos, InvestorEscrow, InvestorStrategyPosition, OracleFeed,
+    PoolPricedInstruction, ProtocolConfig, RaydiumPositionCpi, RaydiumSwapCpi, RaydiumSwapSide,
+    StrategyFeesTransfer, VaultOperation, VaultStrategy, VaultStrategyConfig,
+    VaultStrategyHoldings, DENOMINATOR_MULTIPLIER, MAX_REMOVE_PERCENTAGE, MIN_REMOVE_PERCENTAGE,
+    RAYDIUM_CLMM_ID, USDC_MINT,
+};
+
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct WithdrawToUsdcRaydiumVaultStrategy<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The investor position, created for shares received by transfer
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = InvestorStrategyPosition::DISCRIMINATOR.len() + InvestorStrategyPosition::INIT_SPACE,
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub investor_strategy_position: Box<Account<'info, InvestorStrategyPosition>>,
+
+    /// The share mint of the vault strategy
+    #[account(
+        mut,
+        address = vault_strategy.share_mint @ TokenizedVaultsErrorCode::InvalidShareMint
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The investor account holding the shares to burn
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The investor USDC escrow, default destination of the withdrawn USDC
+    #[account(
+        mut,
+        seeds = [
+            InvestorEscrow::VAULT_SEED.as_bytes(),
+            investor.key().as_ref(),
+        ],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = investor,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Optional destination, the investor USDC account instead of the escrow
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = investor,
+    )]
+    pub investor_usdc_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the withdrawn token 0 in this account before the swap
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_WITHDRAW_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_withdraw_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the withdrawn token 1 in this account before the swap
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_WITHDRAW_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_withdraw_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 0, idle amounts waiting to be compounded,
+    /// the part backing the burned shares is swapped to USDC with the withdrawn tokens
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The rebalance escrow account for the token 1, idle amounts waiting to be compounded,
+    /// the part backing the burned shares is swapped to USDC with the withdrawn tokens
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = vault_strategy_config,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 0
+    /// Vault strategy Config receives the collected token 0 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The escrow account for the token 1
+    /// Vault strategy Config receives the collected token 1 fees in this account
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 0
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The performance fees escrow account for the token 1
+    #[account(
+        mut,
+        seeds = [
+            VaultStrategyConfig::VAULT_PERF_FEES_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_perf_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 0
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_0_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_0_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_0_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The protocol fees escrow account for the token 1
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [
+            ProtocolConfig::PROTOCOL_FEES_ESCROW_SEED.as_bytes(),
+            raydium_vault_1_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = raydium_vault_1_mint,
+        token::authority = protocol_config,
+    )]
+    pub protocol_mint_1_fees_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ TokenizedVaultsErrorCode::InvalidMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* DEX Raydium */
+    #[account(address = RAYDIUM_CLMM_ID)]
+    pub raydium_clmm_program: Program<'info, RaydiumClmm>,
+
+    /// CHECK: Token account that holds the raydium position NFT
+    #[account(mut)]
+    pub raydium_position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    #[account(mut)]
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        mut,
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Store the information of raydium market marking in range
+    #[account(mut)]
+    pub raydium_protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Account to mark the raydium position's lower tick as initialized
+    #[account(mut)]
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK:Account to store data for the raydium position's upper tick
+    #[account(mut)]
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The address that holds raydium pool tokens for token_0
+    #[account(mut)]
+    pub raydium_token_vault_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds raydium pool tokens for token_1
+    #[account(mut)]
+    pub raydium_token_vault_1: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK:
+    /// The factory state to read protocol fees
+    #[account(mut)]
+    pub raydium_amm_config_token_0_for_usdc: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK:
+    /// The program account of the pool in which the swap will be performed
+    #[account(mut)]
+    pub raydium_pool_state_token_0_for_usdc: AccountLoader<'info, PoolState>,
+
+    /// CHECK:
+    /// The factory state to read protocol fees
+    #[account(mut)]
+    pub raydium_amm_config_token_1_for_usdc: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK:
+    /// The program account of the pool in which the swap will be performed
+    #[account(mut)]
+    pub raydium_pool_state_token_1_for_usdc: AccountLoader<'info, PoolState>,
+
+    /// The vault token account for input token
+    /// Vault strategy Config sends token 0 to this account to swap to USDC
+    /// Pool: USDC / Token 0
+    #[account(mut)]
+    pub raydium_vault_0_input: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for input token
+    /// Vault strategy Config sends token 1 to this account to swap to USDC
+    /// Pool: USDC / Token 1
+    #[account(mut)]
+    pub raydium_vault_1_input: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    /// Investor receives USDC from this account
+    /// Pool: USDC / Token 0
+    #[account(mut)]
+    pub raydium_vault_0_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    /// Investor receives USDC from this account
+    /// Pool: USDC / Token 1
+    #[account(mut)]
+    pub raydium_vault_1_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The program account for the most recent oracle observation
+    /// USDC/Token 0
+    #[account(mut)]
+    pub raydium_observation_state_0: AccountLoader<'info, ObservationState>,
+
+    /// The program account for the most recent oracle observation
+    /// USDC/Token 1
+    #[account(mut)]
+    pub raydium_observation_state_1: AccountLoader<'info, ObservationState>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // tickarray_bitmap_extension: must add account if need
+    // Pubkey::default() as separator
+    // Token 0 / USDC swap tick_array_account_...
+    // Pubkey::default() as separator
+    // Token 1 / USDC swap tick_array_account_...
+}
+
+impl<'info> WithdrawToUsdcRaydiumVaultStrategy<'info> {
+    /// Same as `remove_liquidity_raydium_vault_strategy` but both legs, fees included,
+    /// are swapped back to USDC and credited to the investor escrow or USDC account.
+    /// percentage has to be between 10%(10*1e9) and 100%(100*1e9)
+    pub fn process(
+        &mut self,
+        percentage: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        usdc_amount_out_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+        investor_strategy_position_bump: u8,
+    ) -> Result<()> {
+        self.vault_strategy_config
+            .assert_operation_allowed(VaultOperation::Withdraw, &self.investor.key())?;
+        self.checked_price_infos()?;
+
+        require!(
+            percentage >= MIN_REMOVE_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidRemovePercentage
+        );
+        require!(
+            percentage <= MAX_REMOVE_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidRemovePercentage
+        );
+
+        let mut groups =
+            remaining_accounts.split(|account_info| account_info.key() == Pubkey::default());
+        let position_remaining_accounts = groups.next().unwrap_or_default();
+        let remaining_accounts_token_0_for_usdc = groups.next().unwrap_or_default();
+        let remaining_accounts_token_1_for_usdc = groups.next().unwrap_or_default();
+
+        self.sync_investor_shares(investor_strategy_position_bump)?;
+
+        let burn_shares_amount = (percentage as u128)
+            .checked_mul(self.investor_strategy_position.shares as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(MAX_REMOVE_PERCENTAGE as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)? as u64;
+
+        require!(
+            burn_shares_amount > 0,
+            TokenizedVaultsErrorCode::SharesCalculatedToZero
+        );
+
+        self.accrue_fees(position_remaining_accounts)?;
+        self.investor_strategy_position.settle_fees(
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )?;
+
+        let total_vault_shares = self.vault_strategy.total_shares;
+
+        let investor_shares_percentage = self
+            .investor_strategy_position
+            .convert_shares_to_percentage(total_vault_shares)?;
+
+        msg!("investor_shares_percentage: {}", investor_shares_percentage);
+
+        let dex_liquidity_to_remove =
+            self.liquidity_to_remove(percentage, investor_shares_percentage)?;
+
+        let before_balance_0 = self.vault_strategy_cfg_mint_0_withdraw_escrow.amount;
+        let before_balance_1 = self.vault_strategy_cfg_mint_1_withdraw_escrow.amount;
+
+        self.raydium_position().decrease_liquidity(
+            dex_liquidity_to_remove,
+            amount_0_min,
+            amount_1_min,
+            &self
+                .vault_strategy_cfg_mint_0_withdraw_escrow
+                .to_account_info(),
+            &self
+                .vault_strategy_cfg_mint_1_withdraw_escrow
+                .to_account_info(),
+            position_remaining_accounts,
+        )?;
+
+        self.pay_fees_owed()?;
+        self.pay_idle_amounts(burn_shares_amount)?;
+
+        self.vault_strategy_cfg_mint_0_withdraw_escrow.reload()?;
+        self.vault_strategy_cfg_mint_1_withdraw_escrow.reload()?;
+
+        let token_0_amount = self
+            .vault_strategy_cfg_mint_0_withdraw_escrow
+            .amount
+            .saturating_sub(before_balance_0);
+        let token_1_amount = self
+            .vault_strategy_cfg_mint_1_withdraw_escrow
+            .amount
+            .saturating_sub(before_balance_1);
+
+        msg!("token_0_amount: {}", token_0_amount);
+        msg!("token_1_amount: {}", token_1_amount);
+
+        let destination = self
+            .investor_usdc_account
+            .as_ref()
+            .unwrap_or(&self.escrow_vault)
+            .to_account_info();
+        let usdc_balance_before = self.usdc_balance(&destination)?;
+
+        self.swap_token_0_for_usdc(
+            token_0_amount,
+            &destination,
+            remaining_accounts_token_0_for_usdc,
+        )?;
+        self.swap_token_1_for_usdc(
+            token_1_amount,
+            &destination,
+            remaining_accounts_token_1_for_usdc,
+        )?;
+
+        let usdc_amount_out = self
+            .usdc_balance(&destination)?
+            .saturating_sub(usdc_balance_before);
+
+        msg!("usdc_amount_out: {}", usdc_amount_out);
+        require!(
+            usdc_amount_out >= usdc_amount_out_min,
+            TokenizedVaultsErrorCode::AmountOutBelowMinimum
+        );
+
+        let total_vault_assets = self.vault_strategy.total_assets;
+        let total_vault_shares = self.vault_strategy.total_shares;
+
+        let burn_assets = self.investor_strategy_position.convert_shares_to_assets(
+            burn_shares_amount,
+            total_vault_assets,
+            total_vault_shares,
+        )?;
+        self.investor_strategy_position.remove_shares(
+            burn_shares_amount,
+            total_vault_assets,
+            total_vault_shares,
+        )?;
+        msg!("burn_assets: {}", burn_assets);
+        msg!("burn_shares: {}", burn_shares_amount);
+        self.vault_strategy.remove_assets(burn_assets)?;
+        self.vault_strategy.remove_shares(burn_shares_amount)?;
+
+        burn_shares(
+            &self.share_mint,
+            &self.investor_share_account,
+            burn_shares_amount,
+            &self.investor.to_account_info(),
+            &self.token_program,
+        )?;
+
+        msg!("Liquidity withdrawn to USDC successfully");
+        Ok(())
+    }
+
+    fn usdc_balance(&self, destination: &AccountInfo<'info>) -> Result<u64> {
+        let data = destination.try_borrow_data()?;
+        let account = anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?;
+        Ok(account.amount)
+    }
+
+    fn liquidity_to_remove(
+        &self,
+        percentage: u64,
+        investor_shares_percentage: u64,
+    ) -> Result<u128> {
+        let investor_remove_percentage = (investor_shares_percentage as u128)
+            .checked_mul(percentage as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(MAX_REMOVE_PERCENTAGE as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+        let dex_liquidity_to_remove = investor_remove_percentage
+            .checked_mul(self.raydium_personal_position.liquidity)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(DENOMINATOR_MULTIPLIER as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+        msg!("dex_liquidity_to_remove: {}", dex_liquidity_to_remove);
+
+        Ok(dex_liquidity_to_remove)
+    }
+
+    /// Refreshes the investor shares from their share token balance,
+    /// see `InvestorStrategyPosition::sync_shares`
+    fn sync_investor_shares(&mut self, bump: u8) -> Result<()> {
+        self.investor_strategy_position.open_if_needed(
+            self.investor.key(),
+            self.vault_strategy.key(),
+            bump,
+        );
+        self.investor_strategy_position.sync_shares(
+            self.investor_share_account.amount,
+            self.vault_strategy.fee_growth_per_share_0_x64,
+            self.vault_strategy.fee_growth_per_share_1_x64,
+        )
+    }
+
+    /// Prices of the strategy tokens, see `get_pool_checked_price_infos`
+    fn checked_price_infos(&self) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        get_pool_checked_price_infos(
+            PoolPricedInstruction::WithdrawToUsdc,
+            &self.vault_strategy_config,
+            self.vault_strategy.key(),
+            &*self.raydium_pool_state.load()?,
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    fn raydium_position(&self) -> RaydiumPositionCpi<'_, 'info> {
+        RaydiumPositionCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            vault_strategy_config: &self.vault_strategy_config,
+            nft_account: self.raydium_position_nft_account.to_account_info(),
+            pool_state: self.raydium_pool_state.to_account_info(),
+            protocol_position: self.raydium_protocol_position.to_account_info(),
+            personal_position: self.raydium_personal_position.to_account_info(),
+            tick_array_lower: self.raydium_tick_array_lower.to_account_info(),
+            tick_array_upper: self.raydium_tick_array_upper.to_account_info(),
+            token_vault_0: self.raydium_token_vault_0.to_account_info(),
+            token_vault_1: self.raydium_token_vault_1.to_account_info(),
+            vault_0_mint: self.raydium_vault_0_mint.to_account_info(),
+            vault_1_mint: self.raydium_vault_1_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+    }
+
+    /// Collects the position fees into the fees escrows, the creator and protocol cuts leave
+    /// them right away and the investor part is spread over the current shares
+    fn accrue_fees(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let strategy_fees = StrategyFeesTransfer {
+            protocol_config: &self.protocol_config,
+            vault_strategy_config: &self.vault_strategy_config,
+            vault_strategy_key: self.vault_strategy.key(),
+            fees_escrow_0: &self.vault_strategy_cfg_mint_0_fees_escrow,
+            fees_escrow_1: &self.vault_strategy_cfg_mint_1_fees_escrow,
+            perf_fees_escrow_0: &self.vault_strategy_cfg_mint_0_perf_fees_escrow,
+            perf_fees_escrow_1: &self.vault_strategy_cfg_mint_1_perf_fees_escrow,
+            protocol_fees_escrow_0: &self.protocol_mint_0_fees_escrow,
+            protocol_fees_escrow_1: &self.protocol_mint_1_fees_escrow,
+            mint_0: &self.raydium_vault_0_mint,
+            mint_1: &self.raydium_vault_1_mint,
+            token_program: &self.token_program,
+        };
+
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = strategy_fees.collect(
+            &self.raydium_position(),
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+            remaining_accounts,
+        )?;
+        strategy_fees.accrue(
+            &mut self.vault_strategy,
+            strategy_fees_owed_0,
+            strategy_fees_owed_1,
+        )
+    }
+
+    /// The fees settled on the position join the withdrawn tokens to be swapped to USDC
+    fn pay_fees_owed(&mut self) -> Result<()> {
+        let (fees_owed_0, fees_owed_1) = self.investor_strategy_position.take_fees_owed();
+
+        msg!("investor fees_owed_0: {}", fees_owed_0);
+        msg!("investor fees_owed_1: {}", fees_owed_1);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_0_fees_escrow,
+            &self.vault_strategy_cfg_mint_0_withdraw_escrow,
+            fees_owed_0,
+            &self.raydium_vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_1_fees_escrow,
+            &self.vault_strategy_cfg_mint_1_withdraw_escrow,
+            fees_owed_1,
+            &self.raydium_vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )
+    }
+
+    /// The rebalance escrows count in the NAV of the strategy, the part of their balance
+    /// backing the burned shares joins the withdrawn tokens to be swapped to USDC
+    fn pay_idle_amounts(&mut self, burn_shares: u64) -> Result<()> {
+        let idle_amounts = VaultStrategyHoldings {
+            amount_0: self.vault_strategy_cfg_mint_0_rebalance_escrow.amount,
+            amount_1: self.vault_strategy_cfg_mint_1_rebalance_escrow.amount,
+        }
+        .share_of(burn_shares, self.vault_strategy.total_shares)?;
+
+        msg!("idle amount_0: {}", idle_amounts.amount_0);
+        msg!("idle amount_1: {}", idle_amounts.amount_1);
+
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_0_rebalance_escrow,
+            &self.vault_strategy_cfg_mint_0_withdraw_escrow,
+            idle_amounts.amount_0,
+            &self.raydium_vault_0_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            &self.vault_strategy_cfg_mint_1_rebalance_escrow,
+            &self.vault_strategy_cfg_mint_1_withdraw_escrow,
+            idle_amounts.amount_1,
+            &self.raydium_vault_1_mint,
+            &self.vault_strategy_config.to_account_info(),
+            &self.token_program,
+            Some(signer_seeds),
+        )
+    }
+
+    fn swap_token_0_for_usdc(
+        &mut self,
+        amount_in: u64,
+        destination: &AccountInfo<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if amount_in == 0 {
+            return Ok(());
+        }
+
+        let seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&seeds[..]];
+
+        if self.usdc_mint.key() == self.raydium_vault_0_mint.key() {
+            let cpi_accounts = TransferChecked {
+                from: self
+                    .vault_strategy_cfg_mint_0_withdraw_escrow
+                    .to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+                to: destination.clone(),
+                authority: self.vault_strategy_config.to_account_info(),
+            };
+            let cpi_context = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            return transfer_checked(cpi_context, amount_in, self.usdc_mint.decimals);
+        }
+
+        require!(
+            !remaining_accounts.is_empty(),
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        // The aggregate USDC minimum is checked once both legs are swapped
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config_token_0_for_usdc.to_account_info(),
+            pool_state: self.raydium_pool_state_token_0_for_usdc.to_account_info(),
+            observation_state: self.raydium_observation_state_0.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self
+                    .vault_strategy_cfg_mint_0_withdraw_escrow
+                    .to_account_info(),
+                vault: self.raydium_vault_0_input.to_account_info(),
+                mint: self.raydium_vault_0_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: destination.clone(),
+                vault: self.raydium_vault_0_output.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            amount_in,
+            0,
+            remaining_accounts,
+            Some(signer_seeds),
+        )
+    }
+
+    fn swap_token_1_for_usdc(
+        &mut self,
+        amount_in: u64,
+        destination: &AccountInfo<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if amount_in == 0 {
+            return Ok(());
+        }
+
+        let seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&seeds[..]];
+
+        if self.usdc_mint.key() == self.raydium_vault_1_mint.key() {
+            let cpi_accounts = TransferChecked {
+                from: self
+                    .vault_strategy_cfg_mint_1_withdraw_escrow
+                    .to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+                to: destination.clone(),
+                authority: self.vault_strategy_config.to_account_info(),
+            };
+            let cpi_context = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            return transfer_checked(cpi_context, amount_in, self.usdc_mint.decimals);
+        }
+
+        require!(
+            !remaining_accounts.is_empty(),
+            TokenizedVaultsErrorCode::InsufficientRemainingAccounts
+        );
+
+        // The aggregate USDC minimum is checked once both legs are swapped
+        RaydiumSwapCpi {
+            raydium_clmm_program: self.raydium_clmm_program.to_account_info(),
+            payer: self.vault_strategy_config.to_account_info(),
+            amm_config: self.raydium_amm_config_token_1_for_usdc.to_account_info(),
+            pool_state: self.raydium_pool_state_token_1_for_usdc.to_account_info(),
+            observation_state: self.raydium_observation_state_1.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_program_2022: self.token_program_2022.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+        }
+        .swap(
+            &RaydiumSwapSide {
+                token_account: self
+                    .vault_strategy_cfg_mint_1_withdraw_escrow
+                    .to_account_info(),
+                vault: self.raydium_vault_1_input.to_account_info(),
+                mint: self.raydium_vault_1_mint.to_account_info(),
+            },
+            &RaydiumSwapSide {
+                token_account: destination.clone(),
+                vault: self.raydium_vault_1_output.to_account_info(),
+                mint: self.usdc_mint.to_account_info(),
+            },
+            amount_in,
+            0,
+            remaining_accounts,
+            Some(signer_seeds),
+        )
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, WithdrawToUsdcRaydiumVaultStrategy<'info>>,
+    _strategy_id: u8,
+    percentage: u64,
+    amount_0_min: u64,
+    amount_1_min: u64,
+    usdc_amount_out_min: u64,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let investor_strategy_position_bump = ctx.bumps.investor_strategy_position;
+    ctx.accounts.process(
+        percentage,
+        amount_0_min,
+        amount_1_min,
+        usdc_amount_out_min,
+        ctx.remaining_accounts,
+        investor_strategy_position_bump,
+    )
+}
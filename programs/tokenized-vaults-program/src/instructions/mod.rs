@@ -8,5 +8,20 @@ pub use investor::*;
 pub mod creator;
 pub use creator::*;
 
+pub mod crank;
+pub use crank::*;
+
 pub mod raydium_helpers;
 pub use raydium_helpers::*;
+
+pub mod nav;
+pub use nav::*;
+
+pub mod price_deviation;
+pub use price_deviation::*;
+
+pub mod twap;
+pub use twap::*;
+
+pub mod views;
+pub use views::*;
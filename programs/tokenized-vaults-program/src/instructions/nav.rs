@@ -0,0 +1,273 @@
+use anchor_lang::prelude::*;
+use raydium_clmm_cpi::states::{PersonalPositionState, PoolState};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::utils::{convert_amounts_to_usd, TokenPriceInfo};
+use crate::{get_delta_amounts_signed, VaultStrategy, U256};
+
+/// Shares and NAV are denominated in USDC
+pub const NAV_DECIMALS: u8 = 6;
+
+/// Token amounts backing the shares of a vault strategy
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VaultStrategyHoldings {
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+impl VaultStrategyHoldings {
+    pub fn add(&mut self, amount_0: u64, amount_1: u64) -> Result<()> {
+        self.amount_0 = self
+            .amount_0
+            .checked_add(amount_0)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        self.amount_1 = self
+            .amount_1
+            .checked_add(amount_1)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Part of the holdings backing `shares`, rounded down
+    pub fn share_of(&self, shares: u64, total_shares: u64) -> Result<VaultStrategyHoldings> {
+        require!(
+            shares <= total_shares,
+            TokenizedVaultsErrorCode::InsufficientShares
+        );
+
+        let share_of_amount = |amount: u64| -> Result<u64> {
+            let amount = (amount as u128)
+                .checked_mul(shares as u128)
+                .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+                .checked_div(total_shares as u128)
+                .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+            Ok(amount as u64)
+        };
+
+        Ok(VaultStrategyHoldings {
+            amount_0: share_of_amount(self.amount_0)?,
+            amount_1: share_of_amount(self.amount_1)?,
+        })
+    }
+
+    /// Value of the holdings in USDC, priced with Pyth
+    pub fn to_usd(
+        &self,
+        token_0_decimals: u8,
+        token_1_decimals: u8,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        let (amount_0_usd, amount_1_usd) = convert_amounts_to_usd(
+            self.amount_0,
+            self.amount_1,
+            token_0_decimals,
+            token_1_decimals,
+            token_0_price_info,
+            token_1_price_info,
+            NAV_DECIMALS,
+        )?;
+
+        amount_0_usd
+            .checked_add(amount_1_usd)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow.into())
+    }
+}
+
+/// Token amounts returned if the whole liquidity was removed at the current price,
+/// rounded down like a decrease of liquidity
+pub fn get_position_amounts(
+    tick_current: i32,
+    sqrt_price_x64: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    liquidity: u128,
+) -> Result<(u64, u64)> {
+    if liquidity == 0 {
+        return Ok((0, 0));
+    }
+
+    let liquidity_delta =
+        i128::try_from(liquidity).map_err(|_| error!(TokenizedVaultsErrorCode::MathOverflow))?;
+
+    get_delta_amounts_signed(
+        tick_current,
+        sqrt_price_x64,
+        tick_lower_index,
+        tick_upper_index,
+        -liquidity_delta,
+    )
+}
+
+/// Part of the position liquidity backing `shares`, rounded down
+pub fn get_liquidity_for_shares(liquidity: u128, shares: u64, total_shares: u64) -> Result<u128> {
+    require!(
+        shares <= total_shares,
+        TokenizedVaultsErrorCode::InsufficientShares
+    );
+
+    let liquidity_for_shares = U256::from(liquidity)
+        .checked_mul(U256::from(shares))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+        .checked_div(U256::from(total_shares))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+    Ok(liquidity_for_shares.as_u128())
+}
+
+/// Value in USDC of one whole share, 0 before any share exists
+pub fn get_share_price(nav: u64, total_shares: u64) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(0);
+    }
+
+    let share_price = (nav as u128)
+        .checked_mul(10u128.pow(VaultStrategy::SHARE_MINT_DECIMALS as u32))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+        .checked_div(total_shares as u128)
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+    u64::try_from(share_price).map_err(|_| error!(TokenizedVaultsErrorCode::MathOverflow))
+}
+
+/// Everything backing the shares of a strategy:
+/// - the Raydium position valued at the current pool price
+/// - the idle amounts waiting to be compounded
+///
+/// Trading fees are left out, they are collected before every deposit and
+/// belong to the shares that earned them through the fee growth per share
+pub fn get_vault_strategy_holdings(
+    pool_state: &PoolState,
+    personal_position: &PersonalPositionState,
+    idle_amounts: (u64, u64),
+) -> Result<VaultStrategyHoldings> {
+    let mut holdings = VaultStrategyHoldings::default();
+
+    let (position_amount_0, position_amount_1) = get_position_amounts(
+        pool_state.tick_current,
+        pool_state.sqrt_price_x64,
+        personal_position.tick_lower_index,
+        personal_position.tick_upper_index,
+        personal_position.liquidity,
+    )?;
+    holdings.add(position_amount_0, position_amount_1)?;
+
+    holdings.add(idle_amounts.0, idle_amounts.1)?;
+
+    Ok(holdings)
+}
+
+#[cfg(test)]
+mod nav_tests {
+    use super::*;
+    use crate::{tick_math, OracleSource};
+
+    const LIQUIDITY: u128 = 1_000_000_000;
+
+    fn price_info(price: i64, expo: i32) -> TokenPriceInfo {
+        TokenPriceInfo {
+            price,
+            expo,
+            conf: 0,
+            publish_time: 0,
+            source: OracleSource::Pyth,
+        }
+    }
+
+    #[test]
+    fn empty_position_has_no_amounts() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+        assert_eq!(
+            get_position_amounts(0, sqrt_price_x64, -100, 100, 0).unwrap(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn position_below_range_is_only_token_0() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(-200).unwrap();
+        let (amount_0, amount_1) =
+            get_position_amounts(-200, sqrt_price_x64, -100, 100, LIQUIDITY).unwrap();
+        assert!(amount_0 > 0);
+        assert_eq!(amount_1, 0);
+    }
+
+    #[test]
+    fn position_above_range_is_only_token_1() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(200).unwrap();
+        let (amount_0, amount_1) =
+            get_position_amounts(200, sqrt_price_x64, -100, 100, LIQUIDITY).unwrap();
+        assert_eq!(amount_0, 0);
+        assert!(amount_1 > 0);
+    }
+
+    #[test]
+    fn position_in_range_rounds_down() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+        let (amount_0, amount_1) =
+            get_position_amounts(0, sqrt_price_x64, -100, 100, LIQUIDITY).unwrap();
+        let (amount_0_up, amount_1_up) =
+            get_delta_amounts_signed(0, sqrt_price_x64, -100, 100, LIQUIDITY as i128).unwrap();
+        assert!(amount_0 > 0 && amount_1 > 0);
+        assert!(amount_0 <= amount_0_up && amount_1 <= amount_1_up);
+    }
+
+    #[test]
+    fn liquidity_is_split_by_shares() {
+        assert_eq!(
+            get_liquidity_for_shares(LIQUIDITY, 250, 1_000).unwrap(),
+            LIQUIDITY / 4
+        );
+        assert_eq!(
+            get_liquidity_for_shares(LIQUIDITY, 1_000, 1_000).unwrap(),
+            LIQUIDITY
+        );
+        assert_eq!(
+            get_liquidity_for_shares(u128::MAX, 1, 2).unwrap(),
+            u128::MAX / 2
+        );
+        assert!(get_liquidity_for_shares(LIQUIDITY, 1_001, 1_000).is_err());
+    }
+
+    #[test]
+    fn holdings_are_split_by_shares() {
+        let holdings = VaultStrategyHoldings {
+            amount_0: 1_000,
+            amount_1: 333,
+        };
+        assert_eq!(
+            holdings.share_of(1, 3).unwrap(),
+            VaultStrategyHoldings {
+                amount_0: 333,
+                amount_1: 111
+            }
+        );
+        assert_eq!(holdings.share_of(3, 3).unwrap(), holdings);
+        assert!(holdings.share_of(4, 3).is_err());
+    }
+
+    #[test]
+    fn share_price_is_per_whole_share() {
+        // 2_000 USDC of NAV over 1_000 whole shares
+        assert_eq!(
+            get_share_price(2_000_000_000, 1_000_000_000).unwrap(),
+            2_000_000
+        );
+        assert_eq!(get_share_price(2_000_000_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn holdings_are_valued_in_usdc() {
+        let holdings = VaultStrategyHoldings {
+            amount_0: 2_000_000_000, // 2 tokens with 9 decimals
+            amount_1: 5_000_000,     // 5 USDC
+        };
+        let usd = holdings
+            .to_usd(
+                9,
+                6,
+                &price_info(150_00000000, -8),
+                &price_info(1_00000000, -8),
+            )
+            .unwrap();
+        assert_eq!(usd, 305_000_000);
+    }
+}
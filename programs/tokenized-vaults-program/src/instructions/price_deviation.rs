@@ -0,0 +1,476 @@
+use anchor_lang::prelude::*;
+use raydium_clmm_cpi::states::PoolState;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::{fixed_point_64, tick_math};
+use crate::oracle::TokenOracle;
+use crate::utils::{is_price_out_of_bounds, PriceBounds, TokenPriceInfo};
+use crate::{VaultStrategyConfig, MAX_PERCENTAGE, U256};
+
+/// Investor instructions executed or valued at the pool spot price,
+/// every one of them goes through `get_pool_checked_price_infos`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolPricedInstruction {
+    DepositRaydiumVault,
+    DepositTokensRaydiumVault,
+    AddLiquidity,
+    SwapToRatio,
+    WithdrawToUsdc,
+    RemoveLiquidity,
+    WithdrawRaydiumVault,
+    RedeemVault,
+    ClaimInvestorFees,
+}
+
+impl PoolPricedInstruction {
+    /// Deposits mint shares at the oracle value of the strategy, they always read the prices
+    pub fn mints_shares(self) -> bool {
+        matches!(
+            self,
+            Self::DepositRaydiumVault | Self::DepositTokensRaydiumVault | Self::AddLiquidity
+        )
+    }
+
+    /// Deposits and the withdraw by assets convert between shares and USDC at the oracle value
+    /// of the strategy, they always read the prices
+    pub fn prices_shares(self) -> bool {
+        self.mints_shares() || self == Self::WithdrawRaydiumVault
+    }
+
+    /// In-kind exits pay out the tokens of the position, bounded by the investor minimums.
+    /// They are checked while the oracle prices both tokens, but a stale or unconfident
+    /// price does not lock the principal
+    pub fn is_in_kind_exit(self) -> bool {
+        matches!(
+            self,
+            Self::RemoveLiquidity | Self::RedeemVault | Self::ClaimInvestorFees
+        )
+    }
+}
+
+/// USD prices of the two strategy tokens
+pub fn get_price_infos(
+    token_0_oracle: &TokenOracle,
+    token_1_oracle: &TokenOracle,
+    price_bounds: &PriceBounds,
+) -> Result<(TokenPriceInfo, TokenPriceInfo)> {
+    Ok((
+        token_0_oracle.price_info(price_bounds)?,
+        token_1_oracle.price_info(price_bounds)?,
+    ))
+}
+
+/// USD prices of the strategy tokens, rejected while the pool price deviates from them
+/// by more than the maximum of the vault. `None` when the instruction goes through without
+/// prices: the check is disabled and it does not price shares, or an in-kind exit
+/// while the registered oracle accounts only hold prices out of bounds
+pub fn get_pool_checked_price_infos(
+    instruction: PoolPricedInstruction,
+    vault_strategy_config: &VaultStrategyConfig,
+    vault_strategy_key: Pubkey,
+    pool_state: &PoolState,
+    token_0_oracle: &TokenOracle,
+    token_1_oracle: &TokenOracle,
+    price_bounds: &PriceBounds,
+) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+    require_keys_eq!(
+        token_0_oracle.oracle_feed.mint,
+        pool_state.token_mint_0,
+        TokenizedVaultsErrorCode::InvalidOracleAccount
+    );
+    require_keys_eq!(
+        token_1_oracle.oracle_feed.mint,
+        pool_state.token_mint_1,
+        TokenizedVaultsErrorCode::InvalidOracleAccount
+    );
+
+    check_pool_price_deviation(
+        instruction,
+        vault_strategy_config,
+        vault_strategy_key,
+        pool_state.sqrt_price_x64,
+        (pool_state.mint_decimals_0, pool_state.mint_decimals_1),
+        || get_price_infos(token_0_oracle, token_1_oracle, price_bounds),
+    )
+}
+
+/// Deviation check of `get_pool_checked_price_infos`, `price_infos` reads the oracle
+pub fn check_pool_price_deviation(
+    instruction: PoolPricedInstruction,
+    vault_strategy_config: &VaultStrategyConfig,
+    vault_strategy_key: Pubkey,
+    sqrt_price_x64: u128,
+    (token_0_decimals, token_1_decimals): (u8, u8),
+    price_infos: impl FnOnce() -> Result<(TokenPriceInfo, TokenPriceInfo)>,
+) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+    let has_price_deviation_check = vault_strategy_config.has_price_deviation_check();
+    if !has_price_deviation_check && !instruction.prices_shares() {
+        return Ok(None);
+    }
+
+    let (token_0_price_info, token_1_price_info) = match price_infos() {
+        Ok(price_infos) => price_infos,
+        Err(error) if instruction.is_in_kind_exit() && is_price_out_of_bounds(&error) => {
+            msg!(
+                "Oracle price out of bounds, in-kind exit not checked: {}",
+                error
+            );
+            return Ok(None);
+        }
+        Err(error) => return Err(error),
+    };
+
+    if has_price_deviation_check {
+        let deviation = get_pool_price_deviation(
+            sqrt_price_x64,
+            &token_0_price_info,
+            &token_1_price_info,
+            token_0_decimals,
+            token_1_decimals,
+        )?;
+        vault_strategy_config.check_pool_price_deviation(
+            vault_strategy_key,
+            sqrt_price_x64,
+            deviation,
+        )?;
+    }
+
+    Ok(Some((token_0_price_info, token_1_price_info)))
+}
+
+/// Price of token 0 in token 1 raw units, Q64.64, from the pool sqrt price
+pub fn get_pool_price_x64(sqrt_price_x64: u128) -> Result<U256> {
+    require!(
+        (tick_math::MIN_SQRT_PRICE_X64..=tick_math::MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64),
+        TokenizedVaultsErrorCode::InvalidPrice
+    );
+
+    let sqrt_price_x64 = U256::from(sqrt_price_x64);
+    Ok((sqrt_price_x64 * sqrt_price_x64) >> fixed_point_64::RESOLUTION)
+}
+
+/// Price of token 0 in token 1 raw units, Q64.64, from the Pyth USD prices:
+/// (price_0 * 10^expo_0 / 10^decimals_0) / (price_1 * 10^expo_1 / 10^decimals_1)
+pub fn get_oracle_price_x64(
+    token_0_price_info: &TokenPriceInfo,
+    token_1_price_info: &TokenPriceInfo,
+    token_0_decimals: u8,
+    token_1_decimals: u8,
+) -> Result<U256> {
+    require!(
+        token_0_price_info.price > 0 && token_1_price_info.price > 0,
+        TokenizedVaultsErrorCode::InvalidPrice
+    );
+
+    let exponent = token_0_price_info.expo as i64 - token_1_price_info.expo as i64
+        + token_1_decimals as i64
+        - token_0_decimals as i64;
+    let scale = U256::from(10u64)
+        .checked_pow(U256::from(exponent.unsigned_abs()))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+    let mut numerator = U256::from(token_0_price_info.price as u64) << fixed_point_64::RESOLUTION;
+    let mut denominator = U256::from(token_1_price_info.price as u64);
+    if exponent >= 0 {
+        numerator = numerator
+            .checked_mul(scale)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+    } else {
+        denominator = denominator
+            .checked_mul(scale)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+    }
+
+    Ok(numerator / denominator)
+}
+
+/// |pool price - oracle price| / oracle price in BPS scale (100% = 1_000_000),
+/// saturating at u32::MAX
+pub fn get_price_deviation(pool_price_x64: U256, oracle_price_x64: U256) -> Result<u32> {
+    require!(
+        !oracle_price_x64.is_zero(),
+        TokenizedVaultsErrorCode::InvalidPrice
+    );
+
+    let difference = if pool_price_x64 > oracle_price_x64 {
+        pool_price_x64 - oracle_price_x64
+    } else {
+        oracle_price_x64 - pool_price_x64
+    };
+    let deviation = difference
+        .checked_mul(U256::from(MAX_PERCENTAGE))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+        / oracle_price_x64;
+
+    Ok(deviation.min(U256::from(u32::MAX)).low_u32())
+}
+
+/// Deviation of the pool price from the Pyth price, in BPS scale (100% = 1_000_000)
+pub fn get_pool_price_deviation(
+    sqrt_price_x64: u128,
+    token_0_price_info: &TokenPriceInfo,
+    token_1_price_info: &TokenPriceInfo,
+    token_0_decimals: u8,
+    token_1_decimals: u8,
+) -> Result<u32> {
+    get_price_deviation(
+        get_pool_price_x64(sqrt_price_x64)?,
+        get_oracle_price_x64(
+            token_0_price_info,
+            token_1_price_info,
+            token_0_decimals,
+            token_1_decimals,
+        )?,
+    )
+}
+
+#[cfg(test)]
+mod price_deviation_tests {
+    use super::*;
+    use crate::OracleSource;
+
+    fn price_info(price: i64, expo: i32) -> TokenPriceInfo {
+        TokenPriceInfo {
+            price,
+            expo,
+            conf: 0,
+            publish_time: 0,
+            source: OracleSource::Pyth,
+        }
+    }
+
+    #[test]
+    fn pool_at_the_oracle_price_has_no_deviation() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+        let usd = price_info(100_000_000, -8);
+
+        assert_eq!(
+            get_pool_price_deviation(sqrt_price_x64, &usd, &usd, 6, 6).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn measures_the_deviation_of_a_moved_pool() {
+        // 1.0001^200 ~ 1.0202
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(200).unwrap();
+        let usd = price_info(100_000_000, -8);
+
+        let deviation = get_pool_price_deviation(sqrt_price_x64, &usd, &usd, 6, 6).unwrap();
+        assert!((20_100..20_300).contains(&deviation), "{}", deviation);
+
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(-200).unwrap();
+        let deviation = get_pool_price_deviation(sqrt_price_x64, &usd, &usd, 6, 6).unwrap();
+        assert!((19_700..19_900).contains(&deviation), "{}", deviation);
+    }
+
+    #[test]
+    fn scales_the_oracle_price_by_decimals_and_exponents() {
+        // SOL (9 decimals) at 150 USD, USDC (6 decimals) at 1 USD:
+        // one raw SOL unit is worth 0.15 raw USDC units
+        let sol = price_info(15_000_000_000, -8);
+        let usdc = price_info(1_000_000, -6);
+        let sqrt_price_x64 = (0.15f64.sqrt() * fixed_point_64::Q64 as f64) as u128;
+
+        let deviation = get_pool_price_deviation(sqrt_price_x64, &sol, &usdc, 9, 6).unwrap();
+        assert!(deviation <= 1, "{}", deviation);
+
+        // Token order reversed: one raw USDC unit is worth 1 / 0.15 raw SOL units
+        let sqrt_price_x64 = ((1.0f64 / 0.15).sqrt() * fixed_point_64::Q64 as f64) as u128;
+        let deviation = get_pool_price_deviation(sqrt_price_x64, &usdc, &sol, 6, 9).unwrap();
+        assert!(deviation <= 1, "{}", deviation);
+    }
+
+    #[test]
+    fn deviation_is_relative_to_the_oracle_price() {
+        let oracle = U256::from(fixed_point_64::Q64);
+
+        assert_eq!(
+            get_price_deviation(oracle * U256::from(2u64), oracle).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            get_price_deviation(oracle / U256::from(2u64), oracle).unwrap(),
+            500_000
+        );
+        assert_eq!(
+            get_price_deviation(U256::MAX >> 32, U256::from(1u64)).unwrap(),
+            u32::MAX
+        );
+        assert!(get_price_deviation(oracle, U256::zero()).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_prices() {
+        let usd = price_info(100_000_000, -8);
+        assert!(get_oracle_price_x64(&price_info(0, -8), &usd, 6, 6).is_err());
+        assert!(get_pool_price_x64(0).is_err());
+    }
+
+    fn vault(max_price_deviation: u32) -> VaultStrategyConfig {
+        let mut vault = VaultStrategyConfig::default();
+        vault.set_max_price_deviation(max_price_deviation).unwrap();
+        vault
+    }
+
+    /// Runs `instruction` through the guard with the pool at `tick` and both tokens at 1 USD,
+    /// or with the oracle unavailable
+    fn check(
+        instruction: PoolPricedInstruction,
+        vault: &VaultStrategyConfig,
+        tick: i32,
+        oracle_available: bool,
+    ) -> Result<Option<(TokenPriceInfo, TokenPriceInfo)>> {
+        check_pool_price_deviation(
+            instruction,
+            vault,
+            Pubkey::new_unique(),
+            tick_math::get_sqrt_price_at_tick(tick).unwrap(),
+            (6, 6),
+            || {
+                require!(oracle_available, TokenizedVaultsErrorCode::PriceTooOld);
+                let usd = price_info(100_000_000, -8);
+                Ok((usd.clone(), usd))
+            },
+        )
+    }
+
+    /// The entry point is rejected while the pool deviates by more than 2%,
+    /// only in-kind exits go through an oracle outage
+    fn assert_guarded(instruction: PoolPricedInstruction) {
+        let vault = vault(20_000);
+
+        // 1.0001^100 ~ 1.01
+        let price_infos = check(instruction, &vault, 100, true).unwrap();
+        assert_eq!(price_infos.unwrap().0.price, 100_000_000);
+
+        // 1.0001^300 ~ 1.03
+        assert_eq!(
+            check(instruction, &vault, 300, true).unwrap_err(),
+            TokenizedVaultsErrorCode::PoolPriceDeviationTooHigh.into()
+        );
+        assert_eq!(
+            check(instruction, &vault, -300, true).unwrap_err(),
+            TokenizedVaultsErrorCode::PoolPriceDeviationTooHigh.into()
+        );
+
+        let outage = check(instruction, &vault, 0, false);
+        if instruction.is_in_kind_exit() {
+            assert!(outage.unwrap().is_none());
+        } else {
+            assert_eq!(
+                outage.unwrap_err(),
+                TokenizedVaultsErrorCode::PriceTooOld.into()
+            );
+        }
+    }
+
+    #[test]
+    fn guards_deposit_raydium_vault() {
+        assert_guarded(PoolPricedInstruction::DepositRaydiumVault);
+        assert!(PoolPricedInstruction::DepositRaydiumVault.mints_shares());
+    }
+
+    #[test]
+    fn guards_deposit_tokens_raydium_vault() {
+        assert_guarded(PoolPricedInstruction::DepositTokensRaydiumVault);
+    }
+
+    #[test]
+    fn guards_add_liquidity() {
+        assert_guarded(PoolPricedInstruction::AddLiquidity);
+    }
+
+    #[test]
+    fn guards_swap_to_ratio() {
+        assert_guarded(PoolPricedInstruction::SwapToRatio);
+    }
+
+    #[test]
+    fn guards_withdraw_to_usdc() {
+        assert_guarded(PoolPricedInstruction::WithdrawToUsdc);
+        assert!(!PoolPricedInstruction::WithdrawToUsdc.is_in_kind_exit());
+    }
+
+    #[test]
+    fn guards_remove_liquidity() {
+        assert_guarded(PoolPricedInstruction::RemoveLiquidity);
+        assert!(PoolPricedInstruction::RemoveLiquidity.is_in_kind_exit());
+    }
+
+    #[test]
+    fn guards_withdraw_raydium_vault() {
+        assert_guarded(PoolPricedInstruction::WithdrawRaydiumVault);
+        assert!(PoolPricedInstruction::WithdrawRaydiumVault.prices_shares());
+        assert!(!PoolPricedInstruction::WithdrawRaydiumVault.is_in_kind_exit());
+    }
+
+    #[test]
+    fn guards_redeem_vault() {
+        assert_guarded(PoolPricedInstruction::RedeemVault);
+        assert!(PoolPricedInstruction::RedeemVault.is_in_kind_exit());
+    }
+
+    #[test]
+    fn guards_claim_investor_fees() {
+        assert_guarded(PoolPricedInstruction::ClaimInvestorFees);
+        assert!(PoolPricedInstruction::ClaimInvestorFees.is_in_kind_exit());
+    }
+
+    #[test]
+    fn in_kind_exits_skip_only_prices_out_of_bounds() {
+        let vault = vault(20_000);
+
+        for (error, skipped) in [
+            (TokenizedVaultsErrorCode::PriceTooOld, true),
+            (TokenizedVaultsErrorCode::PriceConfidenceTooWide, true),
+            (TokenizedVaultsErrorCode::OracleSourceUnavailable, false),
+            (TokenizedVaultsErrorCode::InvalidOracleAccount, false),
+            (TokenizedVaultsErrorCode::PythPriceFeedNotFound, false),
+        ] {
+            let result = check_pool_price_deviation(
+                PoolPricedInstruction::RemoveLiquidity,
+                &vault,
+                Pubkey::new_unique(),
+                tick_math::get_sqrt_price_at_tick(0).unwrap(),
+                (6, 6),
+                || -> Result<(TokenPriceInfo, TokenPriceInfo)> { Err(error.into()) },
+            );
+            if skipped {
+                assert!(result.unwrap().is_none());
+            } else {
+                assert_eq!(result.unwrap_err(), error.into());
+            }
+        }
+    }
+
+    #[test]
+    fn disabled_check_reads_the_oracle_only_to_price_shares() {
+        let vault = vault(0);
+
+        for instruction in [
+            PoolPricedInstruction::AddLiquidity,
+            PoolPricedInstruction::WithdrawRaydiumVault,
+        ] {
+            assert!(check(instruction, &vault, 5_000, true).unwrap().is_some());
+            assert!(check(instruction, &vault, 0, false).is_err());
+        }
+
+        for instruction in [
+            PoolPricedInstruction::SwapToRatio,
+            PoolPricedInstruction::WithdrawToUsdc,
+            PoolPricedInstruction::RemoveLiquidity,
+        ] {
+            let price_infos = check_pool_price_deviation(
+                instruction,
+                &vault,
+                Pubkey::new_unique(),
+                tick_math::get_sqrt_price_at_tick(5_000).unwrap(),
+                (6, 6),
+                || -> Result<(TokenPriceInfo, TokenPriceInfo)> { panic!("oracle read") },
+            )
+            .unwrap();
+            assert!(price_infos.is_none());
+        }
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
+    error::TokenizedVaultsErrorCode,
     fixed_point_64,
     state::{raydium_tick_array, RaydiumTickArrayState},
-    MulDiv, U128,
+    tick_math,
+    utils::{split_fees_amount, transfer_token},
+    MulDiv, ProtocolConfig, ProtocolFeesAccruedEvent, VaultStrategy, VaultStrategyConfig, U128,
+    U256,
 };
 use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 use num_bigint::BigInt;
+use raydium_clmm_cpi::cpi;
 use raydium_clmm_cpi::states::{PersonalPositionState, PoolState, TickArrayState, TickState};
 
 pub fn get_raydium_owed_fees<'info>(
@@ -40,6 +47,33 @@ pub fn get_raydium_owed_fees<'info>(
     (fees_owed_0, fees_owed_1)
 }
 
+/// Fees owed to a Raydium position, the ones settled on it plus the ones accrued
+/// in its range since its last update
+pub fn get_position_owed_fees(
+    pool_state: &AccountLoader<PoolState>,
+    tick_array_lower: &AccountLoader<TickArrayState>,
+    tick_array_upper: &AccountLoader<TickArrayState>,
+    personal_position: &PersonalPositionState,
+) -> Result<(u64, u64)> {
+    let tick_array_lower_loader = tick_array_lower.load()?;
+    let tick_array_upper_loader = tick_array_upper.load()?;
+    let pool_state = pool_state.load()?;
+
+    let tick_lower_state = tick_array_lower_loader
+        .get_tick_state(personal_position.tick_lower_index, pool_state.tick_spacing)?;
+    let tick_upper_state = tick_array_upper_loader
+        .get_tick_state(personal_position.tick_upper_index, pool_state.tick_spacing)?;
+
+    Ok(get_raydium_owed_fees(
+        tick_lower_state,
+        tick_upper_state,
+        personal_position,
+        pool_state.tick_current,
+        pool_state.fee_growth_global_0_x64,
+        pool_state.fee_growth_global_1_x64,
+    ))
+}
+
 pub fn calculate_raydium_latest_fees(
     last_total_fees: u64,
     fee_growth_inside_last_x64: u128,
@@ -57,3 +91,586 @@ pub fn calculate_raydium_latest_fees(
 
     last_total_fees.checked_add(fee_growth_delta).unwrap()
 }
+
+/// Value of amount_0 in token_1 units at the given sqrt price
+/// `amount_0 * P` with `P = (sqrt_price_x64 / 2^64)^2`
+pub fn convert_amount_0_to_amount_1(amount_0: u64, sqrt_price_x64: u128) -> Result<U256> {
+    let q64 = U256::from(fixed_point_64::Q64);
+    U256::from(amount_0)
+        .mul_div_floor(U256::from(sqrt_price_x64), q64)
+        .and_then(|value| value.mul_div_floor(U256::from(sqrt_price_x64), q64))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow.into())
+}
+
+/// Value of amount_1 in token_0 units at the given sqrt price
+/// `amount_1 / P` with `P = (sqrt_price_x64 / 2^64)^2`
+pub fn convert_amount_1_to_amount_0(amount_1: U256, sqrt_price_x64: u128) -> Result<U256> {
+    require!(sqrt_price_x64 > 0, TokenizedVaultsErrorCode::SqrtPriceX64);
+    let q64 = U256::from(fixed_point_64::Q64);
+    amount_1
+        .mul_div_floor(q64, U256::from(sqrt_price_x64))
+        .and_then(|value| value.mul_div_floor(q64, U256::from(sqrt_price_x64)))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow.into())
+}
+
+/// Amount to swap so that (amount_0, amount_1) matches the ratio of a position
+/// in [tick_lower_index, tick_upper_index) at the current price.
+/// Price impact and swap fees are ignored, the leftover stays for the next compound.
+/// returns (amount_in, zero_for_one)
+pub fn get_swap_amount_to_position_ratio(
+    sqrt_price_x64: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    amount_0: u64,
+    amount_1: u64,
+) -> Result<(u64, bool)> {
+    let sqrt_price_lower_x64 = tick_math::get_sqrt_price_at_tick(tick_lower_index)?;
+    let sqrt_price_upper_x64 = tick_math::get_sqrt_price_at_tick(tick_upper_index)?;
+
+    // Below the range the position only holds token 0, above it only token 1
+    if sqrt_price_x64 <= sqrt_price_lower_x64 {
+        return Ok((amount_1, false));
+    }
+    if sqrt_price_x64 >= sqrt_price_upper_x64 {
+        return Ok((amount_0, true));
+    }
+
+    let (weight_0, weight_1) =
+        get_position_value_weights(sqrt_price_x64, tick_lower_index, tick_upper_index)?;
+
+    let value_0 = convert_amount_0_to_amount_1(amount_0, sqrt_price_x64)?;
+    let total_value = value_0 + U256::from(amount_1);
+    let target_value_0 = total_value
+        .mul_div_floor(weight_0, weight_0 + weight_1)
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+    if value_0 > target_value_0 {
+        let amount_in = convert_amount_1_to_amount_0(value_0 - target_value_0, sqrt_price_x64)?;
+        Ok((u64::min(amount_in.to_underflow_u64(), amount_0), true))
+    } else {
+        let amount_in = target_value_0 - value_0;
+        Ok((u64::min(amount_in.to_underflow_u64(), amount_1), false))
+    }
+}
+
+/// Share of the value held in each token by a position in [tick_lower_index, tick_upper_index)
+/// at the current price, both weights are expressed in token 1 units.
+/// returns (weight_0, weight_1)
+pub fn get_position_value_weights(
+    sqrt_price_x64: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<(U256, U256)> {
+    let sqrt_price_lower_x64 = tick_math::get_sqrt_price_at_tick(tick_lower_index)?;
+    let sqrt_price_upper_x64 = tick_math::get_sqrt_price_at_tick(tick_upper_index)?;
+
+    if sqrt_price_x64 <= sqrt_price_lower_x64 {
+        return Ok((U256::from(1u8), U256::from(0u8)));
+    }
+    if sqrt_price_x64 >= sqrt_price_upper_x64 {
+        return Ok((U256::from(0u8), U256::from(1u8)));
+    }
+
+    // Value held in each token by one unit of liquidity, in token 1 units (x64):
+    // value_0 = (√P_upper - √P) * √P / √P_upper
+    // value_1 = √P - √P_lower
+    let weight_0 = U256::from(sqrt_price_upper_x64 - sqrt_price_x64)
+        .mul_div_floor(U256::from(sqrt_price_x64), U256::from(sqrt_price_upper_x64))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+    let weight_1 = U256::from(sqrt_price_x64 - sqrt_price_lower_x64);
+    Ok((weight_0, weight_1))
+}
+
+/// Split an amount of a third token (USDC) between token 0 and token 1
+/// following the value ratio of the position.
+/// returns (amount_for_token_0, amount_for_token_1)
+pub fn split_amount_to_position_ratio(
+    sqrt_price_x64: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    amount: u64,
+) -> Result<(u64, u64)> {
+    let (weight_0, weight_1) =
+        get_position_value_weights(sqrt_price_x64, tick_lower_index, tick_upper_index)?;
+
+    let amount_for_token_0 = U256::from(amount)
+        .mul_div_floor(weight_0, weight_0 + weight_1)
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+        .to_underflow_u64();
+    Ok((
+        amount_for_token_0,
+        amount.saturating_sub(amount_for_token_0),
+    ))
+}
+
+/// Expected output of a swap at the given sqrt price, price impact and swap fees are ignored
+pub fn get_swap_amount_out_at_price(
+    sqrt_price_x64: u128,
+    amount_in: u64,
+    zero_for_one: bool,
+) -> Result<u64> {
+    let amount_out = if zero_for_one {
+        convert_amount_0_to_amount_1(amount_in, sqrt_price_x64)?
+    } else {
+        convert_amount_1_to_amount_0(U256::from(amount_in), sqrt_price_x64)?
+    };
+    Ok(amount_out.to_underflow_u64())
+}
+
+/// Accounts of a Raydium position held by a vault strategy config,
+/// the config PDA signs as the owner of the position NFT
+pub struct RaydiumPositionCpi<'a, 'info> {
+    pub raydium_clmm_program: AccountInfo<'info>,
+    pub vault_strategy_config: &'a Account<'info, VaultStrategyConfig>,
+    pub nft_account: AccountInfo<'info>,
+    pub pool_state: AccountInfo<'info>,
+    pub protocol_position: AccountInfo<'info>,
+    pub personal_position: AccountInfo<'info>,
+    pub tick_array_lower: AccountInfo<'info>,
+    pub tick_array_upper: AccountInfo<'info>,
+    pub token_vault_0: AccountInfo<'info>,
+    pub token_vault_1: AccountInfo<'info>,
+    pub vault_0_mint: AccountInfo<'info>,
+    pub vault_1_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_program_2022: AccountInfo<'info>,
+    pub memo_program: AccountInfo<'info>,
+}
+
+impl<'info> RaydiumPositionCpi<'_, 'info> {
+    /// Removes liquidity from the position into the recipients, a zero liquidity only
+    /// collects the fees owed to the position
+    pub fn decrease_liquidity(
+        &self,
+        liquidity: u128,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        recipient_token_account_0: &AccountInfo<'info>,
+        recipient_token_account_1: &AccountInfo<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        let cpi_accounts = cpi::accounts::DecreaseLiquidityV2 {
+            nft_owner: self.vault_strategy_config.to_account_info(),
+            nft_account: self.nft_account.clone(),
+            pool_state: self.pool_state.clone(),
+            protocol_position: self.protocol_position.clone(),
+            personal_position: self.personal_position.clone(),
+            tick_array_lower: self.tick_array_lower.clone(),
+            tick_array_upper: self.tick_array_upper.clone(),
+            recipient_token_account_0: recipient_token_account_0.clone(),
+            recipient_token_account_1: recipient_token_account_1.clone(),
+            token_vault_0: self.token_vault_0.clone(),
+            token_vault_1: self.token_vault_1.clone(),
+            token_program: self.token_program.clone(),
+            token_program_2022: self.token_program_2022.clone(),
+            vault_0_mint: self.vault_0_mint.clone(),
+            vault_1_mint: self.vault_1_mint.clone(),
+            memo_program: self.memo_program.clone(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.raydium_clmm_program.clone(),
+            cpi_accounts,
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts.to_vec());
+        cpi::decrease_liquidity_v2(cpi_context, liquidity, amount_0_min, amount_1_min)
+    }
+
+    /// Adds liquidity to the position from token accounts owned by the vault strategy config
+    pub fn increase_liquidity(
+        &self,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        token_account_0: &AccountInfo<'info>,
+        token_account_1: &AccountInfo<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        let cpi_accounts = cpi::accounts::IncreaseLiquidityV2 {
+            nft_owner: self.vault_strategy_config.to_account_info(),
+            nft_account: self.nft_account.clone(),
+            pool_state: self.pool_state.clone(),
+            protocol_position: self.protocol_position.clone(),
+            personal_position: self.personal_position.clone(),
+            tick_array_lower: self.tick_array_lower.clone(),
+            tick_array_upper: self.tick_array_upper.clone(),
+            token_account_0: token_account_0.clone(),
+            token_account_1: token_account_1.clone(),
+            token_vault_0: self.token_vault_0.clone(),
+            token_vault_1: self.token_vault_1.clone(),
+            token_program: self.token_program.clone(),
+            token_program_2022: self.token_program_2022.clone(),
+            vault_0_mint: self.vault_0_mint.clone(),
+            vault_1_mint: self.vault_1_mint.clone(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.raydium_clmm_program.clone(),
+            cpi_accounts,
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts.to_vec());
+        cpi::increase_liquidity_v2(cpi_context, liquidity, amount_0_max, amount_1_max, None)
+    }
+
+    /// Burns the position NFT and closes the personal position, the position must be empty.
+    /// Raydium refunds the rent to the vault strategy config
+    pub fn close_position(
+        &self,
+        position_nft_mint: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        Self::close_position_nft(
+            &self.raydium_clmm_program,
+            self.vault_strategy_config,
+            position_nft_mint,
+            &self.nft_account,
+            &self.personal_position,
+            system_program,
+            &self.token_program_2022,
+        )
+    }
+
+    /// Same as `close_position`, for callers that only hold the accounts of the position NFT
+    pub fn close_position_nft(
+        raydium_clmm_program: &AccountInfo<'info>,
+        vault_strategy_config: &Account<'info, VaultStrategyConfig>,
+        position_nft_mint: &AccountInfo<'info>,
+        nft_account: &AccountInfo<'info>,
+        personal_position: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        token_program_2022: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let vault_strategy_config_seeds = vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        let cpi_accounts = cpi::accounts::ClosePosition {
+            nft_owner: vault_strategy_config.to_account_info(),
+            position_nft_mint: position_nft_mint.clone(),
+            position_nft_account: nft_account.clone(),
+            personal_position: personal_position.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program_2022.clone(),
+        };
+
+        let cpi_context =
+            CpiContext::new_with_signer(raydium_clmm_program.clone(), cpi_accounts, signer_seeds);
+        cpi::close_position(cpi_context)
+    }
+}
+
+/// One side of a Raydium swap: the token account of the payer, the pool vault and the mint
+pub struct RaydiumSwapSide<'info> {
+    pub token_account: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+}
+
+/// Accounts of a swap in a Raydium pool
+pub struct RaydiumSwapCpi<'info> {
+    pub raydium_clmm_program: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub amm_config: AccountInfo<'info>,
+    pub pool_state: AccountInfo<'info>,
+    pub observation_state: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_program_2022: AccountInfo<'info>,
+    pub memo_program: AccountInfo<'info>,
+}
+
+impl<'info> RaydiumSwapCpi<'info> {
+    /// Swaps exactly `amount_in`, signed with `signer_seeds` when the payer is a PDA
+    pub fn swap(
+        &self,
+        input: &RaydiumSwapSide<'info>,
+        output: &RaydiumSwapSide<'info>,
+        amount_in: u64,
+        amount_out_min: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+        signer_seeds: Option<&[&[&[u8]]]>,
+    ) -> Result<()> {
+        let cpi_accounts = cpi::accounts::SwapSingleV2 {
+            payer: self.payer.clone(),
+            amm_config: self.amm_config.clone(),
+            pool_state: self.pool_state.clone(),
+            input_token_account: input.token_account.clone(),
+            output_token_account: output.token_account.clone(),
+            input_vault: input.vault.clone(),
+            output_vault: output.vault.clone(),
+            observation_state: self.observation_state.clone(),
+            token_program: self.token_program.clone(),
+            token_program_2022: self.token_program_2022.clone(),
+            memo_program: self.memo_program.clone(),
+            input_vault_mint: input.mint.clone(),
+            output_vault_mint: output.mint.clone(),
+        };
+
+        let mut cpi_context = CpiContext::new(self.raydium_clmm_program.clone(), cpi_accounts)
+            .with_remaining_accounts(remaining_accounts.to_vec());
+        if let Some(signer_seeds) = signer_seeds {
+            cpi_context = cpi_context.with_signer(signer_seeds);
+        }
+
+        cpi::swap_v2(
+            cpi_context,
+            amount_in,
+            amount_out_min,
+            0,
+            true, // True : Base In (amount_in, amount_out_minimum)
+        )
+    }
+}
+
+/// Fees collected from a vault strategy position into the config fees escrows,
+/// split between the investors, the creator and the protocol
+pub struct StrategyFeesTransfer<'a, 'info> {
+    pub protocol_config: &'a Account<'info, ProtocolConfig>,
+    pub vault_strategy_config: &'a Account<'info, VaultStrategyConfig>,
+    pub vault_strategy_key: Pubkey,
+    pub fees_escrow_0: &'a InterfaceAccount<'info, TokenAccount>,
+    pub fees_escrow_1: &'a InterfaceAccount<'info, TokenAccount>,
+    pub perf_fees_escrow_0: &'a InterfaceAccount<'info, TokenAccount>,
+    pub perf_fees_escrow_1: &'a InterfaceAccount<'info, TokenAccount>,
+    pub protocol_fees_escrow_0: &'a InterfaceAccount<'info, TokenAccount>,
+    pub protocol_fees_escrow_1: &'a InterfaceAccount<'info, TokenAccount>,
+    pub mint_0: &'a InterfaceAccount<'info, Mint>,
+    pub mint_1: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'info> StrategyFeesTransfer<'_, 'info> {
+    /// Collects the fees owed to the position into the fees escrows with a zero
+    /// liquidity decrease
+    /// returns (strategy_fees_owed_0, strategy_fees_owed_1)
+    pub fn collect(
+        &self,
+        position: &RaydiumPositionCpi<'_, 'info>,
+        pool_state: &AccountLoader<PoolState>,
+        tick_array_lower: &AccountLoader<TickArrayState>,
+        tick_array_upper: &AccountLoader<TickArrayState>,
+        personal_position: &PersonalPositionState,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<(u64, u64)> {
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = get_position_owed_fees(
+            pool_state,
+            tick_array_lower,
+            tick_array_upper,
+            personal_position,
+        )?;
+        msg!("strategy_fees_owed_0: {}", strategy_fees_owed_0);
+        msg!("strategy_fees_owed_1: {}", strategy_fees_owed_1);
+
+        position.decrease_liquidity(
+            0,
+            0,
+            0,
+            &self.fees_escrow_0.to_account_info(),
+            &self.fees_escrow_1.to_account_info(),
+            remaining_accounts,
+        )?;
+
+        Ok((strategy_fees_owed_0, strategy_fees_owed_1))
+    }
+
+    /// Pays the protocol and performance cuts of the fees owed to the strategy, the investors
+    /// part stays in the fees escrows and is spread over the current shares of the strategy
+    pub fn accrue(
+        &self,
+        vault_strategy: &mut VaultStrategy,
+        strategy_fees_owed_0: u64,
+        strategy_fees_owed_1: u64,
+    ) -> Result<()> {
+        let (investor_fees_owed_0, investor_fees_owed_1) =
+            self.pay_cuts(strategy_fees_owed_0, strategy_fees_owed_1)?;
+
+        vault_strategy.accrue_fees(investor_fees_owed_0, investor_fees_owed_1)
+    }
+
+    /// Pays the protocol and performance cuts of the fees owed to the strategy
+    /// and moves the investors part to the given escrows
+    pub fn transfer(
+        &self,
+        strategy_fees_owed_0: u64,
+        strategy_fees_owed_1: u64,
+        investor_escrow_0: &InterfaceAccount<'info, TokenAccount>,
+        investor_escrow_1: &InterfaceAccount<'info, TokenAccount>,
+    ) -> Result<()> {
+        let (investor_fees_owed_0, investor_fees_owed_1) =
+            self.pay_cuts(strategy_fees_owed_0, strategy_fees_owed_1)?;
+
+        self.transfer_from_fees_escrows(
+            investor_escrow_0,
+            investor_escrow_1,
+            investor_fees_owed_0,
+            investor_fees_owed_1,
+        )
+    }
+
+    /// Sends the creator and protocol cuts of the fees owed to the strategy to their escrows
+    /// returns the investors part (investor_fees_owed_0, investor_fees_owed_1)
+    fn pay_cuts(&self, strategy_fees_owed_0: u64, strategy_fees_owed_1: u64) -> Result<(u64, u64)> {
+        let (investor_fees_owed_0, creator_fees_owed_0, protocol_fees_owed_0) = split_fees_amount(
+            strategy_fees_owed_0,
+            self.protocol_config.protocol_fees,
+            self.vault_strategy_config.performance_fee,
+        )?;
+
+        let (investor_fees_owed_1, creator_fees_owed_1, protocol_fees_owed_1) = split_fees_amount(
+            strategy_fees_owed_1,
+            self.protocol_config.protocol_fees,
+            self.vault_strategy_config.performance_fee,
+        )?;
+
+        msg!("protocol_fees_owed_0: {}", protocol_fees_owed_0);
+        msg!("protocol_fees_owed_1: {}", protocol_fees_owed_1);
+        msg!("creator_fees_owed_0: {}", creator_fees_owed_0);
+        msg!("creator_fees_owed_1: {}", creator_fees_owed_1);
+
+        self.transfer_from_fees_escrows(
+            self.perf_fees_escrow_0,
+            self.perf_fees_escrow_1,
+            creator_fees_owed_0,
+            creator_fees_owed_1,
+        )?;
+        self.transfer_from_fees_escrows(
+            self.protocol_fees_escrow_0,
+            self.protocol_fees_escrow_1,
+            protocol_fees_owed_0,
+            protocol_fees_owed_1,
+        )?;
+
+        emit!(ProtocolFeesAccruedEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy_key,
+            mint: self.mint_0.key(),
+            amount: protocol_fees_owed_0,
+        });
+
+        emit!(ProtocolFeesAccruedEvent {
+            vault_strategy_config_key: self.vault_strategy_config.key(),
+            vault_strategy_key: self.vault_strategy_key,
+            mint: self.mint_1.key(),
+            amount: protocol_fees_owed_1,
+        });
+
+        Ok((investor_fees_owed_0, investor_fees_owed_1))
+    }
+
+    /// Moves `amount_0` and `amount_1` out of the fees escrows, signed by the vault strategy config
+    pub fn transfer_from_fees_escrows(
+        &self,
+        destination_0: &InterfaceAccount<'info, TokenAccount>,
+        destination_1: &InterfaceAccount<'info, TokenAccount>,
+        amount_0: u64,
+        amount_1: u64,
+    ) -> Result<()> {
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+        let authority = self.vault_strategy_config.to_account_info();
+
+        transfer_token(
+            self.fees_escrow_0,
+            destination_0,
+            amount_0,
+            self.mint_0,
+            &authority,
+            self.token_program,
+            Some(signer_seeds),
+        )?;
+
+        transfer_token(
+            self.fees_escrow_1,
+            destination_1,
+            amount_1,
+            self.mint_1,
+            &authority,
+            self.token_program,
+            Some(signer_seeds),
+        )
+    }
+}
+
+#[cfg(test)]
+mod swap_to_position_ratio_tests {
+    use super::*;
+
+    #[test]
+    fn symmetric_range_at_price_one_swaps_half() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+
+        let (amount_in, zero_for_one) =
+            get_swap_amount_to_position_ratio(sqrt_price_x64, -1000, 1000, 1_000_000, 0).unwrap();
+        assert!(zero_for_one);
+        assert!((499_000..=501_000).contains(&amount_in));
+
+        let (amount_in, zero_for_one) =
+            get_swap_amount_to_position_ratio(sqrt_price_x64, -1000, 1000, 0, 1_000_000).unwrap();
+        assert!(!zero_for_one);
+        assert!((499_000..=501_000).contains(&amount_in));
+    }
+
+    #[test]
+    fn balanced_amounts_need_no_swap() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+
+        let (amount_in, _) =
+            get_swap_amount_to_position_ratio(sqrt_price_x64, -1000, 1000, 1_000_000, 1_000_000)
+                .unwrap();
+        assert!(amount_in <= 1_000);
+    }
+
+    #[test]
+    fn out_of_range_swaps_everything_to_the_active_token() {
+        let below = tick_math::get_sqrt_price_at_tick(-2000).unwrap();
+        assert_eq!(
+            get_swap_amount_to_position_ratio(below, -1000, 1000, 10, 20).unwrap(),
+            (20, false)
+        );
+
+        let above = tick_math::get_sqrt_price_at_tick(2000).unwrap();
+        assert_eq!(
+            get_swap_amount_to_position_ratio(above, -1000, 1000, 10, 20).unwrap(),
+            (10, true)
+        );
+    }
+
+    #[test]
+    fn split_amount_follows_the_position_ratio() {
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(0).unwrap();
+        let (amount_0, amount_1) =
+            split_amount_to_position_ratio(sqrt_price_x64, -1000, 1000, 1_000_000).unwrap();
+        assert!((499_000..=501_000).contains(&amount_0));
+        assert_eq!(amount_0 + amount_1, 1_000_000);
+
+        let below = tick_math::get_sqrt_price_at_tick(-2000).unwrap();
+        assert_eq!(
+            split_amount_to_position_ratio(below, -1000, 1000, 1_000_000).unwrap(),
+            (1_000_000, 0)
+        );
+
+        let above = tick_math::get_sqrt_price_at_tick(2000).unwrap();
+        assert_eq!(
+            split_amount_to_position_ratio(above, -1000, 1000, 1_000_000).unwrap(),
+            (0, 1_000_000)
+        );
+    }
+
+    #[test]
+    fn amount_out_at_price_follows_the_price() {
+        // tick 6932 ~ price 2.0
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(6932).unwrap();
+
+        let amount_1 = get_swap_amount_out_at_price(sqrt_price_x64, 1_000_000, true).unwrap();
+        assert!((1_999_000..=2_001_000).contains(&amount_1));
+
+        let amount_0 = get_swap_amount_out_at_price(sqrt_price_x64, 2_000_000, false).unwrap();
+        assert!((999_000..=1_001_000).contains(&amount_0));
+    }
+}
@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use raydium_clmm_cpi::states::{ObservationState, PoolState};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::tick_math;
+use crate::{
+    get_pool_price_x64, get_price_deviation, get_swap_amount_out_at_price, MAX_PERCENTAGE, U256,
+};
+
+/// Observation of the Raydium CLMM oracle: the pool tick accumulated over time
+/// up to `block_timestamp`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TickObservation {
+    pub block_timestamp: u32,
+    pub tick_cumulative: i64,
+}
+
+/// Initialized observations of the ring buffer, from the oldest to the newest
+pub fn get_tick_observations(observation_state: &ObservationState) -> Vec<TickObservation> {
+    let observations = &observation_state.observations;
+    let newest_index = observation_state.observation_index as usize % observations.len();
+
+    (1..=observations.len())
+        .map(|offset| observations[(newest_index + offset) % observations.len()])
+        .filter(|observation| observation.block_timestamp != 0)
+        .map(|observation| TickObservation {
+            block_timestamp: observation.block_timestamp,
+            tick_cumulative: observation.tick_cumulative,
+        })
+        .collect()
+}
+
+/// Tick accumulated up to `timestamp`, interpolated between two observations or
+/// extrapolated from the newest one with the current tick, which holds since then
+pub fn get_tick_cumulative_at(
+    observations: &[TickObservation],
+    tick_current: i32,
+    timestamp: u32,
+) -> Result<i128> {
+    let newest = match (observations.first(), observations.last()) {
+        (Some(oldest), Some(newest)) if oldest.block_timestamp <= timestamp => newest,
+        _ => return err!(TokenizedVaultsErrorCode::InsufficientTwapObservations),
+    };
+
+    if timestamp >= newest.block_timestamp {
+        let elapsed = (timestamp - newest.block_timestamp) as i128;
+        return Ok(newest.tick_cumulative as i128 + tick_current as i128 * elapsed);
+    }
+
+    // First observation after the timestamp, the one before it exists since the oldest is not after
+    let next = observations.partition_point(|observation| observation.block_timestamp <= timestamp);
+    let (before, after) = (&observations[next - 1], &observations[next]);
+    let elapsed = (timestamp - before.block_timestamp) as i128;
+    let duration = (after.block_timestamp - before.block_timestamp) as i128;
+    let tick_delta = after.tick_cumulative as i128 - before.tick_cumulative as i128;
+
+    Ok(before.tick_cumulative as i128 + (tick_delta * elapsed).div_euclid(duration))
+}
+
+/// Time-weighted average tick over the `window` seconds ending at `now`, rounded down
+pub fn get_twap_tick(
+    observations: &[TickObservation],
+    tick_current: i32,
+    now: u32,
+    window: u32,
+) -> Result<i32> {
+    require!(
+        window > 0 && window <= now,
+        TokenizedVaultsErrorCode::InvalidTwapWindow
+    );
+
+    let tick_cumulative_end = get_tick_cumulative_at(observations, tick_current, now)?;
+    let tick_cumulative_start = get_tick_cumulative_at(observations, tick_current, now - window)?;
+    let twap_tick = (tick_cumulative_end - tick_cumulative_start).div_euclid(window as i128);
+
+    require!(
+        (tick_math::MIN_TICK as i128..=tick_math::MAX_TICK as i128).contains(&twap_tick),
+        TokenizedVaultsErrorCode::InvalidPrice
+    );
+    Ok(twap_tick as i32)
+}
+
+/// Time-weighted average price of a Raydium pool, usable as a secondary price
+/// wherever the spot `tick_current` / `sqrt_price_x64` pair of the pool is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Twap {
+    pub tick: i32,
+    pub sqrt_price_x64: u128,
+}
+
+impl Twap {
+    pub fn from_tick(tick: i32) -> Result<Self> {
+        Ok(Self {
+            tick,
+            sqrt_price_x64: tick_math::get_sqrt_price_at_tick(tick)?,
+        })
+    }
+
+    /// TWAP over the `window` seconds ending at `now`, from the observations of the pool
+    /// and its current tick
+    pub fn from_observation_state(
+        observation_state: &ObservationState,
+        tick_current: i32,
+        now: u32,
+        window: u32,
+    ) -> Result<Self> {
+        let observations = get_tick_observations(observation_state);
+        Self::from_tick(get_twap_tick(&observations, tick_current, now, window)?)
+    }
+
+    /// TWAP over the `window` seconds ending now of a pool, from its observation account
+    pub fn from_pool(
+        pool_state: &PoolState,
+        observation_state: &ObservationState,
+        window: u32,
+    ) -> Result<Self> {
+        let now = u32::try_from(Clock::get()?.unix_timestamp)
+            .map_err(|_| error!(TokenizedVaultsErrorCode::InvalidTwapWindow))?;
+        Self::from_observation_state(observation_state, pool_state.tick_current, now, window)
+    }
+
+    /// Price of token 0 in token 1 raw units, Q64.64
+    pub fn price_x64(&self) -> Result<U256> {
+        get_pool_price_x64(self.sqrt_price_x64)
+    }
+
+    /// Deviation of a spot sqrt price from the TWAP, in BPS scale (100% = 1_000_000)
+    pub fn deviation_of(&self, sqrt_price_x64: u128) -> Result<u32> {
+        get_price_deviation(get_pool_price_x64(sqrt_price_x64)?, self.price_x64()?)
+    }
+
+    /// Minimum output of a swap: the output at the TWAP less `max_slippage`,
+    /// in BPS scale (100% = 1_000_000)
+    pub fn swap_amount_out_min(
+        &self,
+        amount_in: u64,
+        zero_for_one: bool,
+        max_slippage: u32,
+    ) -> Result<u64> {
+        require!(
+            max_slippage <= MAX_PERCENTAGE,
+            TokenizedVaultsErrorCode::PercentageTooHigh
+        );
+
+        let amount_out =
+            get_swap_amount_out_at_price(self.sqrt_price_x64, amount_in, zero_for_one)?;
+        let amount_out_min =
+            amount_out as u128 * (MAX_PERCENTAGE - max_slippage) as u128 / MAX_PERCENTAGE as u128;
+
+        Ok(amount_out_min as u64)
+    }
+
+    /// Whether a position in [tick_lower_index, tick_upper_index) is out of range at the TWAP,
+    /// so that a rebalance is not triggered by a short-lived price move
+    pub fn is_out_of_range(&self, tick_lower_index: i32, tick_upper_index: i32) -> bool {
+        self.tick < tick_lower_index || self.tick >= tick_upper_index
+    }
+}
+
+#[cfg(test)]
+mod twap_tests {
+    use super::*;
+
+    /// Observations every `interval` seconds from `start`, with the tick held in between
+    fn observations(start: u32, interval: u32, ticks: &[i32]) -> Vec<TickObservation> {
+        let mut observation = TickObservation {
+            block_timestamp: start,
+            tick_cumulative: 0,
+        };
+        let mut observations = vec![observation];
+        for tick in ticks {
+            observation.block_timestamp += interval;
+            observation.tick_cumulative += *tick as i64 * interval as i64;
+            observations.push(observation);
+        }
+        observations
+    }
+
+    #[test]
+    fn constant_tick_averages_to_itself() {
+        let observations = observations(1_000, 15, &[100; 40]);
+
+        assert_eq!(get_twap_tick(&observations, 100, 1_600, 300).unwrap(), 100);
+        // Extrapolated with the current tick past the newest observation
+        assert_eq!(get_twap_tick(&observations, 100, 1_900, 300).unwrap(), 100);
+    }
+
+    #[test]
+    fn averages_ticks_over_the_window() {
+        // 100 for the first 300 seconds, then 200
+        let mut ticks = vec![100; 20];
+        ticks.extend([200; 20]);
+        let observations = observations(0, 15, &ticks);
+
+        assert_eq!(get_twap_tick(&observations, 200, 600, 600).unwrap(), 150);
+        assert_eq!(get_twap_tick(&observations, 200, 600, 300).unwrap(), 200);
+        // Window boundary between two observations
+        assert_eq!(get_twap_tick(&observations, 200, 600, 400).unwrap(), 175);
+    }
+
+    #[test]
+    fn current_tick_counts_since_the_newest_observation() {
+        let observations = observations(0, 15, &[100; 20]);
+
+        // The pool moved to -100 at 300 without a newer observation
+        assert_eq!(get_twap_tick(&observations, -100, 400, 200).unwrap(), 0);
+    }
+
+    #[test]
+    fn negative_ticks_round_down() {
+        let observations = observations(0, 10, &[-1, -2]);
+
+        // (-10 - 20) / 20 = -1.5
+        assert_eq!(get_twap_tick(&observations, -2, 20, 20).unwrap(), -2);
+    }
+
+    #[test]
+    fn rejects_a_window_not_covered_by_the_observations() {
+        let observations = observations(1_000, 15, &[100; 10]);
+
+        assert!(get_twap_tick(&observations, 100, 1_150, 151).is_err());
+        assert!(get_twap_tick(&[], 100, 1_150, 60).is_err());
+        assert!(get_twap_tick(&observations, 100, 1_150, 0).is_err());
+    }
+
+    #[test]
+    fn twap_price_matches_the_tick_price() {
+        let twap = Twap::from_tick(0).unwrap();
+        assert_eq!(twap.price_x64().unwrap(), U256::from(1u128 << 64));
+
+        let sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(200).unwrap();
+        let deviation = twap.deviation_of(sqrt_price_x64).unwrap();
+        assert!((20_100..20_300).contains(&deviation), "{}", deviation);
+        assert_eq!(twap.deviation_of(twap.sqrt_price_x64).unwrap(), 0);
+    }
+
+    #[test]
+    fn swap_amount_out_min_applies_the_slippage_to_the_twap_output() {
+        let twap = Twap::from_tick(0).unwrap();
+
+        let amount_out = twap.swap_amount_out_min(1_000_000, true, 0).unwrap();
+        assert!(
+            (999_999..=1_000_000).contains(&amount_out),
+            "{}",
+            amount_out
+        );
+
+        let amount_out_min = twap.swap_amount_out_min(1_000_000, false, 10_000).unwrap();
+        assert!(
+            (989_999..=990_000).contains(&amount_out_min),
+            "{}",
+            amount_out_min
+        );
+
+        assert!(twap
+            .swap_amount_out_min(1_000_000, true, MAX_PERCENTAGE + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn out_of_range_at_the_twap() {
+        let twap = Twap::from_tick(100).unwrap();
+
+        assert!(!twap.is_out_of_range(-100, 200));
+        assert!(!twap.is_out_of_range(100, 200));
+        assert!(twap.is_out_of_range(-100, 100));
+        assert!(twap.is_out_of_range(200, 300));
+    }
+}
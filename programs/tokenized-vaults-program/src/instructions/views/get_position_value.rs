@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::VaultStrategyView;
+
+/// Returned by `get_position_value`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PositionValue {
+    pub shares: u64,
+    /// Value in USDC of the shares
+    pub assets: u64,
+    /// Token 0 backing the shares, position and idle
+    pub amount_0: u64,
+    /// Token 1 backing the shares, position and idle
+    pub amount_1: u64,
+    /// Token 0 fees owed to the position, including the fees not collected from Raydium yet
+    pub fees_0: u64,
+    /// Token 1 fees owed to the position, including the fees not collected from Raydium yet
+    pub fees_1: u64,
+}
+
+impl<'info> VaultStrategyView<'info> {
+    pub fn get_position_value(&self) -> Result<PositionValue> {
+        let investor_strategy_position = self
+            .investor_strategy_position
+            .as_ref()
+            .ok_or(TokenizedVaultsErrorCode::MissingInvestorStrategyPosition)?;
+        let shares = investor_strategy_position.shares;
+        let total_shares = self.vault_strategy.total_shares;
+
+        let (token_0_price_info, token_1_price_info) = self.price_infos()?;
+        let holdings = self.holdings()?;
+        let nav = self.nav(&holdings, &token_0_price_info, &token_1_price_info)?;
+
+        let (assets, position_holdings) = if total_shares == 0 {
+            (0, Default::default())
+        } else {
+            (
+                investor_strategy_position.convert_shares_to_assets(shares, nav, total_shares)?,
+                holdings.share_of(shares, total_shares)?,
+            )
+        };
+
+        // fees are previewed as if the next collect happened now
+        let (uncollected_fees_0, uncollected_fees_1) = self.uncollected_investor_fees()?;
+        let (fee_growth_per_share_0_x64, fee_growth_per_share_1_x64) = self
+            .vault_strategy
+            .fee_growth_after(uncollected_fees_0, uncollected_fees_1)?;
+        let (fees_0, fees_1) = investor_strategy_position
+            .pending_fees(fee_growth_per_share_0_x64, fee_growth_per_share_1_x64)?;
+
+        Ok(PositionValue {
+            shares,
+            assets,
+            amount_0: position_holdings.amount_0,
+            amount_1: position_holdings.amount_1,
+            fees_0,
+            fees_1,
+        })
+    }
+}
+
+pub fn handler(ctx: Context<VaultStrategyView>, _strategy_id: u8) -> Result<PositionValue> {
+    ctx.accounts.get_position_value()
+}
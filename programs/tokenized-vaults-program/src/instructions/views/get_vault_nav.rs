@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{get_share_price, VaultStrategyView};
+
+/// Returned by `get_vault_nav`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VaultNav {
+    /// Mark-to-market value of the strategy in USDC
+    pub nav: u64,
+    pub total_shares: u64,
+    /// Value in USDC of one whole share
+    pub share_price: u64,
+    /// Token 0 backing the shares, position and idle
+    pub amount_0: u64,
+    /// Token 1 backing the shares, position and idle
+    pub amount_1: u64,
+}
+
+impl<'info> VaultStrategyView<'info> {
+    pub fn get_vault_nav(&self) -> Result<VaultNav> {
+        let (token_0_price_info, token_1_price_info) = self.price_infos()?;
+        let holdings = self.holdings()?;
+        let nav = self.nav(&holdings, &token_0_price_info, &token_1_price_info)?;
+        let total_shares = self.vault_strategy.total_shares;
+
+        Ok(VaultNav {
+            nav,
+            total_shares,
+            share_price: get_share_price(nav, total_shares)?,
+            amount_0: holdings.amount_0,
+            amount_1: holdings.amount_1,
+        })
+    }
+}
+
+pub fn handler(ctx: Context<VaultStrategyView>, _strategy_id: u8) -> Result<VaultNav> {
+    ctx.accounts.get_vault_nav()
+}
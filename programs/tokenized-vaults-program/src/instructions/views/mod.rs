@@ -0,0 +1,16 @@
+#![allow(unused_imports, ambiguous_glob_reexports)]
+
+pub mod vault_strategy_view;
+pub use vault_strategy_view::*;
+
+pub mod get_vault_nav;
+pub use get_vault_nav::*;
+
+pub mod preview_deposit;
+pub use preview_deposit::*;
+
+pub mod preview_redeem;
+pub use preview_redeem::*;
+
+pub mod get_position_value;
+pub use get_position_value::*;
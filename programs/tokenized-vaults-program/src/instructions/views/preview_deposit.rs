@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{InvestorStrategyPosition, VaultStrategyHoldings, VaultStrategyView};
+
+/// Returned by `preview_deposit`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DepositPreview {
+    /// Value in USDC of the deposited amounts
+    pub assets: u64,
+    /// Shares minted for the deposit
+    pub shares: u64,
+}
+
+impl<'info> VaultStrategyView<'info> {
+    /// Shares minted if `amount_0` and `amount_1` were fully added as liquidity now.
+    /// A deposit refunds what does not fit the position ratio, so this is an upper bound
+    pub fn preview_deposit(&self, amount_0: u64, amount_1: u64) -> Result<DepositPreview> {
+        let (token_0_price_info, token_1_price_info) = self.price_infos()?;
+        let nav = self.nav(&self.holdings()?, &token_0_price_info, &token_1_price_info)?;
+
+        let assets = VaultStrategyHoldings { amount_0, amount_1 }.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            &token_0_price_info,
+            &token_1_price_info,
+        )?;
+        let shares = InvestorStrategyPosition::default().convert_assets_to_shares(
+            assets,
+            nav,
+            self.vault_strategy.total_shares,
+        )?;
+
+        Ok(DepositPreview { assets, shares })
+    }
+}
+
+pub fn handler(
+    ctx: Context<VaultStrategyView>,
+    _strategy_id: u8,
+    amount_0: u64,
+    amount_1: u64,
+) -> Result<DepositPreview> {
+    ctx.accounts.preview_deposit(amount_0, amount_1)
+}
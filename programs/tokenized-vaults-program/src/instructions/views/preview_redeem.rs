@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::{
+    get_liquidity_for_shares, get_position_amounts, InvestorStrategyPosition, VaultStrategyView,
+};
+
+/// Returned by `preview_redeem`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RedeemPreview {
+    /// Value in USDC of the shares
+    pub assets: u64,
+    /// Raydium liquidity removed for the shares
+    pub liquidity: u128,
+    /// Token 0 returned by the liquidity removal and from the rebalance escrow
+    pub amount_0: u64,
+    /// Token 1 returned by the liquidity removal and from the rebalance escrow
+    pub amount_1: u64,
+}
+
+impl<'info> VaultStrategyView<'info> {
+    /// Amounts returned by `redeem_raydium_vault_strategy` for `shares` at the current
+    /// pool price, before fees owed and any swap to USDC
+    pub fn preview_redeem(&self, shares: u64) -> Result<RedeemPreview> {
+        let total_shares = self.vault_strategy.total_shares;
+
+        let liquidity = get_liquidity_for_shares(
+            self.raydium_personal_position.liquidity,
+            shares,
+            total_shares,
+        )?;
+        require!(
+            liquidity > 0,
+            TokenizedVaultsErrorCode::LiquidityCalculatedToZero
+        );
+
+        let (position_amount_0, position_amount_1) = {
+            let pool_state = self.raydium_pool_state.load()?;
+            get_position_amounts(
+                pool_state.tick_current,
+                pool_state.sqrt_price_x64,
+                self.raydium_personal_position.tick_lower_index,
+                self.raydium_personal_position.tick_upper_index,
+                liquidity,
+            )?
+        };
+
+        let mut amounts = self.idle_amounts()?.share_of(shares, total_shares)?;
+        amounts.add(position_amount_0, position_amount_1)?;
+
+        let (token_0_price_info, token_1_price_info) = self.price_infos()?;
+        let nav = self.nav(&self.holdings()?, &token_0_price_info, &token_1_price_info)?;
+        let assets = InvestorStrategyPosition::default().convert_shares_to_assets(
+            shares,
+            nav,
+            total_shares,
+        )?;
+
+        Ok(RedeemPreview {
+            assets,
+            liquidity,
+            amount_0: amounts.amount_0,
+            amount_1: amounts.amount_1,
+        })
+    }
+}
+
+pub fn handler(
+    ctx: Context<VaultStrategyView>,
+    _strategy_id: u8,
+    shares: u64,
+) -> Result<RedeemPreview> {
+    ctx.accounts.preview_redeem(shares)
+}
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::states::{
+    ObservationState, PersonalPositionState, PoolState, TickArrayState,
+};
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::oracle::TokenOracle;
+use crate::utils::{split_fees_amount, TokenPriceInfo};
+use crate::{
+    get_position_owed_fees, get_price_infos, get_vault_strategy_holdings, InvestorStrategyPosition,
+    OracleFeed, ProtocolConfig, VaultStrategy, VaultStrategyConfig, VaultStrategyHoldings,
+};
+
+/// Read-only accounts shared by the view instructions.
+/// Nothing is written, the views can be simulated or called by CPI
+/// and return their result with `set_return_data`
+#[derive(Accounts)]
+#[instruction(strategy_id: u8)]
+pub struct VaultStrategyView<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED.as_bytes()],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategyConfig::SEED.as_bytes(),
+            vault_strategy_config.creator.as_ref(),
+            vault_strategy_config.name.as_ref()
+        ],
+        bump = vault_strategy_config.bump,
+    )]
+    pub vault_strategy_config: Box<Account<'info, VaultStrategyConfig>>,
+
+    #[account(
+        seeds = [
+            VaultStrategy::SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            raydium_vault_0_mint.key().as_ref(),
+            raydium_vault_1_mint.key().as_ref(),
+            strategy_id.to_le_bytes().as_ref(),
+        ],
+        bump = vault_strategy.bump
+    )]
+    pub vault_strategy: Box<Account<'info, VaultStrategy>>,
+
+    /// The investor position to value, only required by `get_position_value`
+    #[account(
+        seeds = [
+            InvestorStrategyPosition::SEED.as_bytes(),
+            vault_strategy.key().as_ref(),
+            investor_strategy_position.authority.as_ref(),
+        ],
+        bump = investor_strategy_position.bump
+    )]
+    pub investor_strategy_position: Option<Box<Account<'info, InvestorStrategyPosition>>>,
+
+    /// CHECK: The rebalance escrow account for the token 0, read as empty until initialized
+    #[account(
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_0_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_0_rebalance_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: The rebalance escrow account for the token 1, read as empty until initialized
+    #[account(
+        seeds = [
+            VaultStrategyConfig::VAULT_REBALANCE_1_ESCROW_SEED.as_bytes(),
+            vault_strategy_config.key().as_ref(),
+            vault_strategy.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_strategy_cfg_mint_1_rebalance_escrow: UncheckedAccount<'info>,
+
+    /* DEX Raydium */
+    /// CHECK: Represents the state of the pool Token 0 / Token 1
+    pub raydium_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The raydium position of the vault strategy
+    #[account(
+        constraint = raydium_personal_position.nft_mint == vault_strategy.dex_nft_mint
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition,
+        constraint = raydium_personal_position.pool_id == raydium_pool_state.key()
+            @ TokenizedVaultsErrorCode::InvalidRaydiumPosition
+    )]
+    pub raydium_personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: Tick array of the raydium position's lower tick
+    pub raydium_tick_array_lower: AccountLoader<'info, TickArrayState>,
+
+    /// CHECK: Tick array of the raydium position's upper tick
+    pub raydium_tick_array_upper: AccountLoader<'info, TickArrayState>,
+
+    /// The mint of token 0
+    #[account(address = vault_strategy.mint_0 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token 1
+    #[account(address = vault_strategy.mint_1 @ TokenizedVaultsErrorCode::InvalidMint)]
+    pub raydium_vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /* Pyth Price Feeds */
+    /// Oracle registry entry of token 0
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_0_mint.key().as_ref()],
+        bump = token_0_oracle_feed.bump
+    )]
+    pub token_0_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Oracle registry entry of token 1
+    #[account(
+        seeds = [OracleFeed::SEED.as_bytes(), raydium_vault_1_mint.key().as_ref()],
+        bump = token_1_oracle_feed.bump
+    )]
+    pub token_1_oracle_feed: Box<Account<'info, OracleFeed>>,
+
+    /// Pyth price update account for token 0, feed `token_0_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_0_oracle_feed`
+    pub pyth_token_0_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// Pyth price update account for token 1, feed `token_1_oracle_feed.feed_id`,
+    /// only read when Pyth is a source of `token_1_oracle_feed`
+    pub pyth_token_1_price_update: Option<Box<Account<'info, PriceUpdateV2>>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 0, only read when it is a source of
+    /// `token_0_oracle_feed` and checked against its `switchboard_feed`
+    pub token_0_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Switchboard On-Demand feed of token 1, only read when it is a source of
+    /// `token_1_oracle_feed` and checked against its `switchboard_feed`
+    pub token_1_switchboard_feed: Option<UncheckedAccount<'info>>,
+
+    /// Raydium pool of the token 0 TWAP fallback, `token_0_oracle_feed.twap_pool`
+    pub token_0_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 0 TWAP pool
+    pub token_0_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+
+    /// Raydium pool of the token 1 TWAP fallback, `token_1_oracle_feed.twap_pool`
+    pub token_1_twap_pool_state: Option<AccountLoader<'info, PoolState>>,
+
+    /// Observations of the token 1 TWAP pool
+    pub token_1_twap_observation_state: Option<AccountLoader<'info, ObservationState>>,
+}
+
+impl<'info> VaultStrategyView<'info> {
+    pub fn price_infos(&self) -> Result<(TokenPriceInfo, TokenPriceInfo)> {
+        get_price_infos(
+            &TokenOracle::new(
+                &self.token_0_oracle_feed,
+                &self.pyth_token_0_price_update,
+                &self.token_0_switchboard_feed,
+                &self.token_0_twap_pool_state,
+                &self.token_0_twap_observation_state,
+            ),
+            &TokenOracle::new(
+                &self.token_1_oracle_feed,
+                &self.pyth_token_1_price_update,
+                &self.token_1_switchboard_feed,
+                &self.token_1_twap_pool_state,
+                &self.token_1_twap_observation_state,
+            ),
+            &self.protocol_config.price_bounds(),
+        )
+    }
+
+    /// Token amounts backing the shares, valued the same way as on deposit
+    pub fn holdings(&self) -> Result<VaultStrategyHoldings> {
+        let idle_amounts = self.idle_amounts()?;
+        let pool_state = self.raydium_pool_state.load()?;
+
+        get_vault_strategy_holdings(
+            &pool_state,
+            &self.raydium_personal_position,
+            (idle_amounts.amount_0, idle_amounts.amount_1),
+        )
+    }
+
+    /// Amounts of the rebalance escrows waiting to be compounded
+    pub fn idle_amounts(&self) -> Result<VaultStrategyHoldings> {
+        Ok(VaultStrategyHoldings {
+            amount_0: token_account_amount(&self.vault_strategy_cfg_mint_0_rebalance_escrow)?,
+            amount_1: token_account_amount(&self.vault_strategy_cfg_mint_1_rebalance_escrow)?,
+        })
+    }
+
+    /// Mark-to-market value of the strategy in USDC
+    pub fn nav(
+        &self,
+        holdings: &VaultStrategyHoldings,
+        token_0_price_info: &TokenPriceInfo,
+        token_1_price_info: &TokenPriceInfo,
+    ) -> Result<u64> {
+        holdings.to_usd(
+            self.raydium_vault_0_mint.decimals,
+            self.raydium_vault_1_mint.decimals,
+            token_0_price_info,
+            token_1_price_info,
+        )
+    }
+
+    /// Investor part of the fees earned by the Raydium position and not collected yet,
+    /// what the next collect would spread over the shares
+    pub fn uncollected_investor_fees(&self) -> Result<(u64, u64)> {
+        let (strategy_fees_owed_0, strategy_fees_owed_1) = get_position_owed_fees(
+            &self.raydium_pool_state,
+            &self.raydium_tick_array_lower,
+            &self.raydium_tick_array_upper,
+            &self.raydium_personal_position,
+        )?;
+
+        let (investor_fees_owed_0, _, _) = split_fees_amount(
+            strategy_fees_owed_0,
+            self.protocol_config.protocol_fees,
+            self.vault_strategy_config.performance_fee,
+        )?;
+        let (investor_fees_owed_1, _, _) = split_fees_amount(
+            strategy_fees_owed_1,
+            self.protocol_config.protocol_fees,
+            self.vault_strategy_config.performance_fee,
+        )?;
+        Ok((investor_fees_owed_0, investor_fees_owed_1))
+    }
+}
+
+/// Balance of a token account that may not be initialized yet
+fn token_account_amount(account: &AccountInfo) -> Result<u64> {
+    if account.data_is_empty() {
+        return Ok(0);
+    }
+
+    let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(token_account.amount)
+}
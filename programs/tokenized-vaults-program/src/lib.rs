@@ -4,6 +4,7 @@ use anchor_lang::prelude::*;
 
 pub mod error;
 pub mod instructions;
+pub mod oracle;
 pub mod state;
 pub mod utils;
 pub use instructions::*;
@@ -41,6 +42,26 @@ pub mod tokenized_vaults_program {
         unpause_protocol::handler(ctx)
     }
 
+    #[instruction(discriminator = DISC_CLAIM_PROTOCOL_FEES_IX)]
+    pub fn claim_protocol_fees(ctx: Context<ClaimProtocolFees>, amount: u64) -> Result<()> {
+        claim_protocol_fees::handler(ctx, amount)
+    }
+
+    #[instruction(discriminator = DISC_PROPOSE_ADMIN_IX)]
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        propose_admin::handler(ctx, new_admin)
+    }
+
+    #[instruction(discriminator = DISC_ACCEPT_ADMIN_IX)]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    #[instruction(discriminator = DISC_CANCEL_ADMIN_PROPOSAL_IX)]
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        cancel_admin_proposal::handler(ctx)
+    }
+
     #[instruction(discriminator = DISC_INIT_VAULT_STRATEGY_CONFIG_IX)]
     pub fn init_vault_strategy_config(
         ctx: Context<InitVaultStrategyConfig>,
@@ -77,9 +98,10 @@ pub mod tokenized_vaults_program {
         tick_upper_index: i32,
         tick_array_lower_start_index: i32,
         tick_array_upper_start_index: i32,
-        token_0_feed_id: String,
-        token_1_feed_id: String,
         look_up_table: Pubkey,
+        share_name: String,
+        share_symbol: String,
+        share_uri: String,
     ) -> Result<()>
     where
         'c: 'info,
@@ -94,9 +116,10 @@ pub mod tokenized_vaults_program {
             tick_upper_index,
             tick_array_lower_start_index,
             tick_array_upper_start_index,
-            token_0_feed_id,
-            token_1_feed_id,
             look_up_table,
+            share_name,
+            share_symbol,
+            share_uri,
         )
     }
 
@@ -137,11 +160,18 @@ pub mod tokenized_vaults_program {
     pub fn add_liquidity_raydium_vault_strategy<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, AddLiquidityRaydiumVaultStrategy<'info>>,
         strategy_id: u8,
+        refund_0_usdc_amount_out_min: u64,
+        refund_1_usdc_amount_out_min: u64,
     ) -> Result<()>
     where
         'c: 'info,
     {
-        add_liquidity_raydium_vault_strategy::handler(ctx, strategy_id)
+        add_liquidity_raydium_vault_strategy::handler(
+            ctx,
+            strategy_id,
+            refund_0_usdc_amount_out_min,
+            refund_1_usdc_amount_out_min,
+        )
     }
 
     #[instruction(discriminator = DISC_REMOVE_LIQUIDITY_RAYDIUM_VAULT_STRATEGY_IX)]
@@ -163,4 +193,321 @@ pub mod tokenized_vaults_program {
             amount_1_min,
         )
     }
+
+    #[instruction(discriminator = DISC_CLOSE_VAULT_IX)]
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        close_vault::handler(ctx)
+    }
+
+    #[instruction(discriminator = DISC_CLOSE_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn close_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CloseRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        close_raydium_vault_strategy::handler(ctx, strategy_id, amount_0_min, amount_1_min)
+    }
+
+    #[instruction(discriminator = DISC_REDEEM_CLOSED_VAULT_STRATEGY_IX)]
+    pub fn redeem_closed_vault_strategy(
+        ctx: Context<RedeemClosedVaultStrategy>,
+        strategy_id: u8,
+    ) -> Result<()> {
+        redeem_closed_vault_strategy::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_CLOSE_RAYDIUM_POSITION_IX)]
+    pub fn close_raydium_position(
+        ctx: Context<CloseRaydiumPosition>,
+        strategy_id: u8,
+    ) -> Result<()> {
+        close_raydium_position::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_CLAIM_PERFORMANCE_FEES_IX)]
+    pub fn claim_performance_fees(
+        ctx: Context<ClaimPerformanceFees>,
+        amount_0: u64,
+        amount_1: u64,
+    ) -> Result<()> {
+        claim_performance_fees::handler(ctx, amount_0, amount_1)
+    }
+
+    #[instruction(discriminator = DISC_SET_KEEPER_IX)]
+    pub fn set_keeper(ctx: Context<SetKeeper>, keeper: Pubkey) -> Result<()> {
+        set_keeper::handler(ctx, keeper)
+    }
+
+    #[instruction(discriminator = DISC_REBALANCE_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn rebalance_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RebalanceRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        swap_amount_in: u64,
+        swap_amount_out_min: u64,
+        swap_zero_for_one: bool,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        rebalance_raydium_vault_strategy::handler(
+            ctx,
+            strategy_id,
+            tick_lower_index,
+            tick_upper_index,
+            tick_array_lower_start_index,
+            tick_array_upper_start_index,
+            amount_0_min,
+            amount_1_min,
+            swap_amount_in,
+            swap_amount_out_min,
+            swap_zero_for_one,
+        )
+    }
+
+    #[instruction(discriminator = DISC_COMPOUND_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn compound_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CompoundRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        compound_raydium_vault_strategy::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_INIT_VAULT_STRATEGY_SHARE_MINT_IX)]
+    pub fn init_vault_strategy_share_mint(
+        ctx: Context<InitVaultStrategyShareMint>,
+        strategy_id: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        init_vault_strategy_share_mint::handler(ctx, strategy_id, name, symbol, uri)
+    }
+
+    #[instruction(discriminator = DISC_DEPOSIT_RAYDIUM_VAULT_IX)]
+    pub fn deposit_raydium_vault<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DepositRaydiumVault<'info>>,
+        strategy_id: u8,
+        amount: u64,
+        token_0_amount_out_min: u64,
+        token_1_amount_out_min: u64,
+        min_shares_out: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        deposit_raydium_vault::handler(
+            ctx,
+            strategy_id,
+            amount,
+            token_0_amount_out_min,
+            token_1_amount_out_min,
+            min_shares_out,
+        )
+    }
+
+    #[instruction(discriminator = DISC_WITHDRAW_TO_USDC_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn withdraw_to_usdc_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, WithdrawToUsdcRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+        percentage: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        usdc_amount_out_min: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        withdraw_to_usdc_raydium_vault_strategy::handler(
+            ctx,
+            strategy_id,
+            percentage,
+            amount_0_min,
+            amount_1_min,
+            usdc_amount_out_min,
+        )
+    }
+
+    #[instruction(discriminator = DISC_REDEEM_VAULT_IX)]
+    pub fn redeem_vault<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RedeemVault<'info>>,
+        percentage: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        redeem_vault::handler(ctx, percentage, amount_0_min, amount_1_min)
+    }
+
+    #[instruction(discriminator = DISC_CANCEL_INVEST_RESERVE_IX)]
+    pub fn cancel_invest_reserve(ctx: Context<CancelInvestReserve>) -> Result<()> {
+        cancel_invest_reserve::handler(ctx)
+    }
+
+    #[instruction(discriminator = DISC_CLAIM_INVESTOR_FEES_IX)]
+    pub fn claim_investor_fees<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimInvestorFees<'info>>,
+        strategy_id: u8,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        claim_investor_fees::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_REDEEM_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn redeem_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RemoveLiquidityRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+        shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        redeem_raydium_vault_strategy::handler(ctx, strategy_id, shares, amount_0_min, amount_1_min)
+    }
+
+    #[instruction(discriminator = DISC_WITHDRAW_RAYDIUM_VAULT_STRATEGY_IX)]
+    pub fn withdraw_raydium_vault_strategy<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RemoveLiquidityRaydiumVaultStrategy<'info>>,
+        strategy_id: u8,
+        assets: u64,
+        max_shares: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        withdraw_raydium_vault_strategy::handler(
+            ctx,
+            strategy_id,
+            assets,
+            max_shares,
+            amount_0_min,
+            amount_1_min,
+        )
+    }
+
+    #[instruction(discriminator = DISC_GET_VAULT_NAV_IX)]
+    pub fn get_vault_nav(ctx: Context<VaultStrategyView>, strategy_id: u8) -> Result<VaultNav> {
+        get_vault_nav::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_PREVIEW_DEPOSIT_IX)]
+    pub fn preview_deposit(
+        ctx: Context<VaultStrategyView>,
+        strategy_id: u8,
+        amount_0: u64,
+        amount_1: u64,
+    ) -> Result<DepositPreview> {
+        preview_deposit::handler(ctx, strategy_id, amount_0, amount_1)
+    }
+
+    #[instruction(discriminator = DISC_PREVIEW_REDEEM_IX)]
+    pub fn preview_redeem(
+        ctx: Context<VaultStrategyView>,
+        strategy_id: u8,
+        shares: u64,
+    ) -> Result<RedeemPreview> {
+        preview_redeem::handler(ctx, strategy_id, shares)
+    }
+
+    #[instruction(discriminator = DISC_GET_POSITION_VALUE_IX)]
+    pub fn get_position_value(
+        ctx: Context<VaultStrategyView>,
+        strategy_id: u8,
+    ) -> Result<PositionValue> {
+        get_position_value::handler(ctx, strategy_id)
+    }
+
+    #[instruction(discriminator = DISC_DEPOSIT_TOKENS_RAYDIUM_VAULT_IX)]
+    pub fn deposit_tokens_raydium_vault<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DepositTokensRaydiumVault<'info>>,
+        strategy_id: u8,
+        amount_0: u64,
+        amount_1: u64,
+        swap_amount_out_min: u64,
+        min_shares_out: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        deposit_tokens_raydium_vault::handler(
+            ctx,
+            strategy_id,
+            amount_0,
+            amount_1,
+            swap_amount_out_min,
+            min_shares_out,
+        )
+    }
+
+    #[instruction(discriminator = DISC_SET_PRICE_BOUNDS_IX)]
+    pub fn set_price_bounds(
+        ctx: Context<SetPriceBounds>,
+        max_price_age: u64,
+        max_price_conf: u32,
+    ) -> Result<()> {
+        set_price_bounds::handler(ctx, max_price_age, max_price_conf)
+    }
+
+    #[instruction(discriminator = DISC_SET_ORACLE_FEED_IX)]
+    pub fn set_oracle_feed(
+        ctx: Context<SetOracleFeed>,
+        feed_id: [u8; 32],
+        exponent: i32,
+        max_price_age: u64,
+        max_price_conf: u32,
+    ) -> Result<()> {
+        set_oracle_feed::handler(ctx, feed_id, exponent, max_price_age, max_price_conf)
+    }
+
+    #[instruction(discriminator = DISC_SET_MAX_PRICE_DEVIATION_IX)]
+    pub fn set_max_price_deviation(
+        ctx: Context<SetMaxPriceDeviation>,
+        max_price_deviation: u32,
+    ) -> Result<()> {
+        set_max_price_deviation::handler(ctx, max_price_deviation)
+    }
+
+    #[instruction(discriminator = DISC_SET_ORACLE_SOURCES_IX)]
+    pub fn set_oracle_sources(
+        ctx: Context<SetOracleSources>,
+        sources: Vec<OracleSource>,
+        switchboard_feed: Pubkey,
+        twap_pool: Pubkey,
+        twap_window: u32,
+    ) -> Result<()> {
+        set_oracle_sources::handler(ctx, sources, switchboard_feed, twap_pool, twap_window)
+    }
+
+    #[instruction(discriminator = DISC_MIGRATE_ACCOUNT_IX)]
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        migrate_account::handler(ctx)
+    }
+
+    #[instruction(discriminator = DISC_MIGRATE_INVESTOR_SHARES_IX)]
+    pub fn migrate_investor_shares(
+        ctx: Context<MigrateInvestorShares>,
+        strategy_id: u8,
+    ) -> Result<()> {
+        migrate_investor_shares::handler(ctx, strategy_id)
+    }
 }
@@ -0,0 +1,633 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use raydium_clmm_cpi::states::{ObservationState, PoolState};
+use switchboard_on_demand::PullFeedAccountData;
+
+use crate::error::TokenizedVaultsErrorCode;
+use crate::libraries::fixed_point_64;
+use crate::utils::{PriceBounds, TokenPriceInfo};
+use crate::{get_tick_observations, get_twap_tick, OracleFeed, OracleSource, Twap, U256};
+
+/// Switchboard On-Demand results are fixed point numbers with 18 decimals
+pub const SWITCHBOARD_DECIMALS: i32 = 18;
+
+/// Accounts the price of a mint can be read from, see `OracleFeed::price_info`.
+/// Each one is only passed when the mint uses its source
+pub struct OracleAccounts<'a, 'info> {
+    pub pyth_price_update: Option<&'a Account<'info, PriceUpdateV2>>,
+    pub switchboard_feed: Option<&'a AccountInfo<'info>>,
+    pub twap_pool_state: Option<&'a AccountLoader<'info, PoolState>>,
+    pub twap_observation_state: Option<&'a AccountLoader<'info, ObservationState>>,
+}
+
+impl<'a, 'info> OracleAccounts<'a, 'info> {
+    pub fn new(
+        pyth_price_update: &'a Option<Box<Account<'info, PriceUpdateV2>>>,
+        switchboard_feed: &'a Option<UncheckedAccount<'info>>,
+        twap_pool_state: &'a Option<AccountLoader<'info, PoolState>>,
+        twap_observation_state: &'a Option<AccountLoader<'info, ObservationState>>,
+    ) -> Self {
+        Self {
+            pyth_price_update: pyth_price_update.as_deref(),
+            switchboard_feed: switchboard_feed.as_deref(),
+            twap_pool_state: twap_pool_state.as_ref(),
+            twap_observation_state: twap_observation_state.as_ref(),
+        }
+    }
+}
+
+/// Oracle registry entry of a token with the accounts its price is read from
+pub struct TokenOracle<'a, 'info> {
+    pub oracle_feed: &'a OracleFeed,
+    pub accounts: OracleAccounts<'a, 'info>,
+}
+
+impl<'a, 'info> TokenOracle<'a, 'info> {
+    pub fn new(
+        oracle_feed: &'a OracleFeed,
+        pyth_price_update: &'a Option<Box<Account<'info, PriceUpdateV2>>>,
+        switchboard_feed: &'a Option<UncheckedAccount<'info>>,
+        twap_pool_state: &'a Option<AccountLoader<'info, PoolState>>,
+        twap_observation_state: &'a Option<AccountLoader<'info, ObservationState>>,
+    ) -> Self {
+        Self {
+            oracle_feed,
+            accounts: OracleAccounts::new(
+                pyth_price_update,
+                switchboard_feed,
+                twap_pool_state,
+                twap_observation_state,
+            ),
+        }
+    }
+
+    pub fn price_info(&self, protocol_bounds: &PriceBounds) -> Result<TokenPriceInfo> {
+        self.oracle_feed.price_info(&self.accounts, protocol_bounds)
+    }
+}
+
+/// Price of a Switchboard On-Demand pull feed, the caller checks the bounds
+pub fn get_price_from_switchboard_feed(
+    switchboard_feed: &AccountInfo,
+    exponent: i32,
+) -> Result<TokenPriceInfo> {
+    let pull_feed = PullFeedAccountData::parse(switchboard_feed.try_borrow_data()?)
+        .map_err(|_| error!(TokenizedVaultsErrorCode::InvalidSwitchboardFeed))?;
+
+    get_price_from_switchboard_result(
+        pull_feed.result.value,
+        pull_feed.result.std_dev,
+        pull_feed.last_update_timestamp,
+        exponent,
+    )
+}
+
+/// Rescales a Switchboard result and its standard deviation from 18 decimals
+/// to the exponent of the oracle registry
+pub fn get_price_from_switchboard_result(
+    value: i128,
+    std_dev: i128,
+    last_update_timestamp: i64,
+    exponent: i32,
+) -> Result<TokenPriceInfo> {
+    require!(
+        (-SWITCHBOARD_DECIMALS..=0).contains(&exponent),
+        TokenizedVaultsErrorCode::InvalidOracleFeed
+    );
+    let scale = 10i128.pow((SWITCHBOARD_DECIMALS + exponent) as u32);
+
+    Ok(TokenPriceInfo {
+        price: i64::try_from(value / scale)
+            .map_err(|_| error!(TokenizedVaultsErrorCode::InvalidPrice))?,
+        expo: exponent,
+        conf: u64::try_from(std_dev.unsigned_abs() / scale as u128)
+            .map_err(|_| error!(TokenizedVaultsErrorCode::InvalidPrice))?,
+        publish_time: last_update_timestamp,
+        source: OracleSource::SwitchboardOnDemand,
+    })
+}
+
+/// USD price of `mint` from the TWAP of a Raydium pool pairing it with a USD stablecoin,
+/// valued at 1 USD. The price is as old as the newest observation of the pool,
+/// the tick extrapolated since then is not observed
+pub fn get_price_from_raydium_twap(
+    pool_state: &PoolState,
+    observation_state: &ObservationState,
+    mint: &Pubkey,
+    window: u32,
+    exponent: i32,
+    now: i64,
+) -> Result<TokenPriceInfo> {
+    let now_u32 = u32::try_from(now).map_err(|_| error!(TokenizedVaultsErrorCode::InvalidPrice))?;
+    let observations = get_tick_observations(observation_state);
+    let newest_observation = observations
+        .last()
+        .ok_or(TokenizedVaultsErrorCode::InsufficientTwapObservations)?;
+    let twap = Twap::from_tick(get_twap_tick(
+        &observations,
+        pool_state.tick_current,
+        now_u32,
+        window,
+    )?)?;
+
+    let price = if *mint == pool_state.token_mint_0 {
+        get_usd_price_from_pool_price_x64(
+            twap.price_x64()?,
+            pool_state.mint_decimals_0,
+            pool_state.mint_decimals_1,
+            exponent,
+        )?
+    } else if *mint == pool_state.token_mint_1 {
+        // Price of token 1 in token 0 raw units
+        let price_x64 = (U256::one() << (2 * fixed_point_64::RESOLUTION)) / twap.price_x64()?;
+        get_usd_price_from_pool_price_x64(
+            price_x64,
+            pool_state.mint_decimals_1,
+            pool_state.mint_decimals_0,
+            exponent,
+        )?
+    } else {
+        return err!(TokenizedVaultsErrorCode::InvalidOracleAccount);
+    };
+
+    Ok(TokenPriceInfo {
+        price,
+        expo: exponent,
+        conf: 0,
+        publish_time: newest_observation.block_timestamp as i64,
+        source: OracleSource::RaydiumTwap,
+    })
+}
+
+/// USD price with `exponent` of a token priced in raw units of a 1 USD stablecoin, Q64.64:
+/// price_x64 * 10^(decimals - quote_decimals) / 10^exponent
+pub fn get_usd_price_from_pool_price_x64(
+    price_x64: U256,
+    decimals: u8,
+    quote_decimals: u8,
+    exponent: i32,
+) -> Result<i64> {
+    let scale_exponent = decimals as i64 - quote_decimals as i64 - exponent as i64;
+    let scale = U256::from(10u64)
+        .checked_pow(U256::from(scale_exponent.unsigned_abs()))
+        .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+    let price = if scale_exponent >= 0 {
+        price_x64
+            .checked_mul(scale)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            >> fixed_point_64::RESOLUTION
+    } else {
+        (price_x64 / scale) >> fixed_point_64::RESOLUTION
+    };
+    require!(
+        price <= U256::from(i64::MAX as u64),
+        TokenizedVaultsErrorCode::MathOverflow
+    );
+
+    Ok(price.as_u64() as i64)
+}
+
+#[cfg(test)]
+mod oracle_tests {
+    use super::*;
+    use crate::tick_math;
+    use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, VerificationLevel};
+    use raydium_clmm_cpi::states::Observation;
+    use std::mem::{offset_of, size_of};
+
+    const NOW: i64 = 1_700_000_000;
+    const FEED_ID: [u8; 32] = [7u8; 32];
+
+    /// Owner, data and lamports backing a crafted account
+    struct Fixture {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl Fixture {
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 1_000_000_000,
+                data,
+            }
+        }
+
+        fn account_info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                false,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+
+        fn write(&mut self, offset: usize, bytes: &[u8]) {
+            self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        }
+    }
+
+    fn pyth_fixture(price: i64, publish_time: i64) -> Fixture {
+        let price_update = PriceUpdateV2 {
+            write_authority: Pubkey::default(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: FEED_ID,
+                price,
+                conf: 0,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time,
+                ema_price: price,
+                ema_conf: 0,
+            },
+            posted_slot: 0,
+        };
+        let mut data = Vec::new();
+        price_update.try_serialize(&mut data).unwrap();
+        Fixture::new(pyth_solana_receiver_sdk::ID, data)
+    }
+
+    /// Zero-copy Raydium account of type `T` with its discriminator
+    fn raydium_fixture<T: Discriminator>(len: usize) -> Fixture {
+        let mut data = vec![0u8; T::DISCRIMINATOR.len() + len];
+        data[..T::DISCRIMINATOR.len()].copy_from_slice(T::DISCRIMINATOR);
+        Fixture::new(raydium_clmm_cpi::ID, data)
+    }
+
+    /// Pool of `mint_0` (6 decimals) and `mint_1` (6 decimals) at `tick`
+    fn pool_fixture(mint_0: Pubkey, mint_1: Pubkey, tick: i32, observation_key: Pubkey) -> Fixture {
+        let mut pool = raydium_fixture::<PoolState>(size_of::<PoolState>());
+        let base = PoolState::DISCRIMINATOR.len();
+        pool.write(base + offset_of!(PoolState, token_mint_0), mint_0.as_ref());
+        pool.write(base + offset_of!(PoolState, token_mint_1), mint_1.as_ref());
+        pool.write(base + offset_of!(PoolState, mint_decimals_0), &[6]);
+        pool.write(base + offset_of!(PoolState, mint_decimals_1), &[6]);
+        pool.write(
+            base + offset_of!(PoolState, tick_current),
+            &tick.to_le_bytes(),
+        );
+        pool.write(
+            base + offset_of!(PoolState, observation_key),
+            observation_key.as_ref(),
+        );
+        pool
+    }
+
+    /// Observations every 15 seconds over the last 10 minutes, at `tick`
+    fn observation_fixture(tick: i32) -> Fixture {
+        observation_fixture_until(tick, NOW as u32)
+    }
+
+    /// Observations every 15 seconds over the 10 minutes ending at `newest`, at `tick`
+    fn observation_fixture_until(tick: i32, newest: u32) -> Fixture {
+        let mut observation_state =
+            raydium_fixture::<ObservationState>(size_of::<ObservationState>());
+        let base =
+            ObservationState::DISCRIMINATOR.len() + offset_of!(ObservationState, observations);
+
+        let count = 41;
+        for index in 0..count {
+            let offset = base + index * size_of::<Observation>();
+            let block_timestamp = newest - 600 + 15 * index as u32;
+            let tick_cumulative = tick as i64 * 15 * index as i64;
+            observation_state.write(
+                offset + offset_of!(Observation, block_timestamp),
+                &block_timestamp.to_le_bytes(),
+            );
+            observation_state.write(
+                offset + offset_of!(Observation, tick_cumulative),
+                &tick_cumulative.to_le_bytes(),
+            );
+        }
+        observation_state.write(
+            ObservationState::DISCRIMINATOR.len() + offset_of!(ObservationState, observation_index),
+            &((count - 1) as u16).to_le_bytes(),
+        );
+        observation_state
+    }
+
+    fn oracle_feed(mint: Pubkey, sources: &[OracleSource], twap_pool: Pubkey) -> OracleFeed {
+        let mut oracle_feed = OracleFeed {
+            mint,
+            feed_id: FEED_ID,
+            exponent: -8,
+            ..Default::default()
+        };
+        oracle_feed
+            .set_sources(
+                Pubkey::default(),
+                sources,
+                Pubkey::new_unique(),
+                twap_pool,
+                300,
+            )
+            .unwrap();
+        oracle_feed
+    }
+
+    fn bounds() -> PriceBounds {
+        PriceBounds {
+            max_age: 600,
+            max_conf: 20_000,
+        }
+    }
+
+    #[test]
+    fn reads_the_primary_source() {
+        let mut pyth = pyth_fixture(150_00000000, NOW - 10);
+        let pyth_info = pyth.account_info();
+        let pyth_price_update = Account::<PriceUpdateV2>::try_from(&pyth_info).unwrap();
+
+        let oracle_feed = oracle_feed(
+            Pubkey::new_unique(),
+            &[OracleSource::Pyth],
+            Pubkey::default(),
+        );
+        let accounts = OracleAccounts {
+            pyth_price_update: Some(&pyth_price_update),
+            switchboard_feed: None,
+            twap_pool_state: None,
+            twap_observation_state: None,
+        };
+
+        let price_info = oracle_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap();
+        assert_eq!(price_info.price, 150_00000000);
+        assert_eq!(price_info.source, OracleSource::Pyth);
+    }
+
+    #[test]
+    fn falls_back_to_the_raydium_twap_when_pyth_is_stale() {
+        let mint = Pubkey::new_unique();
+        let mut observation_state = observation_fixture(0);
+        let mut pool = pool_fixture(mint, Pubkey::new_unique(), 0, observation_state.key);
+        let mut pyth = pyth_fixture(150_00000000, NOW - 601);
+
+        let pyth_info = pyth.account_info();
+        let pool_info = pool.account_info();
+        let observation_info = observation_state.account_info();
+        let pyth_price_update = Account::<PriceUpdateV2>::try_from(&pyth_info).unwrap();
+        let twap_pool_state = AccountLoader::<PoolState>::try_from(&pool_info).unwrap();
+        let twap_observation_state =
+            AccountLoader::<ObservationState>::try_from(&observation_info).unwrap();
+
+        let oracle_feed = oracle_feed(
+            mint,
+            &[OracleSource::Pyth, OracleSource::RaydiumTwap],
+            twap_pool_state.key(),
+        );
+        let accounts = OracleAccounts {
+            pyth_price_update: Some(&pyth_price_update),
+            switchboard_feed: None,
+            twap_pool_state: Some(&twap_pool_state),
+            twap_observation_state: Some(&twap_observation_state),
+        };
+
+        // The pool trades at 1 USD at tick 0
+        let price_info = oracle_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap();
+        assert_eq!(price_info.price, 1_00000000);
+        assert_eq!(price_info.expo, -8);
+        assert_eq!(price_info.source, OracleSource::RaydiumTwap);
+    }
+
+    #[test]
+    fn reports_a_missing_account_before_a_stale_price() {
+        let mut pyth = pyth_fixture(150_00000000, NOW - 601);
+        let pyth_info = pyth.account_info();
+        let pyth_price_update = Account::<PriceUpdateV2>::try_from(&pyth_info).unwrap();
+
+        // The TWAP accounts are not passed
+        let oracle_feed = oracle_feed(
+            Pubkey::new_unique(),
+            &[OracleSource::Pyth, OracleSource::RaydiumTwap],
+            Pubkey::new_unique(),
+        );
+        let accounts = OracleAccounts {
+            pyth_price_update: Some(&pyth_price_update),
+            switchboard_feed: None,
+            twap_pool_state: None,
+            twap_observation_state: None,
+        };
+
+        let err = oracle_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TokenizedVaultsErrorCode::OracleSourceUnavailable.into()
+        );
+
+        // Every source was read, the stale price of the primary one is reported
+        let pyth_feed = self::oracle_feed(
+            Pubkey::new_unique(),
+            &[OracleSource::Pyth],
+            Pubkey::default(),
+        );
+        let err = pyth_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(err, TokenizedVaultsErrorCode::PriceTooOld.into());
+    }
+
+    #[test]
+    fn rejects_a_twap_pool_other_than_the_registered_one() {
+        let mint = Pubkey::new_unique();
+        let mut observation_state = observation_fixture(0);
+        let mut pool = pool_fixture(mint, Pubkey::new_unique(), 0, observation_state.key);
+        let mut pyth = pyth_fixture(150_00000000, NOW - 10);
+
+        let pyth_info = pyth.account_info();
+        let pool_info = pool.account_info();
+        let observation_info = observation_state.account_info();
+        let pyth_price_update = Account::<PriceUpdateV2>::try_from(&pyth_info).unwrap();
+        let twap_pool_state = AccountLoader::<PoolState>::try_from(&pool_info).unwrap();
+        let twap_observation_state =
+            AccountLoader::<ObservationState>::try_from(&observation_info).unwrap();
+
+        let oracle_feed = oracle_feed(mint, &[OracleSource::RaydiumTwap], Pubkey::new_unique());
+        let accounts = OracleAccounts {
+            pyth_price_update: Some(&pyth_price_update),
+            switchboard_feed: None,
+            twap_pool_state: Some(&twap_pool_state),
+            twap_observation_state: Some(&twap_observation_state),
+        };
+
+        let err = oracle_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(err, TokenizedVaultsErrorCode::InvalidOracleAccount.into());
+    }
+
+    #[test]
+    fn twap_prices_either_token_of_the_pool() {
+        let mint_0 = Pubkey::new_unique();
+        let mint_1 = Pubkey::new_unique();
+        // 1.0001^4055 ~ 1.5
+        let mut observation_state = observation_fixture(4055);
+        let mut pool = pool_fixture(mint_0, mint_1, 4055, observation_state.key);
+
+        let pool_info = pool.account_info();
+        let observation_info = observation_state.account_info();
+        let pool_state = AccountLoader::<PoolState>::try_from(&pool_info).unwrap();
+        let observation_state =
+            AccountLoader::<ObservationState>::try_from(&observation_info).unwrap();
+        let (pool_state, observation_state) = (
+            pool_state.load().unwrap(),
+            observation_state.load().unwrap(),
+        );
+
+        let price_info_0 =
+            get_price_from_raydium_twap(&pool_state, &observation_state, &mint_0, 300, -8, NOW)
+                .unwrap();
+        assert!((1_4995_0000..1_5005_0000).contains(&price_info_0.price));
+        assert_eq!(price_info_0.conf, 0);
+
+        let price_info_1 =
+            get_price_from_raydium_twap(&pool_state, &observation_state, &mint_1, 300, -8, NOW)
+                .unwrap();
+        assert!((66_6500..66_7000).contains(&(price_info_1.price / 100)));
+
+        assert!(get_price_from_raydium_twap(
+            &pool_state,
+            &observation_state,
+            &Pubkey::new_unique(),
+            300,
+            -8,
+            NOW
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn twap_is_as_old_as_the_newest_observation() {
+        let mint = Pubkey::new_unique();
+        let newest = NOW as u32 - 700;
+        let mut observation_state = observation_fixture_until(0, newest);
+        let mut pool = pool_fixture(mint, Pubkey::new_unique(), 0, observation_state.key);
+
+        let pool_info = pool.account_info();
+        let observation_info = observation_state.account_info();
+        let twap_pool_state = AccountLoader::<PoolState>::try_from(&pool_info).unwrap();
+        let twap_observation_state =
+            AccountLoader::<ObservationState>::try_from(&observation_info).unwrap();
+
+        let price_info = get_price_from_raydium_twap(
+            &twap_pool_state.load().unwrap(),
+            &twap_observation_state.load().unwrap(),
+            &mint,
+            300,
+            -8,
+            NOW,
+        )
+        .unwrap();
+        assert_eq!(price_info.publish_time, newest as i64);
+
+        // No swap for longer than the maximum age, the TWAP is stale
+        let oracle_feed = oracle_feed(mint, &[OracleSource::RaydiumTwap], twap_pool_state.key());
+        let accounts = OracleAccounts {
+            pyth_price_update: None,
+            switchboard_feed: None,
+            twap_pool_state: Some(&twap_pool_state),
+            twap_observation_state: Some(&twap_observation_state),
+        };
+        let err = oracle_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(err, TokenizedVaultsErrorCode::PriceTooOld.into());
+        oracle_feed
+            .price_info_at(&accounts, &bounds(), newest as i64 + 600)
+            .unwrap();
+    }
+
+    #[test]
+    fn pyth_account_is_only_needed_by_a_pyth_source() {
+        let mint = Pubkey::new_unique();
+        let mut observation_state = observation_fixture(0);
+        let mut pool = pool_fixture(mint, Pubkey::new_unique(), 0, observation_state.key);
+
+        let pool_info = pool.account_info();
+        let observation_info = observation_state.account_info();
+        let twap_pool_state = AccountLoader::<PoolState>::try_from(&pool_info).unwrap();
+        let twap_observation_state =
+            AccountLoader::<ObservationState>::try_from(&observation_info).unwrap();
+        let accounts = OracleAccounts {
+            pyth_price_update: None,
+            switchboard_feed: None,
+            twap_pool_state: Some(&twap_pool_state),
+            twap_observation_state: Some(&twap_observation_state),
+        };
+
+        let twap_feed = oracle_feed(mint, &[OracleSource::RaydiumTwap], twap_pool_state.key());
+        let price_info = twap_feed.price_info_at(&accounts, &bounds(), NOW).unwrap();
+        assert_eq!(price_info.source, OracleSource::RaydiumTwap);
+
+        let pyth_feed = oracle_feed(mint, &[OracleSource::Pyth], Pubkey::default());
+        let err = pyth_feed
+            .price_info_at(&accounts, &bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TokenizedVaultsErrorCode::OracleSourceUnavailable.into()
+        );
+    }
+
+    #[test]
+    fn usd_price_scales_by_the_decimals_and_the_exponent() {
+        // SOL (9 decimals) at 0.15 raw USDC (6 decimals) per raw SOL is 150 USD
+        let sqrt_price_x64 = (0.15f64.sqrt() * fixed_point_64::Q64 as f64) as u128;
+        let price_x64 = crate::get_pool_price_x64(sqrt_price_x64).unwrap();
+
+        let price = get_usd_price_from_pool_price_x64(price_x64, 9, 6, -8).unwrap();
+        assert!(
+            (149_9999_0000..=150_0001_0000).contains(&price),
+            "{}",
+            price
+        );
+
+        // A negative scale divides: 6 decimals priced in a 9 decimals stablecoin, exponent 0
+        let price_x64 = U256::from(1_000u64) << fixed_point_64::RESOLUTION;
+        assert_eq!(
+            get_usd_price_from_pool_price_x64(price_x64, 6, 9, 0).unwrap(),
+            1
+        );
+        let tick_price_x64 =
+            crate::get_pool_price_x64(tick_math::get_sqrt_price_at_tick(0).unwrap()).unwrap();
+        assert_eq!(
+            get_usd_price_from_pool_price_x64(tick_price_x64, 6, 6, -6).unwrap(),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn switchboard_results_are_rescaled_to_the_registry_exponent() {
+        let price_info = get_price_from_switchboard_result(
+            150_250_000_000_000_000_000,
+            100_000_000_000_000_000,
+            NOW,
+            -8,
+        )
+        .unwrap();
+        assert_eq!(price_info.price, 150_25000000);
+        assert_eq!(price_info.conf, 10000000);
+        assert_eq!(price_info.expo, -8);
+        assert_eq!(price_info.publish_time, NOW);
+        assert_eq!(price_info.source, OracleSource::SwitchboardOnDemand);
+
+        price_info.check_bounds(&bounds(), NOW).unwrap();
+
+        assert!(get_price_from_switchboard_result(1, 0, NOW, 1).is_err());
+        assert!(get_price_from_switchboard_result(i128::MAX, 0, NOW, -18).is_err());
+    }
+}
@@ -6,3 +6,4 @@ pub const DISC_VAULT_STRATEGY_ACCOUNT: &[u8] = &[3];
 pub const DISC_INVESTOR_STR_POS_ACCOUNT: &[u8] = &[4];
 pub const DISC_INVESTOR_ESCROW_ACCOUNT: &[u8] = &[5];
 pub const DISC_INVEST_RESERVE_VAULT_ACCOUNT: &[u8] = &[6];
+pub const DISC_ORACLE_FEED_ACCOUNT: &[u8] = &[7];
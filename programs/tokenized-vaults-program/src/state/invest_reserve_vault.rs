@@ -8,7 +8,9 @@ use crate::{error::TokenizedVaultsErrorCode, DISC_INVEST_RESERVE_VAULT_ACCOUNT};
 pub struct SwapToRatioVault {
     pub vault_strategy_key: Pubkey,
     pub amount_in: u64,
+    /// Token 0 swapped, replaced by the amount added as liquidity once executed
     pub token_0_amount: u64,
+    /// Token 1 swapped, replaced by the amount added as liquidity once executed
     pub token_1_amount: u64,
     pub executed: bool,
 }
@@ -88,9 +90,155 @@ impl InvestReserveVault {
         }
     }
 
+    /// Records the amounts actually consumed by the liquidity increase,
+    /// the remainder is refunded by `add_liquidity_raydium_vault_strategy`
+    pub fn set_swap_to_ratio_consumed(
+        &mut self,
+        vault_strategy_key: Pubkey,
+        token_0_amount: u64,
+        token_1_amount: u64,
+    ) -> Result<()> {
+        let existing_vault = self
+            .swap_to_ratio_vaults
+            .iter_mut()
+            .find(|v| !v.executed && v.vault_strategy_key == vault_strategy_key)
+            .ok_or(TokenizedVaultsErrorCode::SwapToRatioVaultNotFound)?;
+
+        existing_vault.token_0_amount = token_0_amount;
+        existing_vault.token_1_amount = token_1_amount;
+        Ok(())
+    }
+
     pub fn clean_up(&mut self) -> Result<()> {
         self.reserved_amount = 0;
         self.swap_to_ratio_vaults.clear();
         Ok(())
     }
+
+    /// Amounts still owned by the investor that were not added as liquidity:
+    /// (USDC never swapped, token 0 swapped, token 1 swapped)
+    pub fn unexecuted_amounts(&self) -> Result<(u64, u64, u64)> {
+        let mut swapped_usdc: u64 = 0;
+        let mut token_0_amount: u64 = 0;
+        let mut token_1_amount: u64 = 0;
+
+        for vault in self.swap_to_ratio_vaults.iter() {
+            swapped_usdc = swapped_usdc
+                .checked_add(vault.amount_in)
+                .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+            if !vault.executed {
+                token_0_amount = token_0_amount
+                    .checked_add(vault.token_0_amount)
+                    .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+                token_1_amount = token_1_amount
+                    .checked_add(vault.token_1_amount)
+                    .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+            }
+        }
+
+        let usdc_amount = self
+            .reserved_amount
+            .checked_sub(swapped_usdc)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+        Ok((usdc_amount, token_0_amount, token_1_amount))
+    }
+}
+
+/// Emitted when the swapped amounts of a strategy are added as liquidity
+#[event]
+#[derive(Debug)]
+pub struct SwapToRatioVaultExecutedEvent {
+    pub investor: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub amount_in: u64,
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub refund_0: u64,
+    pub refund_1: u64,
+}
+
+/// Emitted when an investor cancels a pending investment
+#[event]
+#[derive(Debug)]
+pub struct InvestReserveCancelledEvent {
+    pub investor: Pubkey,
+    pub vault_strategy_config_key: Pubkey,
+    pub usdc_amount: u64,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+#[cfg(test)]
+mod unexecuted_amounts_tests {
+    use super::*;
+
+    fn swap_to_ratio_vault(amount_in: u64, executed: bool) -> SwapToRatioVault {
+        SwapToRatioVault {
+            vault_strategy_key: Pubkey::new_unique(),
+            amount_in,
+            token_0_amount: amount_in * 2,
+            token_1_amount: amount_in * 3,
+            executed,
+        }
+    }
+
+    #[test]
+    fn nothing_swapped_refunds_the_whole_reserve() {
+        let reserve = InvestReserveVault {
+            reserved_amount: 1_000,
+            ..Default::default()
+        };
+
+        assert_eq!(reserve.unexecuted_amounts().unwrap(), (1_000, 0, 0));
+    }
+
+    #[test]
+    fn executed_swaps_are_not_refunded() {
+        let reserve = InvestReserveVault {
+            reserved_amount: 1_000,
+            swap_to_ratio_vaults: vec![
+                swap_to_ratio_vault(300, true),
+                swap_to_ratio_vault(500, false),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(reserve.unexecuted_amounts().unwrap(), (200, 1_000, 1_500));
+    }
+
+    #[test]
+    fn cleaned_up_reserve_refunds_nothing() {
+        let mut reserve = InvestReserveVault {
+            reserved_amount: 1_000,
+            swap_to_ratio_vaults: vec![swap_to_ratio_vault(1_000, true)],
+            ..Default::default()
+        };
+        reserve.clean_up().unwrap();
+
+        assert_eq!(reserve.unexecuted_amounts().unwrap(), (0, 0, 0));
+    }
+
+    #[test]
+    fn consumed_amounts_replace_the_swapped_amounts() {
+        let vault = swap_to_ratio_vault(500, false);
+        let mut reserve = InvestReserveVault {
+            reserved_amount: 1_000,
+            swap_to_ratio_vaults: vec![vault],
+            ..Default::default()
+        };
+
+        reserve
+            .set_swap_to_ratio_consumed(vault.vault_strategy_key, 990, 1_480)
+            .unwrap();
+        assert_eq!(reserve.unexecuted_amounts().unwrap(), (500, 990, 1_480));
+
+        reserve
+            .set_swap_to_ratio_executed(vault.vault_strategy_key, true)
+            .unwrap();
+        assert!(reserve
+            .set_swap_to_ratio_consumed(vault.vault_strategy_key, 1, 1)
+            .is_err());
+    }
 }
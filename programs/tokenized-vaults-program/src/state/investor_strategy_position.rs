@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::TokenizedVaultsErrorCode, DENOMINATOR_MULTIPLIER, DISC_INVESTOR_STR_POS_ACCOUNT,
+    error::TokenizedVaultsErrorCode, fixed_point_64, MulDiv, DENOMINATOR_MULTIPLIER,
+    DISC_INVESTOR_STR_POS_ACCOUNT, MIN_REDEEM_SHARES, U128,
 };
 
 #[derive(Default, Debug, InitSpace)]
@@ -12,6 +13,17 @@ pub struct InvestorStrategyPosition {
     pub shares: u64,
     pub assets: u64,
     pub bump: u8,
+    /// Fee growth per share of token 0 when the fees owed were last settled, Q64.64
+    pub fee_growth_checkpoint_0_x64: u128,
+    /// Fee growth per share of token 1 when the fees owed were last settled, Q64.64
+    pub fee_growth_checkpoint_1_x64: u128,
+    /// Token 0 fees earned by the shares and not paid out yet
+    pub fees_owed_0: u64,
+    /// Token 1 fees earned by the shares and not paid out yet
+    pub fees_owed_1: u64,
+    /// Whether the shares are held as share tokens, false for the positions opened
+    /// before the strategy had a share mint until `migrate_investor_shares`
+    pub shares_minted: bool,
 }
 
 impl InvestorStrategyPosition {
@@ -32,6 +44,7 @@ impl InvestorStrategyPosition {
         self.authority = authority;
         self.vault_strategy_key = vault_strategy_key;
         self.bump = bump;
+        self.shares_minted = true;
         self.deposit_assets(assets, total_vault_assets, total_vault_shares)
     }
 
@@ -87,6 +100,160 @@ impl InvestorStrategyPosition {
         Ok(())
     }
 
+    /// Sets up a position created on redeem or withdraw for shares received by transfer,
+    /// an existing position is left untouched
+    pub fn open_if_needed(&mut self, authority: Pubkey, vault_strategy_key: Pubkey, bump: u8) {
+        if self.authority == Pubkey::default() {
+            self.authority = authority;
+            self.vault_strategy_key = vault_strategy_key;
+            self.bump = bump;
+            self.shares_minted = true;
+        }
+    }
+
+    /// Marks the shares of a position opened before the strategy had a share mint
+    /// as minted, they can only be minted once
+    /// returns the shares to mint to the investor
+    pub fn take_unminted_shares(&mut self) -> Result<u64> {
+        require!(
+            !self.shares_minted,
+            TokenizedVaultsErrorCode::SharesAlreadyMinted
+        );
+        self.shares_minted = true;
+        Ok(self.shares)
+    }
+
+    /// The share token balance is the source of truth of the redeemable shares,
+    /// shares moved in or out of the investor share account since the last update
+    /// are picked up here once the fees earned by the previous shares are settled
+    pub fn sync_shares(
+        &mut self,
+        share_balance: u64,
+        fee_growth_per_share_0_x64: u128,
+        fee_growth_per_share_1_x64: u128,
+    ) -> Result<()> {
+        require!(
+            self.shares_minted,
+            TokenizedVaultsErrorCode::SharesNotMinted
+        );
+        self.settle_fees(fee_growth_per_share_0_x64, fee_growth_per_share_1_x64)?;
+        if self.shares == share_balance {
+            return Ok(());
+        }
+        self.shares = share_balance;
+
+        emit!(InvestorStrategyPositionEvent {
+            authority: self.authority,
+            vault_strategy_key: self.vault_strategy_key,
+            shares: self.shares,
+            assets: self.assets,
+        });
+        Ok(())
+    }
+
+    /// Credit the fees earned by the current shares since the last checkpoint.
+    /// Must be called with the strategy fee growth before any change of shares
+    pub fn settle_fees(
+        &mut self,
+        fee_growth_per_share_0_x64: u128,
+        fee_growth_per_share_1_x64: u128,
+    ) -> Result<()> {
+        (self.fees_owed_0, self.fees_owed_1) =
+            self.pending_fees(fee_growth_per_share_0_x64, fee_growth_per_share_1_x64)?;
+        self.fee_growth_checkpoint_0_x64 = fee_growth_per_share_0_x64;
+        self.fee_growth_checkpoint_1_x64 = fee_growth_per_share_1_x64;
+        Ok(())
+    }
+
+    fn fees_earned(
+        &self,
+        fee_growth_per_share_x64: u128,
+        fee_growth_checkpoint_x64: u128,
+    ) -> Result<u64> {
+        let earned = U128::from(fee_growth_per_share_x64.wrapping_sub(fee_growth_checkpoint_x64))
+            .mul_div_floor(U128::from(self.shares), U128::from(fixed_point_64::Q64))
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        u64::try_from(earned.as_u128()).map_err(|_| error!(TokenizedVaultsErrorCode::MathOverflow))
+    }
+
+    /// Fees the position would be paid at the given fee growth, settled or not
+    /// returns (fees_0, fees_1)
+    pub fn pending_fees(
+        &self,
+        fee_growth_per_share_0_x64: u128,
+        fee_growth_per_share_1_x64: u128,
+    ) -> Result<(u64, u64)> {
+        let fees_0 = self
+            .fees_owed_0
+            .checked_add(
+                self.fees_earned(fee_growth_per_share_0_x64, self.fee_growth_checkpoint_0_x64)?,
+            )
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        let fees_1 = self
+            .fees_owed_1
+            .checked_add(
+                self.fees_earned(fee_growth_per_share_1_x64, self.fee_growth_checkpoint_1_x64)?,
+            )
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        Ok((fees_0, fees_1))
+    }
+
+    /// Hand out the settled fees
+    /// returns (fees_owed_0, fees_owed_1)
+    pub fn take_fees_owed(&mut self) -> (u64, u64) {
+        let fees_owed = (self.fees_owed_0, self.fees_owed_1);
+        self.fees_owed_0 = 0;
+        self.fees_owed_1 = 0;
+        fees_owed
+    }
+
+    /// Dust guard of the redeem and withdraw by shares or assets: the burn has to be
+    /// at least `MIN_REDEEM_SHARES`, unless it empties the position, and cannot leave
+    /// less than `MIN_REDEEM_SHARES` behind
+    pub fn assert_redeemable_shares(&self, shares: u64) -> Result<()> {
+        require!(
+            shares <= self.shares,
+            TokenizedVaultsErrorCode::InsufficientShares
+        );
+        require!(shares > 0, TokenizedVaultsErrorCode::SharesCalculatedToZero);
+
+        let remaining_shares = self.shares - shares;
+        require!(
+            shares >= MIN_REDEEM_SHARES || remaining_shares == 0,
+            TokenizedVaultsErrorCode::SharesBelowDust
+        );
+        require!(
+            remaining_shares == 0 || remaining_shares >= MIN_REDEEM_SHARES,
+            TokenizedVaultsErrorCode::RemainingSharesBelowDust
+        );
+        Ok(())
+    }
+
+    /// Shares to burn to withdraw `assets`, the inverse of `convert_shares_to_assets`
+    /// rounded up so the burned shares are always worth at least the withdrawn assets
+    pub fn convert_assets_to_shares_rounding_up(
+        &self,
+        assets: u64,
+        total_vault_assets: u64,
+        total_vault_shares: u64,
+    ) -> Result<u64> {
+        require!(
+            total_vault_assets > 0,
+            TokenizedVaultsErrorCode::AssetsCalculatedToZero
+        );
+
+        let numerator = (assets as u128)
+            .checked_mul(total_vault_shares as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        let shares = numerator
+            .checked_add(total_vault_assets as u128 - 1)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(total_vault_assets as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+
+        u64::try_from(shares).map_err(|_| error!(TokenizedVaultsErrorCode::MathOverflow))
+    }
+
     /// Convert shares to percentage is 1e9 precision,
     /// ex: 10 shares in 100 total shares = 10 * 1e9 / 100 = 100_000_000 = 100_000_000/1e9 = 0.1 = 10%
     /// returns an error if the calculation overflows
@@ -151,3 +318,189 @@ pub struct InvestorStrategyPositionEvent {
     pub shares: u64,
     pub assets: u64,
 }
+
+/// Emitted when an investor is paid the fees earned by their shares
+#[event]
+#[derive(Debug)]
+pub struct InvestorFeesClaimedEvent {
+    pub investor: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+#[cfg(test)]
+mod fee_checkpoint_tests {
+    use super::*;
+
+    const Q64: u128 = fixed_point_64::Q64;
+
+    fn position(shares: u64) -> InvestorStrategyPosition {
+        InvestorStrategyPosition {
+            shares,
+            shares_minted: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn earns_only_the_growth_since_its_checkpoint() {
+        // 10 tokens per share were accrued before the investor joined
+        let mut investor = position(0);
+        investor.settle_fees(10 * Q64, 0).unwrap();
+        investor.shares = 100;
+
+        investor.settle_fees(12 * Q64, 0).unwrap();
+        assert_eq!(investor.fees_owed_0, 200);
+        assert_eq!(investor.fee_growth_checkpoint_0_x64, 12 * Q64);
+
+        // settling again at the same growth credits nothing
+        assert_eq!(investor.pending_fees(13 * Q64, 0).unwrap(), (300, 0));
+        investor.settle_fees(12 * Q64, 0).unwrap();
+        assert_eq!(investor.take_fees_owed(), (200, 0));
+        assert_eq!((investor.fees_owed_0, investor.fees_owed_1), (0, 0));
+    }
+
+    #[test]
+    fn entitlement_does_not_depend_on_withdrawal_order() {
+        let mut early = position(300);
+        let mut late = position(0);
+
+        // 600 collected while only the early investor is in
+        let growth_0 = 600 * Q64 / 300;
+        late.settle_fees(growth_0, 0).unwrap();
+        late.shares = 300;
+
+        // 600 collected over 600 shares
+        let growth_1 = growth_0 + 600 * Q64 / 600;
+
+        late.settle_fees(growth_1, growth_1).unwrap();
+        early.settle_fees(growth_1, growth_1).unwrap();
+        assert_eq!(early.fees_owed_0, 900);
+        assert_eq!(late.fees_owed_0, 300);
+        assert_eq!(early.fees_owed_1, late.fees_owed_1);
+    }
+
+    #[test]
+    fn transferred_shares_earn_from_the_sync_on() {
+        let mut sender = position(300);
+        let mut receiver = position(0);
+
+        // 300 shares are transferred once 2 tokens per share were accrued
+        sender.sync_shares(0, 2 * Q64, 0).unwrap();
+        receiver.sync_shares(300, 2 * Q64, 0).unwrap();
+        assert_eq!((sender.shares, receiver.shares), (0, 300));
+
+        sender.settle_fees(3 * Q64, 0).unwrap();
+        receiver.settle_fees(3 * Q64, 0).unwrap();
+        assert_eq!(sender.fees_owed_0, 600);
+        assert_eq!(receiver.fees_owed_0, 300);
+    }
+
+    #[test]
+    fn handles_wrapping_accumulator() {
+        let mut investor = position(10);
+        investor.fee_growth_checkpoint_0_x64 = u128::MAX - Q64 + 1;
+
+        investor.settle_fees(Q64, 0).unwrap();
+        assert_eq!(investor.fees_owed_0, 20);
+    }
+}
+
+#[cfg(test)]
+mod share_migration_tests {
+    use super::*;
+
+    #[test]
+    fn legacy_shares_are_minted_once_before_any_sync() {
+        // Position of the first release, grown by `migrate_account`
+        let mut legacy = InvestorStrategyPosition {
+            authority: Pubkey::new_unique(),
+            shares: 500,
+            ..Default::default()
+        };
+        assert!(legacy.sync_shares(0, 0, 0).is_err());
+        assert_eq!(legacy.shares, 500);
+
+        legacy.open_if_needed(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert!(!legacy.shares_minted);
+
+        assert_eq!(legacy.take_unminted_shares().unwrap(), 500);
+        assert!(legacy.take_unminted_shares().is_err());
+
+        legacy.sync_shares(500, 0, 0).unwrap();
+        assert_eq!(legacy.shares, 500);
+    }
+
+    #[test]
+    fn new_positions_hold_share_tokens() {
+        let mut opened = InvestorStrategyPosition::default();
+        opened.open_if_needed(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert!(opened.shares_minted);
+        assert!(opened.take_unminted_shares().is_err());
+        opened.sync_shares(100, 0, 0).unwrap();
+        assert_eq!(opened.shares, 100);
+    }
+}
+
+#[cfg(test)]
+mod redeem_tests {
+    use super::*;
+
+    fn position(shares: u64) -> InvestorStrategyPosition {
+        InvestorStrategyPosition {
+            shares,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dust_guard_allows_full_exit_and_regular_burns() {
+        let small = position(MIN_REDEEM_SHARES - 1);
+        assert!(small
+            .assert_redeemable_shares(MIN_REDEEM_SHARES - 1)
+            .is_ok());
+
+        let investor = position(10 * MIN_REDEEM_SHARES);
+        assert!(investor.assert_redeemable_shares(MIN_REDEEM_SHARES).is_ok());
+        assert!(investor
+            .assert_redeemable_shares(10 * MIN_REDEEM_SHARES)
+            .is_ok());
+    }
+
+    #[test]
+    fn dust_guard_rejects_dust_burns_and_dust_leftovers() {
+        let investor = position(10 * MIN_REDEEM_SHARES);
+        assert!(investor.assert_redeemable_shares(0).is_err());
+        assert!(investor
+            .assert_redeemable_shares(MIN_REDEEM_SHARES - 1)
+            .is_err());
+        assert!(investor
+            .assert_redeemable_shares(10 * MIN_REDEEM_SHARES - 1)
+            .is_err());
+        assert!(investor
+            .assert_redeemable_shares(10 * MIN_REDEEM_SHARES + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn withdraw_shares_round_up() {
+        let investor = position(1_000);
+        // 3 assets for 1 share
+        let shares = investor
+            .convert_assets_to_shares_rounding_up(10, 3_000, 1_000)
+            .unwrap();
+        assert_eq!(shares, 4);
+        assert!(
+            investor
+                .convert_shares_to_assets(shares, 3_000, 1_000)
+                .unwrap()
+                >= 10
+        );
+
+        let exact = investor
+            .convert_assets_to_shares_rounding_up(9, 3_000, 1_000)
+            .unwrap();
+        assert_eq!(exact, 3);
+    }
+}
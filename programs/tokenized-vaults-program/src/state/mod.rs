@@ -22,3 +22,6 @@ pub use constants::*;
 
 pub mod raydium_tick_array;
 pub use raydium_tick_array::*;
+
+pub mod oracle_feed;
+pub use oracle_feed::*;
@@ -0,0 +1,531 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    error::TokenizedVaultsErrorCode,
+    oracle::{get_price_from_raydium_twap, get_price_from_switchboard_feed, OracleAccounts},
+    utils::{
+        get_unchecked_price_from_pyth_feed_id, is_price_out_of_bounds, PriceBounds, TokenPriceInfo,
+    },
+    DISC_ORACLE_FEED_ACCOUNT, MAX_ORACLE_SOURCES, MAX_PERCENTAGE,
+};
+
+/// Where the USD price of a mint is read from
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum OracleSource {
+    /// Unused slot of the source list
+    #[default]
+    None,
+    /// Pyth pull oracle, feed `OracleFeed::feed_id`
+    Pyth,
+    /// Switchboard On-Demand pull feed `OracleFeed::switchboard_feed`
+    SwitchboardOnDemand,
+    /// TWAP of the Raydium CLMM pool `OracleFeed::twap_pool`
+    RaydiumTwap,
+}
+
+/// Oracle registry entry of a mint, managed by the admin authority.
+/// Every USD valuation of the mint resolves its Pyth feed from here
+#[derive(Default, Debug, InitSpace)]
+#[account(discriminator = DISC_ORACLE_FEED_ACCOUNT)]
+pub struct OracleFeed {
+    pub mint: Pubkey,
+    /// Pyth feed id pricing the mint in USD, zero when Pyth is not a source
+    pub feed_id: [u8; 32],
+    /// Exponent the prices of the mint are expected in
+    pub exponent: i32,
+    /// Maximum age in seconds of the price, 0 uses the protocol config bound
+    pub max_price_age: u64,
+    /// Maximum confidence interval relative to the price in BPS scale (100% = 1_000_000),
+    /// 0 uses the protocol config bound
+    pub max_price_conf: u32,
+    pub bump: u8,
+    /// Price sources tried in order until one is available and within the bounds,
+    /// `None` ends the list
+    pub sources: [OracleSource; MAX_ORACLE_SOURCES],
+    /// Switchboard On-Demand pull feed pricing the mint in USD
+    pub switchboard_feed: Pubkey,
+    /// Raydium CLMM pool pairing the mint with a USD stablecoin, valued at 1 USD
+    pub twap_pool: Pubkey,
+    /// Window of the Raydium TWAP in seconds
+    pub twap_window: u32,
+}
+
+impl OracleFeed {
+    pub const SEED: &'static str = "oracle_feed:";
+
+    pub fn set(
+        &mut self,
+        admin_authority: Pubkey,
+        mint: Pubkey,
+        feed_id: [u8; 32],
+        exponent: i32,
+        max_price_age: u64,
+        max_price_conf: u32,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            max_price_conf <= MAX_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidOracleFeed
+        );
+
+        self.mint = mint;
+        self.feed_id = feed_id;
+        self.exponent = exponent;
+        self.max_price_age = max_price_age;
+        self.max_price_conf = max_price_conf;
+        self.bump = bump;
+        // A new registry entry with a Pyth feed only reads Pyth until fallbacks are set,
+        // one without has no source until `set_sources`
+        if self.sources[0] == OracleSource::None && self.has_pyth_feed() {
+            self.sources[0] = OracleSource::Pyth;
+        }
+        require!(
+            self.has_pyth_feed() || !self.sources.contains(&OracleSource::Pyth),
+            TokenizedVaultsErrorCode::InvalidOracleFeed
+        );
+
+        emit!(OracleFeedEvent {
+            admin_authority,
+            mint,
+            feed_id,
+            exponent,
+            max_price_age,
+            max_price_conf,
+        });
+        Ok(())
+    }
+
+    pub fn has_pyth_feed(&self) -> bool {
+        self.feed_id != [0u8; 32]
+    }
+
+    /// Bounds of the feed, the ones left to 0 fall back to the protocol config
+    pub fn price_bounds(&self, protocol_bounds: &PriceBounds) -> PriceBounds {
+        PriceBounds {
+            max_age: if self.max_price_age == 0 {
+                protocol_bounds.max_age
+            } else {
+                self.max_price_age
+            },
+            max_conf: if self.max_price_conf == 0 {
+                protocol_bounds.max_conf
+            } else {
+                self.max_price_conf
+            },
+        }
+    }
+
+    pub fn check_exponent(&self, price_info: &TokenPriceInfo) -> Result<()> {
+        require!(
+            price_info.expo == self.exponent,
+            TokenizedVaultsErrorCode::PythExponentMismatch
+        );
+        Ok(())
+    }
+
+    /// Sets the ordered price sources of the mint and the accounts they read from
+    pub fn set_sources(
+        &mut self,
+        admin_authority: Pubkey,
+        sources: &[OracleSource],
+        switchboard_feed: Pubkey,
+        twap_pool: Pubkey,
+        twap_window: u32,
+    ) -> Result<()> {
+        require!(
+            !sources.is_empty() && sources.len() <= MAX_ORACLE_SOURCES,
+            TokenizedVaultsErrorCode::InvalidOracleSources
+        );
+        for (index, source) in sources.iter().enumerate() {
+            require!(
+                *source != OracleSource::None && !sources[..index].contains(source),
+                TokenizedVaultsErrorCode::InvalidOracleSources
+            );
+        }
+        require!(
+            !sources.contains(&OracleSource::Pyth) || self.has_pyth_feed(),
+            TokenizedVaultsErrorCode::InvalidOracleSources
+        );
+        require!(
+            !sources.contains(&OracleSource::SwitchboardOnDemand)
+                || switchboard_feed != Pubkey::default(),
+            TokenizedVaultsErrorCode::InvalidOracleSources
+        );
+        require!(
+            !sources.contains(&OracleSource::RaydiumTwap)
+                || (twap_pool != Pubkey::default() && twap_window > 0),
+            TokenizedVaultsErrorCode::InvalidOracleSources
+        );
+
+        self.sources = [OracleSource::None; MAX_ORACLE_SOURCES];
+        self.sources[..sources.len()].copy_from_slice(sources);
+        self.switchboard_feed = switchboard_feed;
+        self.twap_pool = twap_pool;
+        self.twap_window = twap_window;
+
+        emit!(OracleSourcesEvent {
+            admin_authority,
+            mint: self.mint,
+            sources: self.sources,
+            switchboard_feed,
+            twap_pool,
+            twap_window,
+        });
+        Ok(())
+    }
+
+    /// Reads the price of the mint from its sources in order, the first one available
+    /// and within the feed bounds wins. When none is, the error of the first source that could
+    /// not be read from its accounts is returned, else the bounds error of the primary source
+    pub fn price_info<'info>(
+        &self,
+        accounts: &OracleAccounts<'_, 'info>,
+        protocol_bounds: &PriceBounds,
+    ) -> Result<TokenPriceInfo> {
+        self.price_info_at(accounts, protocol_bounds, Clock::get()?.unix_timestamp)
+    }
+
+    pub fn price_info_at<'info>(
+        &self,
+        accounts: &OracleAccounts<'_, 'info>,
+        protocol_bounds: &PriceBounds,
+        now: i64,
+    ) -> Result<TokenPriceInfo> {
+        let bounds = self.price_bounds(protocol_bounds);
+        let mut account_error = None;
+        let mut bounds_error = None;
+
+        for source in self
+            .sources
+            .iter()
+            .take_while(|source| **source != OracleSource::None)
+        {
+            match self.source_price_info(*source, accounts, &bounds, now) {
+                Ok(price_info) => {
+                    if *source != self.sources[0] {
+                        msg!("Oracle fallback to {:?}", source);
+                        emit!(OracleFallbackEvent {
+                            mint: self.mint,
+                            primary_source: self.sources[0],
+                            source: *source,
+                        });
+                    }
+                    return Ok(price_info);
+                }
+                Err(error) if is_price_out_of_bounds(&error) => {
+                    bounds_error.get_or_insert(error);
+                }
+                Err(error) => {
+                    account_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(account_error
+            .or(bounds_error)
+            .unwrap_or_else(|| error!(TokenizedVaultsErrorCode::OracleSourceUnavailable)))
+    }
+
+    fn source_price_info<'info>(
+        &self,
+        source: OracleSource,
+        accounts: &OracleAccounts<'_, 'info>,
+        bounds: &PriceBounds,
+        now: i64,
+    ) -> Result<TokenPriceInfo> {
+        let price_info = match source {
+            OracleSource::Pyth => {
+                let pyth_price_update = accounts
+                    .pyth_price_update
+                    .ok_or(TokenizedVaultsErrorCode::OracleSourceUnavailable)?;
+                get_unchecked_price_from_pyth_feed_id(pyth_price_update, &self.feed_id)?
+            }
+            OracleSource::SwitchboardOnDemand => {
+                let switchboard_feed = accounts
+                    .switchboard_feed
+                    .ok_or(TokenizedVaultsErrorCode::OracleSourceUnavailable)?;
+                require_keys_eq!(
+                    switchboard_feed.key(),
+                    self.switchboard_feed,
+                    TokenizedVaultsErrorCode::InvalidOracleAccount
+                );
+                get_price_from_switchboard_feed(switchboard_feed, self.exponent)?
+            }
+            OracleSource::RaydiumTwap => {
+                let (Some(twap_pool_state), Some(twap_observation_state)) =
+                    (accounts.twap_pool_state, accounts.twap_observation_state)
+                else {
+                    return err!(TokenizedVaultsErrorCode::OracleSourceUnavailable);
+                };
+                require_keys_eq!(
+                    twap_pool_state.key(),
+                    self.twap_pool,
+                    TokenizedVaultsErrorCode::InvalidOracleAccount
+                );
+                let pool_state = twap_pool_state.load()?;
+                require_keys_eq!(
+                    twap_observation_state.key(),
+                    pool_state.observation_key,
+                    TokenizedVaultsErrorCode::InvalidOracleAccount
+                );
+                get_price_from_raydium_twap(
+                    &pool_state,
+                    &twap_observation_state.load()?,
+                    &self.mint,
+                    self.twap_window,
+                    self.exponent,
+                    now,
+                )?
+            }
+            OracleSource::None => return err!(TokenizedVaultsErrorCode::OracleSourceUnavailable),
+        };
+
+        self.check_exponent(&price_info)?;
+        price_info.check_bounds(bounds, now)?;
+        Ok(price_info)
+    }
+}
+
+/// Emitted when the admin registers or updates the oracle feed of a mint
+#[event]
+#[derive(Debug)]
+pub struct OracleFeedEvent {
+    pub admin_authority: Pubkey,
+    pub mint: Pubkey,
+    pub feed_id: [u8; 32],
+    pub exponent: i32,
+    pub max_price_age: u64,
+    pub max_price_conf: u32,
+}
+
+/// Emitted when the admin sets the price sources of a mint
+#[event]
+#[derive(Debug)]
+pub struct OracleSourcesEvent {
+    pub admin_authority: Pubkey,
+    pub mint: Pubkey,
+    pub sources: [OracleSource; MAX_ORACLE_SOURCES],
+    pub switchboard_feed: Pubkey,
+    pub twap_pool: Pubkey,
+    pub twap_window: u32,
+}
+
+/// Emitted when a price is read from a fallback source because the primary one failed
+#[event]
+#[derive(Debug)]
+pub struct OracleFallbackEvent {
+    pub mint: Pubkey,
+    pub primary_source: OracleSource,
+    pub source: OracleSource,
+}
+
+#[cfg(test)]
+mod oracle_feed_tests {
+    use super::*;
+
+    fn protocol_bounds() -> PriceBounds {
+        PriceBounds {
+            max_age: 600,
+            max_conf: 20_000,
+        }
+    }
+
+    fn feed(max_price_age: u64, max_price_conf: u32) -> OracleFeed {
+        OracleFeed {
+            feed_id: [1u8; 32],
+            exponent: -8,
+            max_price_age,
+            max_price_conf,
+            ..Default::default()
+        }
+    }
+
+    fn price_info(expo: i32) -> TokenPriceInfo {
+        TokenPriceInfo {
+            price: 100_000_000,
+            expo,
+            conf: 0,
+            publish_time: 0,
+            source: OracleSource::Pyth,
+        }
+    }
+
+    #[test]
+    fn unset_bounds_fall_back_to_the_protocol_config() {
+        let bounds = feed(0, 0).price_bounds(&protocol_bounds());
+        assert_eq!(bounds.max_age, 600);
+        assert_eq!(bounds.max_conf, 20_000);
+
+        let bounds = feed(60, 0).price_bounds(&protocol_bounds());
+        assert_eq!(bounds.max_age, 60);
+        assert_eq!(bounds.max_conf, 20_000);
+
+        let bounds = feed(0, 5_000).price_bounds(&protocol_bounds());
+        assert_eq!(bounds.max_age, 600);
+        assert_eq!(bounds.max_conf, 5_000);
+    }
+
+    #[test]
+    fn rejects_an_unexpected_exponent() {
+        let feed = feed(0, 0);
+        feed.check_exponent(&price_info(-8)).unwrap();
+        assert!(feed.check_exponent(&price_info(-6)).is_err());
+    }
+
+    #[test]
+    fn sources_are_ordered_without_gaps_or_duplicates() {
+        let mut feed = feed(0, 0);
+        let switchboard_feed = Pubkey::new_unique();
+        let twap_pool = Pubkey::new_unique();
+
+        feed.set_sources(
+            Pubkey::default(),
+            &[OracleSource::Pyth, OracleSource::RaydiumTwap],
+            Pubkey::default(),
+            twap_pool,
+            300,
+        )
+        .unwrap();
+        assert_eq!(
+            feed.sources,
+            [
+                OracleSource::Pyth,
+                OracleSource::RaydiumTwap,
+                OracleSource::None
+            ]
+        );
+
+        for sources in [
+            &[][..],
+            &[OracleSource::None][..],
+            &[OracleSource::Pyth, OracleSource::Pyth][..],
+            &[
+                OracleSource::Pyth,
+                OracleSource::SwitchboardOnDemand,
+                OracleSource::RaydiumTwap,
+                OracleSource::Pyth,
+            ][..],
+        ] {
+            assert!(feed
+                .set_sources(Pubkey::default(), sources, switchboard_feed, twap_pool, 300)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn fallback_sources_need_their_accounts() {
+        let mut feed = feed(0, 0);
+
+        assert!(feed
+            .set_sources(
+                Pubkey::default(),
+                &[OracleSource::SwitchboardOnDemand],
+                Pubkey::default(),
+                Pubkey::default(),
+                0,
+            )
+            .is_err());
+        assert!(feed
+            .set_sources(
+                Pubkey::default(),
+                &[OracleSource::RaydiumTwap],
+                Pubkey::default(),
+                Pubkey::new_unique(),
+                0,
+            )
+            .is_err());
+        feed.set_sources(
+            Pubkey::default(),
+            &[OracleSource::SwitchboardOnDemand, OracleSource::Pyth],
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            0,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn registering_a_feed_defaults_to_pyth() {
+        let mut feed = OracleFeed::default();
+        feed.set(
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            [1u8; 32],
+            -8,
+            0,
+            0,
+            255,
+        )
+        .unwrap();
+        assert_eq!(feed.sources[0], OracleSource::Pyth);
+
+        // Registering again keeps the configured sources
+        feed.sources = [
+            OracleSource::SwitchboardOnDemand,
+            OracleSource::Pyth,
+            OracleSource::None,
+        ];
+        feed.set(Pubkey::default(), feed.mint, [1u8; 32], -8, 0, 0, 255)
+            .unwrap();
+        assert_eq!(feed.sources[0], OracleSource::SwitchboardOnDemand);
+    }
+
+    #[test]
+    fn a_mint_without_pyth_feed_reads_its_other_sources() {
+        let mut feed = OracleFeed::default();
+        feed.set(
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            [0u8; 32],
+            -8,
+            0,
+            0,
+            255,
+        )
+        .unwrap();
+        assert!(!feed.has_pyth_feed());
+        assert_eq!(feed.sources[0], OracleSource::None);
+
+        assert!(feed
+            .set_sources(
+                Pubkey::default(),
+                &[OracleSource::SwitchboardOnDemand, OracleSource::Pyth],
+                Pubkey::new_unique(),
+                Pubkey::default(),
+                0,
+            )
+            .is_err());
+        feed.set_sources(
+            Pubkey::default(),
+            &[OracleSource::SwitchboardOnDemand],
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            0,
+        )
+        .unwrap();
+
+        // The Pyth feed can't be removed while Pyth is a source
+        let mut feed = feed_with_pyth();
+        assert!(feed
+            .set(Pubkey::default(), feed.mint, [0u8; 32], -8, 0, 0, 255)
+            .is_err());
+    }
+
+    fn feed_with_pyth() -> OracleFeed {
+        let mut feed = OracleFeed::default();
+        feed.set(
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            [1u8; 32],
+            -8,
+            0,
+            0,
+            255,
+        )
+        .unwrap();
+        feed
+    }
+}
@@ -1,6 +1,10 @@
 use crate::constants::{HIGH_FEES, LOW_FEES};
 use crate::error::TokenizedVaultsErrorCode;
-use crate::{ProtocolStatus, DISC_PROTOCOL_CONFIG_ACCOUNT};
+use crate::utils::PriceBounds;
+use crate::{
+    ProtocolStatus, DEFAULT_MAX_PRICE_AGE, DEFAULT_MAX_PRICE_CONF, DISC_PROTOCOL_CONFIG_ACCOUNT,
+    MAX_PERCENTAGE,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Default, Debug, InitSpace)]
@@ -10,10 +14,16 @@ pub struct ProtocolConfig {
     pub protocol_fees: u32,
     pub status: ProtocolStatus,
     pub bump: u8,
+    pub pending_admin: Pubkey,
+    /// Maximum age in seconds of the Pyth prices used for valuations
+    pub max_price_age: u64,
+    /// Maximum Pyth confidence interval relative to the price, in BPS scale (100% = 1_000_000)
+    pub max_price_conf: u32,
 }
 
 impl ProtocolConfig {
     pub const SEED: &'static str = "protocol_config:";
+    pub const PROTOCOL_FEES_ESCROW_SEED: &'static str = "protocol_fees_escrow:";
 
     pub fn initialize(
         &mut self,
@@ -38,10 +48,18 @@ impl ProtocolConfig {
             TokenizedVaultsErrorCode::FeeTooLow
         );
 
-        self.set_inner(admin_authority, protocol_fees, ProtocolStatus::Active, bump)?;
+        self.set_inner(
+            admin_authority,
+            protocol_fees,
+            ProtocolStatus::Active,
+            DEFAULT_MAX_PRICE_AGE,
+            DEFAULT_MAX_PRICE_CONF,
+            bump,
+        )?;
 
         emit!(ProtocolConfigEvent {
             admin_authority,
+            pending_admin: self.pending_admin,
             protocol_fees,
             status: self.status,
         });
@@ -53,11 +71,16 @@ impl ProtocolConfig {
         admin_authority: Pubkey,
         protocol_fees: u32,
         status: ProtocolStatus,
+        max_price_age: u64,
+        max_price_conf: u32,
         bump: u8,
     ) -> Result<()> {
         self.admin_authority = admin_authority;
+        self.pending_admin = Pubkey::default();
         self.protocol_fees = protocol_fees;
         self.status = status;
+        self.max_price_age = max_price_age;
+        self.max_price_conf = max_price_conf;
         self.bump = bump;
 
         Ok(())
@@ -73,6 +96,7 @@ impl ProtocolConfig {
         self.status = ProtocolStatus::Paused;
         emit!(ProtocolConfigEvent {
             admin_authority: self.admin_authority,
+            pending_admin: self.pending_admin,
             protocol_fees: self.protocol_fees,
             status: self.status,
         });
@@ -90,6 +114,99 @@ impl ProtocolConfig {
 
         emit!(ProtocolConfigEvent {
             admin_authority: self.admin_authority,
+            pending_admin: self.pending_admin,
+            protocol_fees: self.protocol_fees,
+            status: self.status,
+        });
+        Ok(())
+    }
+
+    /// First step of the admin authority transfer.
+    /// The new admin only takes over once it calls `accept_admin`.
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        require!(
+            new_admin != Pubkey::default() && new_admin != self.admin_authority,
+            TokenizedVaultsErrorCode::InvalidPendingAdmin
+        );
+
+        self.pending_admin = new_admin;
+
+        emit!(ProtocolConfigEvent {
+            admin_authority: self.admin_authority,
+            pending_admin: self.pending_admin,
+            protocol_fees: self.protocol_fees,
+            status: self.status,
+        });
+        Ok(())
+    }
+
+    /// Second step of the admin authority transfer, signed by the pending admin.
+    pub fn accept_admin(&mut self, pending_admin: Pubkey) -> Result<()> {
+        require!(
+            self.pending_admin != Pubkey::default(),
+            TokenizedVaultsErrorCode::NoPendingAdmin
+        );
+        require_keys_eq!(
+            self.pending_admin,
+            pending_admin,
+            TokenizedVaultsErrorCode::Unauthorized
+        );
+
+        self.admin_authority = pending_admin;
+        self.pending_admin = Pubkey::default();
+
+        emit!(ProtocolConfigEvent {
+            admin_authority: self.admin_authority,
+            pending_admin: self.pending_admin,
+            protocol_fees: self.protocol_fees,
+            status: self.status,
+        });
+        Ok(())
+    }
+
+    /// Defaults of the fields appended after the first release,
+    /// for a config grown by `migrate_account`
+    pub fn migrate(&mut self) {
+        self.max_price_age = DEFAULT_MAX_PRICE_AGE;
+        self.max_price_conf = DEFAULT_MAX_PRICE_CONF;
+    }
+
+    /// Freshness and confidence required from the Pyth prices
+    pub fn set_price_bounds(&mut self, max_price_age: u64, max_price_conf: u32) -> Result<()> {
+        require!(
+            max_price_age > 0 && max_price_conf > 0 && max_price_conf <= MAX_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidPriceBounds
+        );
+
+        self.max_price_age = max_price_age;
+        self.max_price_conf = max_price_conf;
+
+        emit!(ProtocolPriceBoundsEvent {
+            admin_authority: self.admin_authority,
+            max_price_age,
+            max_price_conf,
+        });
+        Ok(())
+    }
+
+    pub fn price_bounds(&self) -> PriceBounds {
+        PriceBounds {
+            max_age: self.max_price_age,
+            max_conf: self.max_price_conf,
+        }
+    }
+
+    pub fn cancel_admin_proposal(&mut self) -> Result<()> {
+        require!(
+            self.pending_admin != Pubkey::default(),
+            TokenizedVaultsErrorCode::NoPendingAdmin
+        );
+
+        self.pending_admin = Pubkey::default();
+
+        emit!(ProtocolConfigEvent {
+            admin_authority: self.admin_authority,
+            pending_admin: self.pending_admin,
             protocol_fees: self.protocol_fees,
             status: self.status,
         });
@@ -101,6 +218,36 @@ impl ProtocolConfig {
 #[derive(Debug)]
 pub struct ProtocolConfigEvent {
     pub admin_authority: Pubkey,
+    pub pending_admin: Pubkey,
     pub protocol_fees: u32,
     pub status: ProtocolStatus,
 }
+
+/// Emitted when the admin updates the Pyth price bounds
+#[event]
+#[derive(Debug)]
+pub struct ProtocolPriceBoundsEvent {
+    pub admin_authority: Pubkey,
+    pub max_price_age: u64,
+    pub max_price_conf: u32,
+}
+
+/// Emitted when protocol fees are moved into a protocol fees escrow
+#[event]
+#[derive(Debug)]
+pub struct ProtocolFeesAccruedEvent {
+    pub vault_strategy_config_key: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the admin claims protocol fees from a protocol fees escrow
+#[event]
+#[derive(Debug)]
+pub struct ProtocolFeesClaimedEvent {
+    pub admin_authority: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
@@ -1,5 +1,6 @@
 use crate::{
-    assert_vault_strategy_percentage, error::TokenizedVaultsErrorCode, DISC_VAULT_STRATEGY_ACCOUNT,
+    assert_vault_strategy_percentage, error::TokenizedVaultsErrorCode, fixed_point_64, MulDiv,
+    DISC_VAULT_STRATEGY_ACCOUNT, U128,
 };
 
 use anchor_lang::prelude::*;
@@ -13,16 +14,43 @@ pub struct VaultStrategy {
     pub dex_nft_mint: Pubkey,
     pub mint_0: Pubkey,
     pub mint_1: Pubkey,
+    /// Mark-to-market value of the strategy in USDC, refreshed on every deposit
     pub total_assets: u64,
     pub total_shares: u64,
     pub percentage: u32,
     pub strategy_id: u8,
     pub bump: u8,
+    /// SPL mint of the strategy shares, created with the strategy
+    pub share_mint: Pubkey,
+    /// Pyth feed id of token 0 in the oracle registry when the strategy was created
+    pub price_feed_id_0: [u8; 32],
+    /// Pyth feed id of token 1 in the oracle registry when the strategy was created
+    pub price_feed_id_1: [u8; 32],
+    /// Investor fees of token 0 earned per share since the strategy was created, Q64.64
+    pub fee_growth_per_share_0_x64: u128,
+    /// Investor fees of token 1 earned per share since the strategy was created, Q64.64
+    pub fee_growth_per_share_1_x64: u128,
+    /// Token 0 left to be redeemed by investors once the strategy is closed
+    pub closed_amount_0: u64,
+    /// Token 1 left to be redeemed by investors once the strategy is closed
+    pub closed_amount_1: u64,
+    pub is_closed: bool,
+    /// Lower tick of the current Raydium position
+    pub tick_lower_index: i32,
+    /// Upper tick of the current Raydium position
+    pub tick_upper_index: i32,
 }
 
 impl VaultStrategy {
     /// The seed used to derive the vault strategy PDA
     pub const SEED: &'static str = "vlt_strtg:";
+    /// The seeds used to derive the escrows holding the closed strategy proceeds
+    pub const CLOSED_0_ESCROW_SEED: &'static str = "vlt_strtg_closed_0_escrow:";
+    pub const CLOSED_1_ESCROW_SEED: &'static str = "vlt_strtg_closed_1_escrow:";
+    /// The seed used to derive the share mint PDA
+    pub const SHARE_MINT_SEED: &'static str = "vlt_strtg_share_mint:";
+    /// Shares are denominated like the USDC assets, 1 micro share per micro USDC
+    pub const SHARE_MINT_DECIMALS: u8 = 6;
 
     pub fn initialize(
         &mut self,
@@ -130,6 +158,69 @@ impl VaultStrategy {
         Ok(())
     }
 
+    /// Spread the investor part of freshly collected fees over the current shares.
+    /// The tokens stay in the fees escrows until each investor claims their part
+    pub fn accrue_fees(&mut self, fees_0: u64, fees_1: u64) -> Result<()> {
+        if self.total_shares == 0 || (fees_0 == 0 && fees_1 == 0) {
+            return Ok(());
+        }
+
+        (
+            self.fee_growth_per_share_0_x64,
+            self.fee_growth_per_share_1_x64,
+        ) = self.fee_growth_after(fees_0, fees_1)?;
+
+        emit!(VaultStrategyFeesAccruedEvent {
+            creator: self.creator,
+            vault_strategy_config_key: self.vault_strategy_config_key,
+            fees_0,
+            fees_1,
+            fee_growth_per_share_0_x64: self.fee_growth_per_share_0_x64,
+            fee_growth_per_share_1_x64: self.fee_growth_per_share_1_x64,
+            strategy_id: self.strategy_id
+        });
+        Ok(())
+    }
+
+    /// Fee growth per share once `fees_0` and `fees_1` are accrued, without accruing them
+    /// returns (fee_growth_per_share_0_x64, fee_growth_per_share_1_x64)
+    pub fn fee_growth_after(&self, fees_0: u64, fees_1: u64) -> Result<(u128, u128)> {
+        if self.total_shares == 0 {
+            return Ok((
+                self.fee_growth_per_share_0_x64,
+                self.fee_growth_per_share_1_x64,
+            ));
+        }
+
+        let growth_0 = Self::fee_growth_per_share(fees_0, self.total_shares)?;
+        let growth_1 = Self::fee_growth_per_share(fees_1, self.total_shares)?;
+
+        // the accumulators wrap like the Raydium fee growth, only differences are meaningful
+        Ok((
+            self.fee_growth_per_share_0_x64.wrapping_add(growth_0),
+            self.fee_growth_per_share_1_x64.wrapping_add(growth_1),
+        ))
+    }
+
+    fn fee_growth_per_share(fees: u64, total_shares: u64) -> Result<u128> {
+        let growth = U128::from(fees)
+            .mul_div_floor(U128::from(fixed_point_64::Q64), U128::from(total_shares))
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        Ok(growth.as_u128())
+    }
+
+    /// Replaces the running total with the NAV of the strategy before a deposit
+    pub fn mark_to_market(&mut self, nav: u64) -> Result<()> {
+        self.total_assets = nav;
+        emit!(VaultStrategyUpdateAssetsEvent {
+            creator: self.creator,
+            vault_strategy_config_key: self.vault_strategy_config_key,
+            assets: self.total_assets,
+            strategy_id: self.strategy_id
+        });
+        Ok(())
+    }
+
     pub fn remove_assets(&mut self, assets: u64) -> Result<()> {
         self.total_assets = self.total_assets.saturating_sub(assets);
         emit!(VaultStrategyUpdateAssetsEvent {
@@ -141,6 +232,130 @@ impl VaultStrategy {
         Ok(())
     }
 
+    /// Moves the strategy to a new Raydium position, shares and assets are left untouched
+    pub fn rebalance(
+        &mut self,
+        dex_nft_mint: Pubkey,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        liquidity: u128,
+    ) -> Result<()> {
+        let previous_dex_nft_mint = self.dex_nft_mint;
+        self.dex_nft_mint = dex_nft_mint;
+        self.set_position_ticks(tick_lower_index, tick_upper_index)?;
+
+        emit!(VaultStrategyRebalancedEvent {
+            creator: self.creator,
+            vault_strategy_config_key: self.vault_strategy_config_key,
+            previous_dex_nft_mint,
+            dex_nft_mint,
+            tick_lower_index,
+            tick_upper_index,
+            liquidity,
+            strategy_id: self.strategy_id
+        });
+        Ok(())
+    }
+
+    pub fn set_position_ticks(
+        &mut self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        self.tick_lower_index = tick_lower_index;
+        self.tick_upper_index = tick_upper_index;
+        Ok(())
+    }
+
+    pub fn set_price_feed_ids(
+        &mut self,
+        price_feed_id_0: [u8; 32],
+        price_feed_id_1: [u8; 32],
+    ) -> Result<()> {
+        self.price_feed_id_0 = price_feed_id_0;
+        self.price_feed_id_1 = price_feed_id_1;
+        Ok(())
+    }
+
+    pub fn set_share_mint(&mut self, share_mint: Pubkey) -> Result<()> {
+        require!(
+            self.share_mint == Pubkey::default(),
+            TokenizedVaultsErrorCode::VaultStrategyShareMintInitialized
+        );
+
+        self.share_mint = share_mint;
+
+        emit!(VaultStrategyShareMintEvent {
+            creator: self.creator,
+            vault_strategy_config_key: self.vault_strategy_config_key,
+            share_mint,
+            strategy_id: self.strategy_id
+        });
+        Ok(())
+    }
+
+    /// Record the proceeds of the unwound Raydium position,
+    /// investors redeem them pro-rata to their shares afterward
+    pub fn close(&mut self, amount_0: u64, amount_1: u64) -> Result<()> {
+        require!(
+            !self.is_closed,
+            TokenizedVaultsErrorCode::VaultStrategyAlreadyClosed
+        );
+
+        self.is_closed = true;
+        self.closed_amount_0 = amount_0;
+        self.closed_amount_1 = amount_1;
+
+        emit!(VaultStrategyClosedEvent {
+            creator: self.creator,
+            vault_strategy_config_key: self.vault_strategy_config_key,
+            amount_0,
+            amount_1,
+            total_shares: self.total_shares,
+            strategy_id: self.strategy_id
+        });
+        Ok(())
+    }
+
+    /// Pro-rata share of the closed proceeds
+    /// returns (amount_0, amount_1)
+    pub fn convert_shares_to_closed_amounts(&self, shares: u64) -> Result<(u64, u64)> {
+        require!(
+            self.is_closed,
+            TokenizedVaultsErrorCode::VaultStrategyNotClosed
+        );
+        require!(
+            shares <= self.total_shares,
+            TokenizedVaultsErrorCode::InsufficientShares
+        );
+
+        let amount_0 = (shares as u128)
+            .checked_mul(self.closed_amount_0 as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(self.total_shares as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)? as u64;
+
+        let amount_1 = (shares as u128)
+            .checked_mul(self.closed_amount_1 as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?
+            .checked_div(self.total_shares as u128)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)? as u64;
+
+        Ok((amount_0, amount_1))
+    }
+
+    pub fn remove_closed_amounts(&mut self, amount_0: u64, amount_1: u64) -> Result<()> {
+        self.closed_amount_0 = self
+            .closed_amount_0
+            .checked_sub(amount_0)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        self.closed_amount_1 = self
+            .closed_amount_1
+            .checked_sub(amount_1)
+            .ok_or(TokenizedVaultsErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
     pub fn remove_shares(&mut self, shares: u64) -> Result<()> {
         self.total_shares = self.total_shares.saturating_sub(shares);
         emit!(VaultStrategyUpdateShareEvent {
@@ -153,6 +368,16 @@ impl VaultStrategy {
     }
 }
 
+/// Emitted on deposit with the NAV the new shares were priced against
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyNavEvent {
+    pub vault_strategy_config_key: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub nav: u64,
+    pub total_shares: u64,
+}
+
 #[event]
 #[derive(Debug)]
 pub struct VaultStrategyInitializeEvent {
@@ -166,6 +391,71 @@ pub struct VaultStrategyInitializeEvent {
     percentage: u32,
     strategy_id: u8,
 }
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyRebalancedEvent {
+    creator: Pubkey,
+    vault_strategy_config_key: Pubkey,
+    previous_dex_nft_mint: Pubkey,
+    dex_nft_mint: Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    liquidity: u128,
+    strategy_id: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyShareMintEvent {
+    creator: Pubkey,
+    vault_strategy_config_key: Pubkey,
+    share_mint: Pubkey,
+    strategy_id: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyCompoundedEvent {
+    pub vault_strategy_config_key: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub fees_0: u64,
+    pub fees_1: u64,
+    pub liquidity: u128,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyFeesAccruedEvent {
+    creator: Pubkey,
+    vault_strategy_config_key: Pubkey,
+    fees_0: u64,
+    fees_1: u64,
+    fee_growth_per_share_0_x64: u128,
+    fee_growth_per_share_1_x64: u128,
+    strategy_id: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyClosedEvent {
+    creator: Pubkey,
+    vault_strategy_config_key: Pubkey,
+    amount_0: u64,
+    amount_1: u64,
+    total_shares: u64,
+    strategy_id: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyRedeemEvent {
+    pub investor: Pubkey,
+    pub vault_strategy_key: Pubkey,
+    pub shares: u64,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
 #[event]
 #[derive(Debug)]
 pub struct VaultStrategyUpdateAssetsEvent {
@@ -183,3 +473,120 @@ pub struct VaultStrategyUpdateShareEvent {
     shares: u64,
     strategy_id: u8,
 }
+
+#[cfg(test)]
+mod closed_amounts_tests {
+    use super::*;
+
+    fn closed_strategy(total_shares: u64, amount_0: u64, amount_1: u64) -> VaultStrategy {
+        VaultStrategy {
+            total_shares,
+            closed_amount_0: amount_0,
+            closed_amount_1: amount_1,
+            is_closed: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn redeems_pro_rata_until_empty() {
+        let mut vault_strategy = closed_strategy(300, 1_000, 10);
+
+        for shares in [100, 150, 50] {
+            let (amount_0, amount_1) = vault_strategy
+                .convert_shares_to_closed_amounts(shares)
+                .unwrap();
+            vault_strategy
+                .remove_closed_amounts(amount_0, amount_1)
+                .unwrap();
+            vault_strategy.total_shares -= shares;
+        }
+
+        // rounding dust is left to the last shares, nothing stays locked
+        assert_eq!(vault_strategy.total_shares, 0);
+        assert_eq!(vault_strategy.closed_amount_0, 0);
+        assert_eq!(vault_strategy.closed_amount_1, 0);
+    }
+
+    #[test]
+    fn rejects_open_strategy_and_excess_shares() {
+        let mut vault_strategy = closed_strategy(100, 1_000, 1_000);
+        assert!(vault_strategy
+            .convert_shares_to_closed_amounts(101)
+            .is_err());
+
+        vault_strategy.is_closed = false;
+        assert!(vault_strategy.convert_shares_to_closed_amounts(1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod fee_growth_tests {
+    use super::*;
+
+    #[test]
+    fn fees_are_spread_over_current_shares() {
+        let mut vault_strategy = VaultStrategy {
+            total_shares: 400,
+            ..Default::default()
+        };
+        vault_strategy.accrue_fees(1_000, 0).unwrap();
+
+        assert_eq!(
+            vault_strategy.fee_growth_per_share_0_x64,
+            (1_000u128 << 64) / 400
+        );
+        assert_eq!(vault_strategy.fee_growth_per_share_1_x64, 0);
+    }
+
+    #[test]
+    fn growth_preview_matches_accrual() {
+        let mut vault_strategy = VaultStrategy {
+            total_shares: 300,
+            fee_growth_per_share_1_x64: u128::MAX,
+            ..Default::default()
+        };
+        let preview = vault_strategy.fee_growth_after(600, 300).unwrap();
+        vault_strategy.accrue_fees(600, 300).unwrap();
+
+        assert_eq!(
+            preview,
+            (
+                vault_strategy.fee_growth_per_share_0_x64,
+                vault_strategy.fee_growth_per_share_1_x64
+            )
+        );
+        assert_eq!(preview, (2 << 64, (1 << 64) - 1));
+    }
+
+    #[test]
+    fn nothing_accrues_without_shares() {
+        let mut vault_strategy = VaultStrategy::default();
+        vault_strategy.accrue_fees(1_000, 1_000).unwrap();
+
+        assert_eq!(vault_strategy.fee_growth_per_share_0_x64, 0);
+        assert_eq!(vault_strategy.fee_growth_per_share_1_x64, 0);
+    }
+}
+
+#[cfg(test)]
+mod rebalance_tests {
+    use super::*;
+
+    #[test]
+    fn rebalance_records_the_new_position() {
+        let mut vault_strategy = VaultStrategy {
+            tick_lower_index: -100,
+            tick_upper_index: 100,
+            ..Default::default()
+        };
+        let dex_nft_mint = Pubkey::new_unique();
+        vault_strategy
+            .rebalance(dex_nft_mint, 200, 400, 1_000)
+            .unwrap();
+
+        assert_eq!(vault_strategy.dex_nft_mint, dex_nft_mint);
+        assert_eq!(vault_strategy.tick_lower_index, 200);
+        assert_eq!(vault_strategy.tick_upper_index, 400);
+    }
+}
@@ -2,8 +2,9 @@ use std::mem::MaybeUninit;
 
 use crate::{
     assert_vault_strategy_percentage, assert_vault_strategy_performance_fee,
-    assert_vault_strategy_type, error::TokenizedVaultsErrorCode, VaultStrategyStatus,
-    VaultStrategyType, DISC_VAULT_STRATEGY_CONFIG_ACCOUNT, MAX_NUM_STRATEGIES, MAX_PERCENTAGE,
+    assert_vault_strategy_type, error::TokenizedVaultsErrorCode, VaultOperation,
+    VaultStrategyStatus, VaultStrategyType, DEFAULT_MAX_PRICE_DEVIATION,
+    DISC_VAULT_STRATEGY_CONFIG_ACCOUNT, MAX_NUM_STRATEGIES, MAX_PERCENTAGE,
 };
 
 use anchor_lang::prelude::*;
@@ -22,6 +23,11 @@ pub struct VaultStrategyConfig {
     pub percentages: Vec<u32>, // [300_000, 500_000, 200_000]
     #[max_len(32)]
     pub name: String,
+    /// Optional operator allowed to rebalance the strategies, Pubkey::default() when unset
+    pub keeper: Pubkey,
+    /// Maximum deviation of the pool price from the Pyth price allowed to deposit or withdraw,
+    /// in BPS scale (100% = 1_000_000), 0 disables the check
+    pub max_price_deviation: u32,
 }
 
 impl VaultStrategyConfig {
@@ -34,6 +40,20 @@ impl VaultStrategyConfig {
     pub const VAULT_FEES_1_ESCROW_SEED: &str = "vlt_fees_1_escrow:";
     pub const VAULT_PERF_FEES_0_ESCROW_SEED: &str = "vlt_perf_fees_0_escrow:";
     pub const VAULT_PERF_FEES_1_ESCROW_SEED: &str = "vlt_perf_fees_1_escrow:";
+    pub const VAULT_REBALANCE_0_ESCROW_SEED: &str = "vlt_rebalance_0_escrow:";
+    pub const VAULT_REBALANCE_1_ESCROW_SEED: &str = "vlt_rebalance_1_escrow:";
+    pub const VAULT_WITHDRAW_0_ESCROW_SEED: &str = "vlt_withdraw_0_escrow:";
+    pub const VAULT_WITHDRAW_1_ESCROW_SEED: &str = "vlt_withdraw_1_escrow:";
+
+    /// Seeds of the config PDA, which signs for the vault escrows and the strategy positions
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::SEED.as_bytes(),
+            self.creator.as_ref(),
+            self.name.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
 
     pub fn initialize(
         &mut self,
@@ -82,6 +102,7 @@ impl VaultStrategyConfig {
         bump: u8,
     ) -> Result<()> {
         self.creator = creator;
+        self.max_price_deviation = DEFAULT_MAX_PRICE_DEVIATION;
         self.performance_fee = performance_fee;
         self.vault_strategy_type = vault_strategy_type;
         self.name = name;
@@ -124,6 +145,123 @@ impl VaultStrategyConfig {
         );
 
         self.status = VaultStrategyStatus::Paused;
+
+        emit!(VaultStrategyConfigEvent {
+            creator: self.creator,
+            performance_fee: self.performance_fee,
+            vault_strategy_type: self.vault_strategy_type,
+            status: self.status,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_keeper(&mut self, keeper: Pubkey) -> Result<()> {
+        self.keeper = keeper;
+
+        emit!(VaultStrategyConfigKeeperEvent {
+            creator: self.creator,
+            keeper,
+        });
+
+        Ok(())
+    }
+
+    /// 0 disables the pool price deviation check
+    pub fn set_max_price_deviation(&mut self, max_price_deviation: u32) -> Result<()> {
+        require!(
+            max_price_deviation <= MAX_PERCENTAGE,
+            TokenizedVaultsErrorCode::InvalidPriceDeviation
+        );
+
+        self.max_price_deviation = max_price_deviation;
+
+        emit!(VaultStrategyConfigPriceDeviationEvent {
+            creator: self.creator,
+            max_price_deviation,
+        });
+
+        Ok(())
+    }
+
+    /// Defaults of the fields appended after the first release,
+    /// for a vault grown by `migrate_account`
+    pub fn migrate(&mut self) {
+        self.max_price_deviation = DEFAULT_MAX_PRICE_DEVIATION;
+    }
+
+    pub fn has_price_deviation_check(&self) -> bool {
+        self.max_price_deviation != 0
+    }
+
+    /// Rejects an operation while the pool price deviates from its reference, the oracle price
+    /// or the pool TWAP for a rebalance, by more than `max_price_deviation`
+    pub fn check_pool_price_deviation(
+        &self,
+        vault_strategy_key: Pubkey,
+        sqrt_price_x64: u128,
+        deviation: u32,
+    ) -> Result<()> {
+        if !self.has_price_deviation_check() {
+            return Ok(());
+        }
+
+        msg!(
+            "pool price deviation: {}, max: {}",
+            deviation,
+            self.max_price_deviation
+        );
+        require!(
+            deviation <= self.max_price_deviation,
+            TokenizedVaultsErrorCode::PoolPriceDeviationTooHigh
+        );
+
+        emit!(PoolPriceDeviationEvent {
+            vault_strategy_key,
+            sqrt_price_x64,
+            deviation,
+            max_price_deviation: self.max_price_deviation,
+        });
+
+        Ok(())
+    }
+
+    pub fn assert_creator_or_keeper(&self, actor: &Pubkey) -> Result<()> {
+        require!(
+            *actor == self.creator || (self.keeper != Pubkey::default() && *actor == self.keeper),
+            TokenizedVaultsErrorCode::Unauthorized
+        );
+        Ok(())
+    }
+
+    /// Final state of the vault, it can be closed from Draft, Active or Paused
+    pub fn close_vault(&mut self) -> Result<()> {
+        require!(
+            self.status != VaultStrategyStatus::Closed,
+            TokenizedVaultsErrorCode::VaultStrategyConfigAlreadyClosed
+        );
+        require!(
+            self.status != VaultStrategyStatus::Unknown,
+            TokenizedVaultsErrorCode::VaultStrategyConfigNotActive
+        );
+
+        self.status = VaultStrategyStatus::Closed;
+
+        emit!(VaultStrategyConfigEvent {
+            creator: self.creator,
+            performance_fee: self.performance_fee,
+            vault_strategy_type: self.vault_strategy_type,
+            status: self.status,
+        });
+
+        Ok(())
+    }
+
+    pub fn assert_closed(&self) -> Result<()> {
+        require!(
+            self.status == VaultStrategyStatus::Closed,
+            TokenizedVaultsErrorCode::VaultStrategyConfigNotClosed
+        );
         Ok(())
     }
 
@@ -144,7 +282,97 @@ impl VaultStrategyConfig {
 
         Ok(())
     }
+
+    /// Per-vault state machine, shared by every investor instruction:
+    /// - Draft: only the creator can operate the vault
+    /// - Active: every operation is allowed
+    /// - Paused: only withdrawals and fee claims
+    /// - Closed: only the final redemption
+    pub fn assert_operation_allowed(
+        &self,
+        operation: VaultOperation,
+        actor: &Pubkey,
+    ) -> Result<()> {
+        match self.status {
+            VaultStrategyStatus::Draft => {
+                require_keys_eq!(
+                    *actor,
+                    self.creator,
+                    TokenizedVaultsErrorCode::VaultStrategyConfigCreatorOnly
+                );
+                Ok(())
+            }
+            VaultStrategyStatus::Active => Ok(()),
+            VaultStrategyStatus::Paused => {
+                require!(
+                    operation == VaultOperation::Withdraw || operation == VaultOperation::ClaimFees,
+                    TokenizedVaultsErrorCode::VaultOperationNotAllowed
+                );
+                Ok(())
+            }
+            VaultStrategyStatus::Closed => {
+                require!(
+                    operation == VaultOperation::Redeem,
+                    TokenizedVaultsErrorCode::VaultOperationNotAllowed
+                );
+                Ok(())
+            }
+            VaultStrategyStatus::Unknown => {
+                Err(TokenizedVaultsErrorCode::VaultStrategyConfigNotActive.into())
+            }
+        }
+    }
+}
+/// Emitted when the creator updates the keeper
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyConfigKeeperEvent {
+    pub creator: Pubkey,
+    pub keeper: Pubkey,
+}
+
+/// Emitted when the creator updates the maximum pool price deviation
+#[event]
+#[derive(Debug)]
+pub struct VaultStrategyConfigPriceDeviationEvent {
+    pub creator: Pubkey,
+    pub max_price_deviation: u32,
 }
+
+/// Emitted when an operation passes the pool price deviation check
+#[event]
+#[derive(Debug)]
+pub struct PoolPriceDeviationEvent {
+    pub vault_strategy_key: Pubkey,
+    pub sqrt_price_x64: u128,
+    pub deviation: u32,
+    pub max_price_deviation: u32,
+}
+
+/// Emitted once when an investor redeems every strategy of the vault
+#[event]
+#[derive(Debug)]
+pub struct VaultRedeemEvent {
+    pub investor: Pubkey,
+    pub vault_strategy_config_key: Pubkey,
+    pub percentage: u64,
+    pub shares: u64,
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub strategies: u8,
+}
+
+/// Emitted when the creator claims performance fees, once per mint
+#[event]
+#[derive(Debug)]
+pub struct PerformanceFeesClaimedEvent {
+    pub vault_strategy_config_key: Pubkey,
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
 /// Emitted when update status of VaultStrategyConfig
 #[event]
 #[derive(Debug)]
@@ -154,3 +382,182 @@ pub struct VaultStrategyConfigEvent {
     pub vault_strategy_type: VaultStrategyType,
     pub status: VaultStrategyStatus,
 }
+
+#[cfg(test)]
+mod assert_operation_allowed_tests {
+    use super::*;
+
+    const ALL_OPERATIONS: [VaultOperation; 4] = [
+        VaultOperation::Deposit,
+        VaultOperation::Withdraw,
+        VaultOperation::ClaimFees,
+        VaultOperation::Redeem,
+    ];
+
+    fn vault_with_status(creator: Pubkey, status: VaultStrategyStatus) -> VaultStrategyConfig {
+        VaultStrategyConfig {
+            creator,
+            status,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn draft_allows_only_the_creator() {
+        let creator = Pubkey::new_unique();
+        let investor = Pubkey::new_unique();
+        let vault = vault_with_status(creator, VaultStrategyStatus::Draft);
+
+        for operation in ALL_OPERATIONS {
+            assert!(vault.assert_operation_allowed(operation, &creator).is_ok());
+            assert!(vault
+                .assert_operation_allowed(operation, &investor)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn active_allows_everything() {
+        let creator = Pubkey::new_unique();
+        let investor = Pubkey::new_unique();
+        let vault = vault_with_status(creator, VaultStrategyStatus::Active);
+
+        for operation in ALL_OPERATIONS {
+            assert!(vault.assert_operation_allowed(operation, &investor).is_ok());
+        }
+    }
+
+    #[test]
+    fn paused_allows_only_withdrawals_and_fee_claims() {
+        let creator = Pubkey::new_unique();
+        let investor = Pubkey::new_unique();
+        let vault = vault_with_status(creator, VaultStrategyStatus::Paused);
+
+        for operation in ALL_OPERATIONS {
+            let expected =
+                operation == VaultOperation::Withdraw || operation == VaultOperation::ClaimFees;
+            assert_eq!(
+                vault.assert_operation_allowed(operation, &investor).is_ok(),
+                expected
+            );
+            assert_eq!(
+                vault.assert_operation_allowed(operation, &creator).is_ok(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn closed_allows_only_final_redemption() {
+        let creator = Pubkey::new_unique();
+        let investor = Pubkey::new_unique();
+        let vault = vault_with_status(creator, VaultStrategyStatus::Closed);
+
+        for operation in ALL_OPERATIONS {
+            let result = vault.assert_operation_allowed(operation, &investor);
+            assert_eq!(result.is_ok(), operation == VaultOperation::Redeem);
+        }
+    }
+
+    #[test]
+    fn unknown_allows_nothing() {
+        let creator = Pubkey::new_unique();
+        let vault = vault_with_status(creator, VaultStrategyStatus::Unknown);
+
+        for operation in ALL_OPERATIONS {
+            assert!(vault.assert_operation_allowed(operation, &creator).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod price_deviation_tests {
+    use super::*;
+
+    #[test]
+    fn initialize_enables_the_default_deviation_check() {
+        let mut vault = VaultStrategyConfig::default();
+        vault
+            .set_inner(
+                Pubkey::new_unique(),
+                0,
+                VaultStrategyType::Balanced,
+                VaultStrategyStatus::Draft,
+                "vault".to_string(),
+                255,
+            )
+            .unwrap();
+
+        assert_eq!(vault.max_price_deviation, DEFAULT_MAX_PRICE_DEVIATION);
+        assert!(vault.has_price_deviation_check());
+    }
+
+    #[test]
+    fn rejects_a_deviation_above_the_maximum() {
+        let mut vault = VaultStrategyConfig::default();
+        vault.set_max_price_deviation(20_000).unwrap();
+
+        let key = Pubkey::new_unique();
+        vault.check_pool_price_deviation(key, 0, 20_000).unwrap();
+        assert!(vault.check_pool_price_deviation(key, 0, 20_001).is_err());
+    }
+
+    #[test]
+    fn zero_disables_the_deviation_check() {
+        let mut vault = VaultStrategyConfig::default();
+        vault.set_max_price_deviation(0).unwrap();
+
+        assert!(!vault.has_price_deviation_check());
+        vault
+            .check_pool_price_deviation(Pubkey::new_unique(), 0, u32::MAX)
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_a_maximum_above_100_percent() {
+        let mut vault = VaultStrategyConfig::default();
+        vault.set_max_price_deviation(MAX_PERCENTAGE).unwrap();
+        assert!(vault.set_max_price_deviation(MAX_PERCENTAGE + 1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod migration_tests {
+    use super::*;
+
+    #[test]
+    fn a_grown_vault_keeps_its_data_and_gets_the_default_deviation() {
+        let mut vault = VaultStrategyConfig::default();
+        vault
+            .set_inner(
+                Pubkey::new_unique(),
+                1_000,
+                VaultStrategyType::Balanced,
+                VaultStrategyStatus::Active,
+                "vault".to_string(),
+                255,
+            )
+            .unwrap();
+        vault.set_keeper(Pubkey::new_unique()).unwrap();
+
+        let mut data = Vec::new();
+        vault.try_serialize(&mut data).unwrap();
+
+        // Layout before `keeper` and `max_price_deviation`, zero-extended by the realloc
+        data.truncate(data.len() - Pubkey::INIT_SPACE - u32::INIT_SPACE);
+        data.resize(
+            VaultStrategyConfig::DISCRIMINATOR.len() + VaultStrategyConfig::INIT_SPACE,
+            0,
+        );
+
+        let mut grown = VaultStrategyConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(grown.creator, vault.creator);
+        assert_eq!(grown.performance_fee, 1_000);
+        assert_eq!(grown.name, vault.name);
+        assert_eq!(grown.keeper, Pubkey::default());
+        assert!(!grown.has_price_deviation_check());
+
+        grown.migrate();
+        assert_eq!(grown.max_price_deviation, DEFAULT_MAX_PRICE_DEVIATION);
+    }
+}
@@ -32,3 +32,16 @@ pub enum VaultStrategyStatus {
     Paused,
     Closed,
 }
+
+/// Operations gated by the VaultStrategyConfig status
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VaultOperation {
+    /// invest_reserve, swap to ratio and add liquidity
+    Deposit,
+    /// remove liquidity
+    Withdraw,
+    /// claim of already accrued fees
+    ClaimFees,
+    /// final redemption of a closed vault
+    Redeem,
+}
@@ -1,20 +1,59 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{
-    token::{transfer_checked, Token, TransferChecked},
+    metadata::{
+        create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
+    },
+    token::{burn, mint_to, transfer_checked, Burn, MintTo, Token, TransferChecked},
     token_interface::{Mint, TokenAccount},
 };
 use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
-pub const MAXIMUM_PRICE_AGE: u64 = 600; // Ten minutes
+use crate::{OracleSource, VaultStrategyConfig, MAX_PERCENTAGE, MAX_PERFORMANCE_FEE};
+
+/// Freshness and confidence an oracle price must meet to be used
+#[derive(Debug, Clone, Copy)]
+pub struct PriceBounds {
+    /// Maximum age of the price in seconds
+    pub max_age: u64,
+    /// Maximum confidence interval relative to the price, in BPS scale (100% = 1_000_000)
+    pub max_conf: u32,
+}
 
 #[derive(Debug, Clone)]
 pub struct TokenPriceInfo {
     pub price: i64,
     pub expo: i32,
     pub conf: u64,
+    pub publish_time: i64,
+    /// Oracle the price was read from
+    pub source: OracleSource,
 }
 
 impl TokenPriceInfo {
+    /// Rejects a price that is not positive, older than `bounds.max_age`
+    /// or with a confidence interval wider than `bounds.max_conf` of the price
+    pub fn check_bounds(&self, bounds: &PriceBounds, now: i64) -> Result<()> {
+        require!(
+            self.price > 0,
+            crate::error::TokenizedVaultsErrorCode::InvalidPrice
+        );
+
+        let max_age = i64::try_from(bounds.max_age).unwrap_or(i64::MAX);
+        require!(
+            now.saturating_sub(self.publish_time) <= max_age,
+            crate::error::TokenizedVaultsErrorCode::PriceTooOld
+        );
+
+        // conf / price <= max_conf / MAX_PERCENTAGE
+        require!(
+            (self.conf as u128) * (MAX_PERCENTAGE as u128)
+                <= (self.price as u128) * (bounds.max_conf as u128),
+            crate::error::TokenizedVaultsErrorCode::PriceConfidenceTooWide
+        );
+        Ok(())
+    }
+
     /// Convert raw price to a normalized price with given decimal places
     pub fn get_normalized_price(&self, target_decimals: u8) -> Result<u64> {
         if self.price < 0 {
@@ -34,25 +73,58 @@ impl TokenPriceInfo {
     }
 }
 
-/// Extract price from Pyth price update account
+/// Whether `error` rejects a price read from its oracle account for its age or confidence,
+/// as opposed to an oracle account that is missing, mismatched or unreadable
+pub fn is_price_out_of_bounds(error: &Error) -> bool {
+    [
+        crate::error::TokenizedVaultsErrorCode::PriceTooOld,
+        crate::error::TokenizedVaultsErrorCode::PriceConfidenceTooWide,
+    ]
+    .into_iter()
+    .any(|code| *error == code.into())
+}
+
+/// Extract price from Pyth price update account, within the given bounds
 pub fn get_price_from_pyth_update<'info>(
     price_update: &Account<'info, PriceUpdateV2>,
     feed_id: &str,
+    bounds: &PriceBounds,
+) -> Result<TokenPriceInfo> {
+    get_price_from_pyth_feed_id(price_update, &get_feed_id_from_hex(feed_id)?, bounds)
+}
+
+/// Same as `get_price_from_pyth_update` with a raw feed id, see `OracleFeed::price_info`
+pub fn get_price_from_pyth_feed_id<'info>(
+    price_update: &Account<'info, PriceUpdateV2>,
+    feed_id: &[u8; 32],
+    bounds: &PriceBounds,
 ) -> Result<TokenPriceInfo> {
+    let price_info = get_unchecked_price_from_pyth_feed_id(price_update, feed_id)?;
+    price_info.check_bounds(bounds, Clock::get()?.unix_timestamp)?;
+
+    Ok(price_info)
+}
+
+/// Price of a Pyth feed as published, the caller checks the bounds
+pub fn get_unchecked_price_from_pyth_feed_id<'info>(
+    price_update: &Account<'info, PriceUpdateV2>,
+    feed_id: &[u8; 32],
+) -> Result<TokenPriceInfo> {
+    require!(
+        *feed_id != [0u8; 32],
+        crate::error::TokenizedVaultsErrorCode::InvalidPythFeedId
+    );
+
     let price_feed = price_update
-        .get_price_unchecked(&get_feed_id_from_hex(feed_id)?)
+        .get_price_unchecked(feed_id)
         .map_err(|_| error!(crate::error::TokenizedVaultsErrorCode::PythPriceFeedNotFound))?;
-    // .get_price_no_older_than(
-    //     &Clock::get()?,
-    //     MAXIMUM_PRICE_AGE,
-    //     &get_feed_id_from_hex(feed_id)?,
-    // )?;
-    // .map_err(|_| error!(crate::error::TokenizedVaultsErrorCode::PythPriceFeedNotFound))?;
 
     Ok(TokenPriceInfo {
         price: price_feed.price,
         expo: price_feed.exponent,
         conf: price_feed.conf,
+        publish_time: price_feed.publish_time,
+        source: OracleSource::Pyth,
     })
 }
 
@@ -114,6 +186,185 @@ pub fn transfer_token<'info>(
     }
 }
 
+/// Mint vault shares, the mint authority is the vault strategy config PDA
+pub fn mint_shares<'info>(
+    share_mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+    authority: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mint_to_accounts = MintTo {
+        mint: share_mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        mint_to_accounts,
+        signer_seeds,
+    );
+    mint_to(cpi_context, amount)
+}
+
+/// Accounts of the Metaplex metadata of a strategy share mint,
+/// the vault strategy config signs as the mint and update authority
+pub struct ShareMintMetadataCpi<'a, 'info> {
+    pub token_metadata_program: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub share_mint: AccountInfo<'info>,
+    pub vault_strategy_config: &'a Account<'info, VaultStrategyConfig>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+impl ShareMintMetadataCpi<'_, '_> {
+    /// Creates the metadata of the share mint, it stays mutable by the vault strategy config
+    pub fn create(&self, name: String, symbol: String, uri: String) -> Result<()> {
+        let vault_strategy_config_seeds = self.vault_strategy_config.signer_seeds();
+        let signer_seeds = &[&vault_strategy_config_seeds[..]];
+
+        let cpi_accounts = CreateMetadataAccountsV3 {
+            metadata: self.metadata.clone(),
+            mint: self.share_mint.clone(),
+            mint_authority: self.vault_strategy_config.to_account_info(),
+            payer: self.payer.clone(),
+            update_authority: self.vault_strategy_config.to_account_info(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        create_metadata_accounts_v3(
+            cpi_context,
+            DataV2 {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            true, // is_mutable
+            true, // update_authority_is_signer
+            None,
+        )
+    }
+}
+
+/// Burn vault shares from the owner account, the owner signs the transaction
+pub fn burn_shares<'info>(
+    share_mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+    authority: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let burn_accounts = Burn {
+        mint: share_mint.to_account_info(),
+        from: from.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(token_program.to_account_info(), burn_accounts);
+    burn(cpi_context, amount)
+}
+
+/// Grows a program account created with an older, smaller layout to `space` bytes.
+/// The payer funds the rent of the new bytes, which are zeroed
+#[allow(deprecated)]
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+/// Moves the lamports of a program account above its rent-exempt minimum to the recipient,
+/// e.g. the rent raydium refunds to the vault strategy config when a position is closed
+pub fn reclaim_rent<'info>(
+    account: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+) -> Result<u64> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(account.data_len());
+    let reclaimed_lamports = account.lamports().saturating_sub(rent_exempt_lamports);
+
+    account.sub_lamports(reclaimed_lamports)?;
+    recipient.add_lamports(reclaimed_lamports)?;
+    Ok(reclaimed_lamports)
+}
+
+/// Split collected fees between the protocol, the vault creator and the investor.
+/// The protocol cut is taken first from the gross amount, then the creator
+/// performance fee is taken from what is left.
+/// Both fees are expressed in BPS scale (100% = 1_000_000).
+/// returns (investor_fees, creator_fees, protocol_fees)
+pub fn split_fees_amount(
+    fees_amount: u64,
+    protocol_fees: u32,
+    performance_fee: u32,
+) -> Result<(u64, u64, u64)> {
+    let protocol_fees_amount = (fees_amount as u128)
+        .checked_mul(protocol_fees as u128)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?
+        .checked_div(MAX_PERCENTAGE as u128)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?
+        as u64;
+
+    let fees_after_protocol = fees_amount
+        .checked_sub(protocol_fees_amount)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?;
+
+    let creator_fees_amount = (fees_after_protocol as u128)
+        .checked_mul(performance_fee as u128)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?
+        .checked_div(MAX_PERFORMANCE_FEE as u128)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?
+        as u64;
+
+    let investor_fees_amount = fees_after_protocol
+        .checked_sub(creator_fees_amount)
+        .ok_or(error!(crate::error::TokenizedVaultsErrorCode::MathOverflow))?;
+
+    Ok((
+        investor_fees_amount,
+        creator_fees_amount,
+        protocol_fees_amount,
+    ))
+}
+
 /// Normalize token amount to target decimal places
 fn normalize_token_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
     if from_decimals == to_decimals {
@@ -140,3 +391,99 @@ fn normalize_token_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Re
         Ok(amount.checked_div(scale).unwrap_or(0))
     }
 }
+
+#[cfg(test)]
+mod split_fees_amount_tests {
+    use super::*;
+
+    #[test]
+    fn split_fees_amount_takes_protocol_cut_first() {
+        // 10% protocol fees, 20% performance fee
+        let (investor, creator, protocol) = split_fees_amount(1_000_000, 100_000, 200_000).unwrap();
+        assert_eq!(protocol, 100_000);
+        assert_eq!(creator, 180_000);
+        assert_eq!(investor, 720_000);
+    }
+
+    #[test]
+    fn split_fees_amount_never_exceeds_fees() {
+        for fees in [0u64, 1, 7, 999, 123_456_789, u64::MAX] {
+            let (investor, creator, protocol) =
+                split_fees_amount(fees, 100_000, 1_000_000).unwrap();
+            assert_eq!(
+                investor as u128 + creator as u128 + protocol as u128,
+                fees as u128
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod price_bounds_tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn price_info(price: i64, conf: u64, publish_time: i64) -> TokenPriceInfo {
+        TokenPriceInfo {
+            price,
+            expo: -8,
+            conf,
+            publish_time,
+            source: OracleSource::Pyth,
+        }
+    }
+
+    fn bounds() -> PriceBounds {
+        PriceBounds {
+            max_age: 600,
+            max_conf: 20_000, // 2%
+        }
+    }
+
+    #[test]
+    fn accepts_fresh_and_tight_prices() {
+        price_info(100_000_000, 2_000_000, NOW - 600)
+            .check_bounds(&bounds(), NOW)
+            .unwrap();
+        // published after the clock, age is negative
+        price_info(100_000_000, 0, NOW + 5)
+            .check_bounds(&bounds(), NOW)
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_stale_prices() {
+        let err = price_info(100_000_000, 0, NOW - 601)
+            .check_bounds(&bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            crate::error::TokenizedVaultsErrorCode::PriceTooOld.into()
+        );
+    }
+
+    #[test]
+    fn rejects_wide_confidence() {
+        let err = price_info(100_000_000, 2_000_001, NOW)
+            .check_bounds(&bounds(), NOW)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            crate::error::TokenizedVaultsErrorCode::PriceConfidenceTooWide.into()
+        );
+    }
+
+    #[test]
+    fn rejects_non_positive_prices() {
+        for price in [0, -1] {
+            let err = price_info(price, 0, NOW)
+                .check_bounds(&bounds(), NOW)
+                .unwrap_err();
+            assert_eq!(
+                err,
+                crate::error::TokenizedVaultsErrorCode::InvalidPrice.into()
+            );
+        }
+    }
+}
@@ -9,6 +9,7 @@ import {
 import { expect } from "chai";
 import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
 import { airdrop } from "../../../app/utils";
+import { getTokens } from "../../../app/web/tokens";
 
 setupDotEnv();
 
@@ -96,5 +97,123 @@ describe("init-protocol-config", () => {
     console.log("Status:", protocolConfigAccount.status);
     expect(protocolConfigAccount.status.active).to.not.be.undefined;
     expect(protocolConfigAccount.bump).to.equal(bump);
+    expect(protocolConfigAccount.maxPriceAge.toNumber()).to.equal(600);
+    expect(protocolConfigAccount.maxPriceConf).to.equal(20_000);
+  });
+
+  it("Relaxes the Pyth price age for the localnet fixtures", async function () {
+    // The Pyth fixtures are mainnet dumps, their publish time is older than
+    // the default 10 minutes maximum age on a localnet clock
+    if (process.env.ENV !== "localnet") {
+      this.skip();
+    }
+
+    const maxPriceAge = new anchor.BN(5 * 365 * 24 * 60 * 60); // 5 years
+    const maxPriceConf = 2 * 10_000; // 2%
+
+    const tx = await program.methods
+      .setPriceBounds(maxPriceAge, maxPriceConf)
+      .accounts({
+        adminAuthority: creator.publicKey,
+      })
+      .signers([creator])
+      .transaction();
+    await sendAndConfirmTransaction(connection as any, tx as any, [creator], {
+      commitment: "finalized",
+    });
+
+    const protocolConfigAccount =
+      await program.account.protocolConfig.fetch(protocolConfigPda);
+    expect(protocolConfigAccount.maxPriceAge.toString()).to.equal(
+      maxPriceAge.toString()
+    );
+    expect(protocolConfigAccount.maxPriceConf).to.equal(maxPriceConf);
+  });
+
+  it("Registers the oracle feeds of the strategy tokens", async () => {
+    // Token 0 is priced with the SOL/USD feed, token 1 with the USDC/USD feed
+    const PYTH_SOL_USD_FEED_ID =
+      "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+    const PYTH_USDC_USD_FEED_ID =
+      "eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a";
+    const PYTH_EXPONENT = -8;
+
+    const { token0, token1 } = getTokens();
+    const [mint0, mint1] = [token0, token1].sort((a, b) =>
+      Buffer.compare(a.toBuffer(), b.toBuffer())
+    );
+
+    for (const [mint, feedId] of [
+      [mint0, PYTH_SOL_USD_FEED_ID],
+      [mint1, PYTH_USDC_USD_FEED_ID],
+    ] as [PublicKey, string][]) {
+      // 0 max age and confidence keep the protocol price bounds
+      const tx = await program.methods
+        .setOracleFeed(
+          Array.from(Buffer.from(feedId, "hex")),
+          PYTH_EXPONENT,
+          new anchor.BN(0),
+          0
+        )
+        .accounts({
+          adminAuthority: creator.publicKey,
+          mint,
+        })
+        .signers([creator])
+        .transaction();
+      await sendAndConfirmTransaction(connection as any, tx as any, [creator], {
+        commitment: "finalized",
+      });
+
+      const [oracleFeedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle_feed:"), mint.toBuffer()],
+        programId
+      );
+      const oracleFeedAccount =
+        await program.account.oracleFeed.fetch(oracleFeedPda);
+      expect(oracleFeedAccount.mint.toBase58()).to.equal(mint.toBase58());
+      expect(Buffer.from(oracleFeedAccount.feedId).toString("hex")).to.equal(
+        feedId
+      );
+      expect(oracleFeedAccount.exponent).to.equal(PYTH_EXPONENT);
+      expect(oracleFeedAccount.sources[0]).to.deep.equal({ pyth: {} });
+    }
+  });
+
+  it("Sets Pyth as the only price source of the strategy tokens", async () => {
+    // Fallbacks (switchboardOnDemand, raydiumTwap) need their feed or pool,
+    // the localnet only has the Pyth fixtures
+    const { token0, token1 } = getTokens();
+
+    for (const mint of [token0, token1]) {
+      const tx = await program.methods
+        .setOracleSources(
+          [{ pyth: {} }],
+          PublicKey.default,
+          PublicKey.default,
+          0
+        )
+        .accounts({
+          adminAuthority: creator.publicKey,
+          mint,
+        })
+        .signers([creator])
+        .transaction();
+      await sendAndConfirmTransaction(connection as any, tx as any, [creator], {
+        commitment: "finalized",
+      });
+
+      const [oracleFeedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle_feed:"), mint.toBuffer()],
+        programId
+      );
+      const oracleFeedAccount =
+        await program.account.oracleFeed.fetch(oracleFeedPda);
+      expect(oracleFeedAccount.sources).to.deep.equal([
+        { pyth: {} },
+        { none: {} },
+        { none: {} },
+      ]);
+    }
   });
 });
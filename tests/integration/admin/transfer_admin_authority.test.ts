@@ -0,0 +1,152 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { airdrop } from "../../../app/utils";
+
+setupDotEnv();
+
+describe("transfer-admin-authority", () => {
+  const admin = _creatorWallet;
+  const newAdmin = Keypair.generate();
+
+  const provider = new AnchorProvider(
+    connection as any,
+    new Wallet(admin as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+  const programId = program.programId;
+
+  const [protocolConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config:")],
+    programId
+  );
+
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    console.log("Admin address:", admin.publicKey.toString());
+    console.log("New admin address:", newAdmin.publicKey.toString());
+
+    await airdrop(connection as any, newAdmin.publicKey, LAMPORTS_PER_SOL);
+  });
+
+  it("Cancel an admin proposal", async () => {
+    await program.methods
+      .proposeAdmin(newAdmin.publicKey)
+      .accounts({
+        adminAuthority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc({ commitment: "confirmed" });
+
+    let protocolConfigAccount =
+      await program.account.protocolConfig.fetch(protocolConfigPda);
+    expect(protocolConfigAccount.pendingAdmin.toBase58()).to.equal(
+      newAdmin.publicKey.toBase58()
+    );
+
+    await program.methods
+      .cancelAdminProposal()
+      .accounts({
+        adminAuthority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc({ commitment: "confirmed" });
+
+    protocolConfigAccount =
+      await program.account.protocolConfig.fetch(protocolConfigPda);
+    expect(protocolConfigAccount.pendingAdmin.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+    expect(protocolConfigAccount.adminAuthority.toBase58()).to.equal(
+      admin.publicKey.toBase58()
+    );
+  });
+
+  it("Fail to accept admin without a proposal", async () => {
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          pendingAdmin: newAdmin.publicKey,
+        })
+        .signers([newAdmin])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed due NoPendingAdmin");
+    } catch (error) {
+      expect(error.message).to.include("NoPendingAdmin");
+    }
+  });
+
+  it("Transfer admin authority and transfer it back", async () => {
+    await program.methods
+      .proposeAdmin(newAdmin.publicKey)
+      .accounts({
+        adminAuthority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc({ commitment: "confirmed" });
+
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc({ commitment: "confirmed" });
+
+    let protocolConfigAccount =
+      await program.account.protocolConfig.fetch(protocolConfigPda);
+    expect(protocolConfigAccount.adminAuthority.toBase58()).to.equal(
+      newAdmin.publicKey.toBase58()
+    );
+    expect(protocolConfigAccount.pendingAdmin.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+
+    // The previous admin can no longer propose
+    try {
+      await program.methods
+        .proposeAdmin(admin.publicKey)
+        .accounts({
+          adminAuthority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc({ commitment: "confirmed" });
+      expect.fail("The transaction should have failed due Unauthorized");
+    } catch (error) {
+      expect(error.message).to.include("Unauthorized");
+    }
+
+    // Restore the original admin for the next test suites
+    await program.methods
+      .proposeAdmin(admin.publicKey)
+      .accounts({
+        adminAuthority: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc({ commitment: "confirmed" });
+
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc({ commitment: "confirmed" });
+
+    protocolConfigAccount =
+      await program.account.protocolConfig.fetch(protocolConfigPda);
+    expect(protocolConfigAccount.adminAuthority.toBase58()).to.equal(
+      admin.publicKey.toBase58()
+    );
+  });
+});
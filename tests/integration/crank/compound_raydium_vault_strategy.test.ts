@@ -0,0 +1,177 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { PositionInfoLayout } from "@raydium-io/raydium-sdk-v2";
+import { expect } from "chai";
+import {
+  _creatorWallet,
+  _masterWallet,
+  connection,
+  initSdk,
+  setupDotEnv,
+} from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { swap } from "../../../app/swap";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+  swapAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("compound-raydium-vault-strategy", () => {
+  // The compound is permissionless, the vault creator pauses the vault
+  const creator = _creatorWallet;
+  const payer = _masterWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(payer as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda, vaultStrategyConfigPda } = protocolPDAs({
+    strategyCreator: creator.publicKey,
+    investor: creator.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The strategy holds a position, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(creator.publicKey, payer.publicKey);
+
+    // The swaps are signed by the master wallet, load its sdk first
+    const raydium = await initSdk({ owner: payer, loadToken: true });
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: creator.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+
+    // Swaps both ways in the strategy pool to earn fees to the position
+    const poolId = strategy.positionAccounts.raydiumPoolState.toBase58();
+    await swap(
+      raydium,
+      poolId,
+      strategy.mint0.toBase58(),
+      new anchor.BN(LAMPORTS_PER_SOL)
+    );
+    await swap(
+      raydium,
+      poolId,
+      strategy.mint1.toBase58(),
+      new anchor.BN(LAMPORTS_PER_SOL)
+    );
+  });
+
+  const compoundTx = async () => {
+    const { raydium, mint0, positionAccounts, raydiumAccounts } = strategy;
+
+    // The fees swap to the position ratio stays in the current tick array
+    const swap0 = await swapAccounts({
+      raydium,
+      poolState: positionAccounts.raydiumPoolState,
+      inputMint: mint0,
+      amountIn: new BN(1000),
+    });
+
+    const compoundIx = await program.methods
+      .compoundRaydiumVaultStrategy(strategyId)
+      .accountsPartial({
+        payer: payer.publicKey,
+        ...positionAccounts,
+        raydiumAmmConfig: swap0.ammConfig,
+        raydiumObservationState: swap0.observationState,
+      })
+      .remainingAccounts([
+        ...accountMetas([raydiumAccounts.bitmapExtMint0WithMint1]),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas(swap0.tickArrays),
+      ])
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: payer.publicKey,
+      instructions: [compoundIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  const positionLiquidity = async () => {
+    const positionAccountInfo = await connection.getAccountInfo(
+      strategy.positionAccounts.raydiumPersonalPosition
+    );
+    return PositionInfoLayout.decode(positionAccountInfo!.data).liquidity;
+  };
+
+  it("Fail to compound a paused vault", async () => {
+    await program.methods
+      .pauseVault()
+      .accountsPartial({
+        creator: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    try {
+      const tx = await compoundTx();
+
+      await expectTxError(
+        () => sendTx(tx, [payer]),
+        "VaultStrategyConfigNotActive"
+      );
+    } finally {
+      await program.methods
+        .unpauseVault()
+        .accountsPartial({
+          creator: creator.publicKey,
+          vaultStrategyConfig: vaultStrategyConfigPda,
+        })
+        .signers([creator])
+        .rpc();
+    }
+  });
+
+  it("Compound Raydium Vault Strategy", async () => {
+    const liquidityBefore = await positionLiquidity();
+    const vaultStrategyAccountBefore =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+
+    const tx = await compoundTx();
+    await sendTx(tx, [payer]);
+
+    // The fees are added back to the position, the shares are untouched
+    const liquidityAfter = await positionLiquidity();
+    expect(liquidityAfter.gt(liquidityBefore)).to.be.true;
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+      vaultStrategyAccountBefore.totalShares.toString()
+    );
+  });
+});
@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount } from "../helpers";
+
+setupDotEnv();
+
+describe("claim-performance-fees", () => {
+  const creator = _creatorWallet;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(creator as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const {
+    mint0,
+    mint1,
+    vaultStrategyConfigPda,
+    vaultStrategyCfgMint0PerfFeesEscrow,
+    vaultStrategyCfgMint1PerfFeesEscrow,
+  } = protocolPDAs({
+    strategyCreator: creator.publicKey,
+    investor: creator.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId: 1,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  // The performance fees are earned with the position fees, see claim_investor_fees
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(creator.publicKey);
+  });
+
+  // The fees are paid to the creator token accounts
+  const claimPerformanceFees = (amount0: BN, amount1: BN) =>
+    program.methods
+      .claimPerformanceFees(amount0, amount1)
+      .accountsPartial({
+        creator: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+        destinationMint0Account: null,
+        destinationMint1Account: null,
+        vault0Mint: mint0,
+        vault1Mint: mint1,
+      })
+      .signers([creator])
+      .rpc();
+
+  it("Fail to claim no performance fees", async () => {
+    await expectTxError(
+      () => claimPerformanceFees(new BN(0), new BN(0)),
+      "InvalidAmount"
+    );
+  });
+
+  it("Fail to claim more than the performance fees", async () => {
+    const perfFees0 = await getTokenAmount(vaultStrategyCfgMint0PerfFeesEscrow);
+
+    await expectTxError(
+      () => claimPerformanceFees(perfFees0.addn(1), new BN(0)),
+      "InsufficientFunds"
+    );
+  });
+
+  it("Claim Performance Fees", async () => {
+    const creatorMint0Account = getAssociatedTokenAddressSync(
+      mint0,
+      creator.publicKey
+    );
+    const creatorMint1Account = getAssociatedTokenAddressSync(
+      mint1,
+      creator.publicKey
+    );
+    const perfFees0 = await getTokenAmount(vaultStrategyCfgMint0PerfFeesEscrow);
+    const perfFees1 = await getTokenAmount(vaultStrategyCfgMint1PerfFeesEscrow);
+    const balance0Before = await getTokenAmount(creatorMint0Account);
+    const balance1Before = await getTokenAmount(creatorMint1Account);
+
+    const txSignature = await claimPerformanceFees(perfFees0, perfFees1);
+    console.log("\n Transaction signature:", txSignature);
+
+    // The performance fees escrows are emptied to the creator
+    const perfFees0After = await getTokenAmount(
+      vaultStrategyCfgMint0PerfFeesEscrow
+    );
+    const perfFees1After = await getTokenAmount(
+      vaultStrategyCfgMint1PerfFeesEscrow
+    );
+    expect(perfFees0After.toNumber()).to.equal(0);
+    expect(perfFees1After.toNumber()).to.equal(0);
+
+    const balance0After = await getTokenAmount(creatorMint0Account);
+    const balance1After = await getTokenAmount(creatorMint1Account);
+    expect(balance0After.sub(balance0Before).toString()).to.equal(
+      perfFees0.toString()
+    );
+    expect(balance1After.sub(balance1Before).toString()).to.equal(
+      perfFees1.toString()
+    );
+  });
+});
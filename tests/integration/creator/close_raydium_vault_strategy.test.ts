@@ -0,0 +1,219 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+// Closing the vault is final, these tests run after every other one
+describe("close-raydium-vault-strategy", () => {
+  // The vault creator is also the only investor of the vault
+  const creator = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(creator as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const {
+    vaultStrategyPda,
+    vaultStrategyConfigPda,
+    vaultStrategyClosed0Escrow,
+    vaultStrategyClosed1Escrow,
+  } = protocolPDAs({
+    strategyCreator: creator.publicKey,
+    investor: creator.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(creator.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: creator.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  const closeVault = () =>
+    program.methods
+      .closeVault()
+      .accountsPartial({
+        authority: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+      })
+      .signers([creator])
+      .rpc();
+
+  // The position liquidity and fees are moved to the closed escrows
+  const closeRaydiumVaultStrategyTx = async () => {
+    const closeRaydiumVaultStrategyIx = await program.methods
+      .closeRaydiumVaultStrategy(strategyId, new BN(0), new BN(0))
+      .accountsPartial({
+        authority: creator.publicKey,
+        ...strategy.positionAccounts,
+      })
+      .remainingAccounts(
+        accountMetas([strategy.raydiumAccounts.bitmapExtMint0WithMint1])
+      )
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: creator.publicKey,
+      instructions: [closeRaydiumVaultStrategyIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  const closeRaydiumPosition = () =>
+    program.methods
+      .closeRaydiumPosition(strategyId)
+      .accountsPartial({
+        creator: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+        vaultStrategy: vaultStrategyPda,
+        raydiumPositionNftMint: strategy.vaultStrategyAccount.dexNftMint,
+        raydiumPositionNftAccount: strategy.positionNftAccount,
+        raydiumPersonalPosition:
+          strategy.positionAccounts.raydiumPersonalPosition,
+      })
+      .signers([creator])
+      .rpc();
+
+  const redeemClosedVaultStrategy = () =>
+    program.methods
+      .redeemClosedVaultStrategy(strategyId)
+      .accountsPartial({
+        investor: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+        vault0Mint: strategy.mint0,
+        vault1Mint: strategy.mint1,
+      })
+      .signers([creator])
+      .rpc();
+
+  it("Fail to close the strategy of an open vault", async () => {
+    const tx = await closeRaydiumVaultStrategyTx();
+
+    await expectTxError(
+      () => sendTx(tx, [creator]),
+      "VaultStrategyConfigNotClosed"
+    );
+  });
+
+  it("Close Vault", async () => {
+    const txSignature = await closeVault();
+    console.log("\n Transaction signature:", txSignature);
+
+    const vaultStrategyConfigAccount =
+      await program.account.vaultStrategyConfig.fetch(vaultStrategyConfigPda);
+    expect(vaultStrategyConfigAccount.status).to.deep.equal({ closed: {} });
+  });
+
+  it("Fail to close an already closed vault", async () => {
+    await expectTxError(() => closeVault(), "VaultStrategyConfigAlreadyClosed");
+  });
+
+  it("Fail to close the position of an open strategy", async () => {
+    await expectTxError(() => closeRaydiumPosition(), "VaultStrategyNotClosed");
+  });
+
+  it("Close Raydium Vault Strategy", async () => {
+    const tx = await closeRaydiumVaultStrategyTx();
+    await sendTx(tx, [creator]);
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.isClosed).to.be.true;
+
+    // The tokens of the position wait in the closed escrows for the investors
+    const closed0 = await getTokenAmount(vaultStrategyClosed0Escrow);
+    const closed1 = await getTokenAmount(vaultStrategyClosed1Escrow);
+    expect(closed0.gtn(0) || closed1.gtn(0)).to.be.true;
+  });
+
+  it("Fail to close the position with outstanding shares", async () => {
+    await expectTxError(
+      () => closeRaydiumPosition(),
+      "VaultStrategyHasOutstandingShares"
+    );
+  });
+
+  it("Redeem Closed Vault Strategy", async () => {
+    const investorMint0Account = getAssociatedTokenAddressSync(
+      strategy.mint0,
+      creator.publicKey
+    );
+    const investorMint1Account = getAssociatedTokenAddressSync(
+      strategy.mint1,
+      creator.publicKey
+    );
+    const balance0Before = await getTokenAmount(investorMint0Account);
+    const balance1Before = await getTokenAmount(investorMint1Account);
+
+    const txSignature = await redeemClosedVaultStrategy();
+    console.log("\n Transaction signature:", txSignature);
+
+    // Every share is burned against the closed escrows
+    const shares = await getTokenAmount(strategy.investorShareAccount);
+    expect(shares.toNumber()).to.equal(0);
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.totalShares.toNumber()).to.equal(0);
+
+    const balance0After = await getTokenAmount(investorMint0Account);
+    const balance1After = await getTokenAmount(investorMint1Account);
+    expect(
+      balance0After.gt(balance0Before) || balance1After.gt(balance1Before)
+    ).to.be.true;
+  });
+
+  it("Fail to redeem without shares", async () => {
+    await expectTxError(
+      () => redeemClosedVaultStrategy(),
+      "InsufficientShares"
+    );
+  });
+
+  it("Close Raydium Position", async () => {
+    const txSignature = await closeRaydiumPosition();
+    console.log("\n Transaction signature:", txSignature);
+
+    // The emptied position is closed and its rent reclaimed
+    const personalPosition = await connection.getAccountInfo(
+      strategy.positionAccounts.raydiumPersonalPosition
+    );
+    expect(personalPosition).to.be.null;
+  });
+});
@@ -3,6 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
+  getAccount,
   getAssociatedTokenAddressSync,
   TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -233,6 +234,16 @@ describe("create-raydium-vault-strategy", () => {
       expect(investorStrategyPositionAccount.assets.toNumber()).to.equal(
         182061723
       );
+
+      // the share mint is created with the strategy and holds the creator shares
+      const creatorShareAccount = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(
+          vaultStrategyAccount.shareMint,
+          creator.publicKey
+        )
+      );
+      expect(creatorShareAccount.amount.toString()).to.equal("182061723");
     } catch (error) {
       console.error("Error creating vault strategy:", error);
       throw error;
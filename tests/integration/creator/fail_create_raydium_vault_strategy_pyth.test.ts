@@ -0,0 +1,87 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { airdrop } from "../../../app/utils";
+import { confirmTransaction } from "@solana-developers/helpers";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { createRaydiumStrategyTx } from "../../../app/web/creator/create-raydium-strategy";
+
+setupDotEnv();
+
+// Run on localnet after `create_raydium_vault_strategy`, while the vault is still a draft.
+// The fixtures are loaded by `make start-test-validator-from-dump-mainnet`
+describe("fail-create-raydium-vault-strategy-pyth", () => {
+  const creator = _creatorWallet;
+
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(creator as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+
+  // SOL/USD price update published at the unix epoch
+  const PYTH_SOL_USD_STALE_FEED_ACCOUNT = new PublicKey(
+    "7UgyS8X8rYu1jBrgwxu6n692iUqwenEQ36eQ6fQWybmN"
+  );
+  // SOL/USD price update with a confidence interval of 10% of the price
+  const PYTH_SOL_USD_WIDE_CONF_FEED_ACCOUNT = new PublicKey(
+    "pEXHgX79jUQqwHLTCWDzsFwwgfjcM9rQ9uX92eSRMyC"
+  );
+
+  const createStrategy = async (pythToken0PriceUpdate: PublicKey) => {
+    const { signers, tx } = await createRaydiumStrategyTx({
+      provider,
+      creator: creator.publicKey,
+      strategyId: 2,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      percentage: 100 * 10_000,
+      tickLower: -194221,
+      tickUpper: 7940,
+      amount0Max: "1000000000",
+      amount1Max: "3051827671",
+      pythToken0PriceUpdate,
+    });
+    tx.sign([creator, ...signers]);
+
+    const txSignature = await connection.sendTransaction(tx as any, {
+      skipPreflight: false,
+      preflightCommitment: "confirmed",
+    });
+    await confirmTransaction(connection as any, txSignature, "confirmed");
+  };
+
+  before(async () => {
+    const creatorBalance = await connection.getBalance(creator.publicKey);
+    if (creatorBalance < LAMPORTS_PER_SOL) {
+      await airdrop(
+        connection as any,
+        creator.publicKey,
+        200 * LAMPORTS_PER_SOL
+      );
+    }
+  });
+
+  it("Fails with a stale Pyth price", async () => {
+    try {
+      await createStrategy(PYTH_SOL_USD_STALE_FEED_ACCOUNT);
+      expect.fail("The transaction should have failed due PriceTooOld");
+    } catch (error) {
+      expect(error.message).to.include("PriceTooOld");
+    }
+  });
+
+  it("Fails with a wide Pyth confidence interval", async () => {
+    try {
+      await createStrategy(PYTH_SOL_USD_WIDE_CONF_FEED_ACCOUNT);
+      expect.fail(
+        "The transaction should have failed due PriceConfidenceTooWide"
+      );
+    } catch (error) {
+      expect(error.message).to.include("PriceConfidenceTooWide");
+    }
+  });
+});
@@ -105,4 +105,32 @@ describe("init-vault-strategy-config", () => {
     expect(vaultStrategyConfigAccount.vaultStrategyType.conservative).to.not.be
       .undefined;
   });
+
+  it("Disables the pool price deviation check for the localnet pools", async function () {
+    // The localnet pools are created at price 1, far from the mainnet Pyth
+    // prices of the fixtures, the default 2% deviation would reject every deposit
+    const vaultStrategyConfigAccount =
+      await program.account.vaultStrategyConfig.fetch(vaultStrategyConfigPda);
+    expect(vaultStrategyConfigAccount.maxPriceDeviation).to.equal(20_000);
+
+    if (process.env.ENV !== "localnet") {
+      this.skip();
+    }
+
+    const tx = await program.methods
+      .setMaxPriceDeviation(0)
+      .accounts({
+        creator: creator.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+      })
+      .signers([creator])
+      .transaction();
+    await sendAndConfirmTransaction(connection as any, tx as any, [creator], {
+      commitment: "confirmed",
+    });
+
+    const updatedVaultStrategyConfigAccount =
+      await program.account.vaultStrategyConfig.fetch(vaultStrategyConfigPda);
+    expect(updatedVaultStrategyConfigAccount.maxPriceDeviation).to.equal(0);
+  });
 });
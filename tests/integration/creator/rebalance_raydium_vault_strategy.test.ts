@@ -0,0 +1,212 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  _creatorWallet,
+  _masterWallet,
+  connection,
+  initSdk,
+  setupDotEnv,
+} from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { raydiumPDAs } from "../../../app/raydium-helpers";
+import { swap } from "../../../app/swap";
+import { sleep } from "../../../app/utils";
+import {
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, sendTx } from "../helpers";
+
+setupDotEnv();
+
+// STRATEGY_POOL_TWAP_WINDOW of the program, in seconds
+const STRATEGY_POOL_TWAP_WINDOW = 300;
+
+// Width of the new position on each side of the current tick, in tick spacings
+const NEW_POSITION_HALF_WIDTH = 100;
+
+describe("rebalance-raydium-vault-strategy", () => {
+  const creator = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(creator as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda } = protocolPDAs({
+    strategyCreator: creator.publicKey,
+    investor: creator.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The strategy holds a position, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(creator.publicKey, _masterWallet.publicKey);
+
+    // The swaps are signed by the master wallet, load its sdk first
+    await initSdk({ owner: _masterWallet, loadToken: true });
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: creator.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  const poolInfo = async () => {
+    const data = await strategy.raydium.clmm.getPoolInfoFromRpc(
+      strategy.positionAccounts.raydiumPoolState.toBase58()
+    );
+    return {
+      tickCurrent: data.computePoolInfo.tickCurrent,
+      tickSpacing: data.poolInfo.config.tickSpacing,
+      observationState: data.computePoolInfo.observationId,
+    };
+  };
+
+  // The new position is opened around the current tick of the pool
+  const rebalanceTx = async (newPositionNftMint: Keypair) => {
+    const { tickCurrent, tickSpacing, observationState } = await poolInfo();
+    const alignedTick = Math.floor(tickCurrent / tickSpacing) * tickSpacing;
+    const tickLower = alignedTick - NEW_POSITION_HALF_WIDTH * tickSpacing;
+    const tickUpper = alignedTick + NEW_POSITION_HALF_WIDTH * tickSpacing;
+
+    const newPosition = await raydiumPDAs({
+      ammConfig: strategy.ammConfig,
+      raydium: strategy.raydium,
+      nft: newPositionNftMint.publicKey,
+      tickLower,
+      tickUpper,
+      mint0: strategy.mint0,
+      mint1: strategy.mint1,
+    });
+    const newPositionNftAccount = getAssociatedTokenAddressSync(
+      newPositionNftMint.publicKey,
+      strategy.vaultStrategyConfigPda,
+      true, // allowOwnerOffCurve
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // Without swap, the new position takes the tokens at the current ratio
+    const rebalanceIx = await program.methods
+      .rebalanceRaydiumVaultStrategy(
+        strategyId,
+        tickLower,
+        tickUpper,
+        newPosition.tickArrayLowerStartIndex,
+        newPosition.tickArrayUpperStartIndex,
+        new BN(0),
+        new BN(0),
+        new BN(0),
+        new BN(0),
+        false
+      )
+      .accountsPartial({
+        authority: creator.publicKey,
+        ...strategy.positionAccounts,
+        raydiumAmmConfig: strategy.ammConfig,
+        raydiumObservationState: observationState,
+        raydiumPositionNftMint: strategy.vaultStrategyAccount.dexNftMint,
+        raydiumNewPositionNftMint: newPositionNftMint.publicKey,
+        raydiumNewPositionNftAccount: newPositionNftAccount,
+        raydiumNewPersonalPosition: newPosition.personalPosition,
+        raydiumNewProtocolPosition: newPosition.protocolPosition,
+        raydiumNewTickArrayLower: newPosition.tickLowerArrayAddress,
+        raydiumNewTickArrayUpper: newPosition.tickUpperArrayAddress,
+      })
+      .remainingAccounts(
+        accountMetas([strategy.raydiumAccounts.bitmapExtMint0WithMint1])
+      )
+      .instruction();
+
+    const tx = await buildV0Tx({
+      provider,
+      payer: creator.publicKey,
+      instructions: [rebalanceIx],
+      lookupTables: [strategy.lookupTableAccount],
+      computeUnits: 1_400_000,
+    });
+    return { tx, tickLower, tickUpper };
+  };
+
+  it("Fail to rebalance a position in range", async () => {
+    const newPositionNftMint = Keypair.generate();
+    const { tx } = await rebalanceTx(newPositionNftMint);
+
+    await expectTxError(
+      () => sendTx(tx, [creator, newPositionNftMint]),
+      "RaydiumPositionInRange"
+    );
+  });
+
+  it("Rebalance Raydium Vault Strategy", async () => {
+    const { tickUpperIndex } = strategy.vaultStrategyAccount;
+    const poolId = strategy.positionAccounts.raydiumPoolState.toBase58();
+
+    // Sells token 1 until the price is above the position
+    for (
+      let i = 0;
+      i < 10 && (await poolInfo()).tickCurrent <= tickUpperIndex;
+      i++
+    ) {
+      await swap(
+        strategy.raydium,
+        poolId,
+        strategy.mint1.toBase58(),
+        new BN(1000 * LAMPORTS_PER_SOL)
+      );
+    }
+    expect((await poolInfo()).tickCurrent > tickUpperIndex).to.be.true;
+
+    // The TWAP follows the price once the window has passed,
+    // the last swap writes an observation at the out of range price
+    await sleep((STRATEGY_POOL_TWAP_WINDOW + 10) * 1000);
+    await swap(
+      strategy.raydium,
+      poolId,
+      strategy.mint1.toBase58(),
+      new BN(LAMPORTS_PER_SOL / 1000)
+    );
+
+    const newPositionNftMint = Keypair.generate();
+    const { tx, tickLower, tickUpper } = await rebalanceTx(newPositionNftMint);
+    await sendTx(tx, [creator, newPositionNftMint]);
+
+    // The strategy moved to the new position
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.dexNftMint.toString()).to.equal(
+      newPositionNftMint.publicKey.toString()
+    );
+    expect(vaultStrategyAccount.tickLowerIndex).to.equal(tickLower);
+    expect(vaultStrategyAccount.tickUpperIndex).to.equal(tickUpper);
+    expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+      strategy.vaultStrategyAccount.totalShares.toString()
+    );
+  });
+});
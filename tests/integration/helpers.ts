@@ -0,0 +1,61 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { confirmTransaction } from "@solana-developers/helpers";
+import { connection } from "../../app/config";
+import { airdrop } from "../../app/utils";
+
+export const U64_MAX = new anchor.BN("18446744073709551615");
+
+// Airdrops SOL to the wallets running low, for the transaction fees and rents
+export const fundWallets = async (...wallets: PublicKey[]) => {
+  for (const wallet of wallets) {
+    const balance = await connection.getBalance(wallet);
+    console.log(wallet.toString(), "balance:", balance / LAMPORTS_PER_SOL);
+
+    if (balance < LAMPORTS_PER_SOL) {
+      await airdrop(connection as any, wallet, 200 * LAMPORTS_PER_SOL);
+    }
+  }
+};
+
+export const sendTx = async (
+  tx: anchor.web3.VersionedTransaction,
+  signers: Keypair[]
+) => {
+  tx.sign(signers);
+
+  const txSignature = await connection.sendTransaction(tx as any, {
+    skipPreflight: false,
+    preflightCommitment: "confirmed",
+  });
+  await confirmTransaction(connection as any, txSignature, "confirmed");
+
+  console.log("\n Transaction signature:", txSignature);
+  return txSignature;
+};
+
+// Runs `send` and checks it is rejected by the program with `errorCode`
+export const expectTxError = async (
+  send: () => Promise<unknown>,
+  errorCode: string
+) => {
+  let error: any;
+  try {
+    await send();
+  } catch (e) {
+    error = e;
+  }
+
+  if (!error) {
+    expect.fail("The transaction should have been rejected by the program");
+  }
+  // the simulation error message only keeps the last logs
+  const logs: string[] = error.transactionLogs ?? error.logs ?? [];
+  expect([error.message, ...logs].join("\n")).to.include(errorCode);
+};
+
+export const getTokenAmount = async (tokenAccount: PublicKey) => {
+  const balance = await connection.getTokenAccountBalance(tokenAccount);
+  return new anchor.BN(balance.value.amount);
+};
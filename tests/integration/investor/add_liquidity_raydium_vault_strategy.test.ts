@@ -32,7 +32,7 @@ import {
   initSdk,
   setupDotEnv,
 } from "../../../app/config";
-import { raydiumPDAs } from "../../../app/raydium-helpers";
+import { raydiumAmmConfig, raydiumPDAs } from "../../../app/raydium-helpers";
 import {
   AMM_CONFIG,
   CLMM_PROGRAM_ID,
@@ -53,6 +53,7 @@ import { protocolPDAs } from "../../../app/protocol-pdas";
 import { confirmTransaction } from "@solana-developers/helpers";
 import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
 import { addLiquidityRaydiumStrategyTx } from "../../../app/web/investor/add-liquidity-raydium-strategy";
+import { expectTxError, getTokenAmount, sendTx } from "../helpers";
 
 setupDotEnv();
 
@@ -73,6 +74,8 @@ describe("add-liquidity-raydium-vault-strategy", () => {
     .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
 
   let raydium: Raydium;
+  let poolStateUSDCWithMint0: PublicKey;
+  let poolStateUSDCWithMint1: PublicKey;
 
   before(async () => {
     console.log("Running tests on ", process.env.ENV);
@@ -121,6 +124,44 @@ describe("add-liquidity-raydium-vault-strategy", () => {
     );
     console.log(`\n USDC Balance: ${balanceUsdc?.uiAmountString} USDC \n `);
     console.log();
+
+    ({ poolStateUSDCWithMint0, poolStateUSDCWithMint1 } = await raydiumPDAs({
+      ammConfig: raydiumAmmConfig(),
+      raydium,
+      mint0: TokenA,
+      mint1: TokenB,
+    }));
+  });
+
+  it("Fail to add liquidity with a refund swap pool of the other token", async () => {
+    const { vaultStrategyPda, investorStrategyPositionPda } = protocolPDAs({
+      strategyCreator: creator.publicKey,
+      investor: creator.publicKey,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      strategyId: 1,
+      mint0: TokenA,
+      mint1: TokenB,
+    });
+
+    const { tx } = await addLiquidityRaydiumStrategyTx({
+      provider,
+      investor: creator.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      amount: 10,
+      refundSwapPools: {
+        token0: poolStateUSDCWithMint1,
+        token1: poolStateUSDCWithMint0,
+      },
+    });
+
+    await expectTxError(() => sendTx(tx, [creator]), "InvalidSwapPool");
+
+    const investorStrategyPositionAccount =
+      await program.account.investorStrategyPosition.fetchNullable(
+        investorStrategyPositionPda
+      );
+    expect(investorStrategyPositionAccount).to.be.null;
   });
 
   it("Add Liquidity Raydium Vault Strategy", async () => {
@@ -132,6 +173,8 @@ describe("add-liquidity-raydium-vault-strategy", () => {
       vaultStrategyConfigPda,
       vaultStrategyPda,
       investorStrategyPositionPda,
+      vaultStrategyCfgMint0RebalanceEscrow,
+      vaultStrategyCfgMint1RebalanceEscrow,
     } = protocolPDAs({
       strategyCreator: creator.publicKey,
       investor: creator.publicKey,
@@ -148,6 +191,11 @@ describe("add-liquidity-raydium-vault-strategy", () => {
         vaultStrategyPda,
         strategyConfigName,
         amount: amountToAdd,
+        // the remainders of the legs go back to USDC instead of the investor token accounts
+        refundSwapPools: {
+          token0: poolStateUSDCWithMint0,
+          token1: poolStateUSDCWithMint1,
+        },
       });
 
       tx.sign([creator]);
@@ -200,6 +248,25 @@ describe("add-liquidity-raydium-vault-strategy", () => {
 
       expect(vaultStrategyAccount.totalAssets.toNumber()).to.equal(184561723);
       expect(vaultStrategyAccount.totalShares.toNumber()).to.equal(184561723);
+
+      // The share token balance is what the investor owns
+      const investorShareAccount = getAssociatedTokenAddressSync(
+        vaultStrategyAccount.shareMint,
+        creator.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+      expect((await getTokenAmount(investorShareAccount)).toNumber()).to.equal(
+        184561723
+      );
+
+      // The rebalance escrows are opened by the first deposit and start empty
+      expect(
+        (await getTokenAmount(vaultStrategyCfgMint0RebalanceEscrow)).toNumber()
+      ).to.equal(0);
+      expect(
+        (await getTokenAmount(vaultStrategyCfgMint1RebalanceEscrow)).toNumber()
+      ).to.equal(0);
     } catch (error) {
       console.error("Error adding liquidity:", error);
       throw error;
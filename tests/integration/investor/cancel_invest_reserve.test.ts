@@ -0,0 +1,96 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { getTokens } from "../../../app/web/tokens";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount } from "../helpers";
+
+setupDotEnv();
+
+describe("cancel-invest-reserve", () => {
+  const investor = _creatorWallet;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { USDC } = getTokens();
+  const { mint0, mint1, vaultStrategyConfigPda, investReserveVaultPda } =
+    protocolPDAs({
+      strategyCreator: investor.publicKey,
+      investor: investor.publicKey,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      strategyId: 1,
+      mint0: TokenA,
+      mint1: TokenB,
+    });
+  const [escrowVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow_vault:"), investor.publicKey.toBuffer()],
+    program.programId
+  );
+
+  // The investor has USDC in its escrow, see deposit_to_escrow
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+  });
+
+  const cancelInvestReserve = () =>
+    program.methods
+      .cancelInvestReserve()
+      .accountsPartial({
+        investor: investor.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+        usdcMint: USDC,
+        raydiumVault0Mint: mint0,
+        raydiumVault1Mint: mint1,
+      })
+      .signers([investor])
+      .rpc();
+
+  it("Cancel Invest Reserve", async () => {
+    const escrowBefore = await getTokenAmount(escrowVaultPda);
+
+    await program.methods
+      .investReserve(new BN(10 * 1e6))
+      .accounts({
+        investor: investor.publicKey,
+        vaultStrategyConfig: vaultStrategyConfigPda,
+        usdcMint: USDC,
+      })
+      .signers([investor])
+      .rpc();
+
+    const escrowReserved = await getTokenAmount(escrowVaultPda);
+    expect(escrowBefore.sub(escrowReserved).toNumber()).to.equal(10 * 1e6);
+
+    const txSignature = await cancelInvestReserve();
+    console.log("\n Transaction signature:", txSignature);
+
+    // The reserved USDC is back in the escrow
+    const escrowAfter = await getTokenAmount(escrowVaultPda);
+    expect(escrowAfter.toString()).to.equal(escrowBefore.toString());
+
+    const investReserveVaultAccount =
+      await program.account.investReserveVault.fetch(investReserveVaultPda);
+    expect(investReserveVaultAccount.reservedAmount.toNumber()).to.equal(0);
+    expect(investReserveVaultAccount.swapToRatioVaults.length).to.equal(0);
+  });
+
+  it("Fail to cancel without a reserve", async () => {
+    await expectTxError(() => cancelInvestReserve(), "NoReservedAmount");
+  });
+});
@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  _creatorWallet,
+  _masterWallet,
+  connection,
+  initSdk,
+  setupDotEnv,
+} from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { swap } from "../../../app/swap";
+import {
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("claim-investor-fees", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey, _masterWallet.publicKey);
+
+    // The swaps are signed by the master wallet, load its sdk first
+    const raydium = await initSdk({ owner: _masterWallet, loadToken: true });
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+
+    // Swaps both ways in the strategy pool to earn fees to the position
+    const poolId = strategy.positionAccounts.raydiumPoolState.toBase58();
+    await swap(
+      raydium,
+      poolId,
+      strategy.mint0.toBase58(),
+      new anchor.BN(LAMPORTS_PER_SOL)
+    );
+    await swap(
+      raydium,
+      poolId,
+      strategy.mint1.toBase58(),
+      new anchor.BN(LAMPORTS_PER_SOL)
+    );
+  });
+
+  const claimInvestorFeesTx = async () => {
+    const claimInvestorFeesIx = await program.methods
+      .claimInvestorFees(strategyId)
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+      })
+      .remainingAccounts(
+        accountMetas([strategy.raydiumAccounts.bitmapExtMint0WithMint1])
+      )
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [claimInvestorFeesIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Claim Investor Fees", async () => {
+    const investorMint0Account = getAssociatedTokenAddressSync(
+      strategy.mint0,
+      investor.publicKey
+    );
+    const investorMint1Account = getAssociatedTokenAddressSync(
+      strategy.mint1,
+      investor.publicKey
+    );
+    const balance0Before = await getTokenAmount(investorMint0Account);
+    const balance1Before = await getTokenAmount(investorMint1Account);
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+
+    const tx = await claimInvestorFeesTx();
+    await sendTx(tx, [investor]);
+
+    // The fees of both swaps are paid to the investor
+    const balance0After = await getTokenAmount(investorMint0Account);
+    const balance1After = await getTokenAmount(investorMint1Account);
+    expect(balance0After.gt(balance0Before)).to.be.true;
+    expect(balance1After.gt(balance1Before)).to.be.true;
+
+    // The shares are left untouched
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    expect(sharesAfter.toString()).to.equal(sharesBefore.toString());
+  });
+
+  it("Fail to claim without new fees", async () => {
+    const tx = await claimInvestorFeesTx();
+
+    await expectTxError(() => sendTx(tx, [investor]), "NoFeesToClaim");
+  });
+});
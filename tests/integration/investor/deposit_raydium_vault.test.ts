@@ -0,0 +1,174 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+  swapAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import {
+  U64_MAX,
+  expectTxError,
+  fundWallets,
+  getTokenAmount,
+  sendTx,
+} from "../helpers";
+
+setupDotEnv();
+
+describe("deposit-raydium-vault", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda, investorStrategyPositionPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+  const [escrowVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow_vault:"), investor.publicKey.toBuffer()],
+    program.programId
+  );
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor has USDC in its escrow, see deposit_to_escrow
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  // USDC of the investor escrow, swapped to the position ratio and added to the position
+  const depositTx = async (amount: BN, minSharesOut: BN) => {
+    const { raydium, ammConfig, USDC, raydiumAccounts } = strategy;
+
+    // The strategy splits the USDC between both legs
+    const swap0 = await swapAccounts({
+      raydium,
+      poolState: raydiumAccounts.poolStateUSDCWithMint0,
+      inputMint: USDC,
+      amountIn: amount.divn(2),
+    });
+    const swap1 = await swapAccounts({
+      raydium,
+      poolState: raydiumAccounts.poolStateUSDCWithMint1,
+      inputMint: USDC,
+      amountIn: amount.divn(2),
+    });
+
+    const depositIx = await program.methods
+      .depositRaydiumVault(
+        strategyId,
+        amount,
+        new BN(0),
+        new BN(0),
+        minSharesOut
+      )
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+        usdcMint: USDC,
+        raydiumAmmConfigUsdcForToken0: ammConfig,
+        raydiumPoolStateUsdcForToken0: raydiumAccounts.poolStateUSDCWithMint0,
+        raydiumAmmConfigUsdcForToken1: ammConfig,
+        raydiumPoolStateUsdcForToken1: raydiumAccounts.poolStateUSDCWithMint1,
+        raydiumVault0Input: swap0.inputVault,
+        raydiumVault1Input: swap1.inputVault,
+        raydiumVault0Output: swap0.outputVault,
+        raydiumVault1Output: swap1.outputVault,
+        raydiumObservationState0: swap0.observationState,
+        raydiumObservationState1: swap1.observationState,
+      })
+      .remainingAccounts([
+        ...accountMetas(swap0.tickArrays),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas(swap1.tickArrays),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas([raydiumAccounts.bitmapExtMint0WithMint1]),
+      ])
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [depositIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Fail to deposit below the minimum shares out", async () => {
+    const tx = await depositTx(new BN(10 * 1e6), U64_MAX);
+
+    await expectTxError(() => sendTx(tx, [investor]), "SharesBelowMinimum");
+  });
+
+  it("Deposit Raydium Vault", async () => {
+    const amount = new BN(10 * 1e6);
+
+    const escrowBefore = await getTokenAmount(escrowVaultPda);
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+    const vaultStrategyBefore =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+
+    const tx = await depositTx(amount, new BN(1));
+    await sendTx(tx, [investor]);
+
+    const escrowAfter = await getTokenAmount(escrowVaultPda);
+    expect(escrowBefore.sub(escrowAfter).toString()).to.equal(
+      amount.toString()
+    );
+
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    const mintedShares = sharesAfter.sub(sharesBefore);
+    expect(mintedShares.gtn(0)).to.be.true;
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+      vaultStrategyBefore.totalShares.add(mintedShares).toString()
+    );
+    expect(
+      vaultStrategyAccount.totalAssets.gt(vaultStrategyBefore.totalAssets)
+    ).to.be.true;
+
+    const investorStrategyPositionAccount =
+      await program.account.investorStrategyPosition.fetch(
+        investorStrategyPositionPda
+      );
+    expect(investorStrategyPositionAccount.shares.toString()).to.equal(
+      sharesAfter.toString()
+    );
+  });
+});
@@ -0,0 +1,138 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+  swapAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("deposit-tokens-raydium-vault", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda, investorStrategyPositionPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds token 0, see remove_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  // Token 0 only, the strategy swaps a part of it to token 1 in the strategy pool
+  const depositTokensTx = async (amount0: BN, minSharesOut: BN) => {
+    const { raydium, ammConfig, mint0, raydiumAccounts } = strategy;
+
+    const swap = await swapAccounts({
+      raydium,
+      poolState: raydiumAccounts.poolStateMint0WithMint1,
+      inputMint: mint0,
+      amountIn: amount0.divn(2),
+    });
+
+    const depositTokensIx = await program.methods
+      .depositTokensRaydiumVault(
+        strategyId,
+        amount0,
+        new BN(0),
+        new BN(0),
+        minSharesOut
+      )
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        raydiumAmmConfig: ammConfig,
+        raydiumObservationState: swap.observationState,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+      })
+      .remainingAccounts([
+        ...accountMetas([raydiumAccounts.bitmapExtMint0WithMint1]),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas(swap.tickArrays),
+      ])
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [depositTokensIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Fail to deposit no tokens", async () => {
+    const tx = await depositTokensTx(new BN(0), new BN(0));
+
+    await expectTxError(() => sendTx(tx, [investor]), "InvalidAmount");
+  });
+
+  it("Deposit Tokens Raydium Vault", async () => {
+    const amount0 = new BN(10_000_000);
+    const investorMint0Account = getAssociatedTokenAddressSync(
+      strategy.mint0,
+      investor.publicKey
+    );
+
+    const token0Before = await getTokenAmount(investorMint0Account);
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+
+    const tx = await depositTokensTx(amount0, new BN(1));
+    await sendTx(tx, [investor]);
+
+    const token0After = await getTokenAmount(investorMint0Account);
+    const spent0 = token0Before.sub(token0After);
+    expect(spent0.gtn(0)).to.be.true;
+    expect(spent0.lte(amount0)).to.be.true;
+
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    expect(sharesAfter.gt(sharesBefore)).to.be.true;
+
+    const investorStrategyPositionAccount =
+      await program.account.investorStrategyPosition.fetch(
+        investorStrategyPositionPda
+      );
+    expect(investorStrategyPositionAccount.shares.toString()).to.equal(
+      sharesAfter.toString()
+    );
+  });
+});
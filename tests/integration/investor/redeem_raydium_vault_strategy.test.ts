@@ -0,0 +1,133 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("redeem-raydium-vault-strategy", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda, investorStrategyPositionPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  const redeemTx = async (shares: BN) => {
+    const redeemIx = await program.methods
+      .redeemRaydiumVaultStrategy(strategyId, shares, new BN(0), new BN(0))
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+      })
+      .remainingAccounts(
+        accountMetas([strategy.raydiumAccounts.bitmapExtMint0WithMint1])
+      )
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [redeemIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Fail to redeem more shares than owned", async () => {
+    const shares = await getTokenAmount(strategy.investorShareAccount);
+    const tx = await redeemTx(shares.addn(1));
+
+    await expectTxError(() => sendTx(tx, [investor]), "InsufficientShares");
+  });
+
+  it("Redeem Raydium Vault Strategy", async () => {
+    const investorMint0Account = getAssociatedTokenAddressSync(
+      strategy.mint0,
+      investor.publicKey
+    );
+    const investorMint1Account = getAssociatedTokenAddressSync(
+      strategy.mint1,
+      investor.publicKey
+    );
+
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+    const token0Before = await getTokenAmount(investorMint0Account);
+    const token1Before = await getTokenAmount(investorMint1Account);
+    const vaultStrategyBefore =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+
+    const shares = sharesBefore.divn(10);
+    const tx = await redeemTx(shares);
+    await sendTx(tx, [investor]);
+
+    // Exactly the given shares are burned
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    expect(sharesBefore.sub(sharesAfter).toString()).to.equal(
+      shares.toString()
+    );
+
+    const token0After = await getTokenAmount(investorMint0Account);
+    const token1After = await getTokenAmount(investorMint1Account);
+    expect(token0After.gt(token0Before) || token1After.gt(token1Before)).to.be
+      .true;
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+      vaultStrategyBefore.totalShares.sub(shares).toString()
+    );
+
+    const investorStrategyPositionAccount =
+      await program.account.investorStrategyPosition.fetch(
+        investorStrategyPositionPda
+      );
+    expect(investorStrategyPositionAccount.shares.toString()).to.equal(
+      sharesAfter.toString()
+    );
+  });
+});
@@ -0,0 +1,152 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { AccountMeta } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("redeem-vault", () => {
+  const investor = _creatorWallet;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId: 1,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  // One entry per strategy of the vault, in the order of the vault strategies
+  let strategies: Awaited<ReturnType<typeof raydiumStrategyAccounts>>[];
+
+  // The investor holds shares of the strategies, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    const vaultStrategyConfigAccount =
+      await program.account.vaultStrategyConfig.fetch(
+        vaultStrategyAccount.vaultStrategyConfigKey
+      );
+
+    strategies = [];
+    for (const strategyPda of vaultStrategyConfigAccount.strategies) {
+      strategies.push(
+        await raydiumStrategyAccounts({
+          provider,
+          investor: investor.publicKey,
+          vaultStrategyPda: strategyPda,
+          strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+        })
+      );
+    }
+  });
+
+  // vault_strategy, investor_strategy_position, share_mint, investor_share_account,
+  // the position accounts, the rebalance escrows and the tick array bitmap extension
+  const strategyRemainingAccounts = (
+    strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>
+  ): AccountMeta[] =>
+    accountMetas([
+      strategy.protocolAccounts.vaultStrategyPda,
+      strategy.protocolAccounts.investorStrategyPositionPda,
+      strategy.vaultStrategyAccount.shareMint,
+      strategy.investorShareAccount,
+      strategy.positionAccounts.raydiumPoolState,
+      strategy.positionAccounts.raydiumPersonalPosition,
+      strategy.positionAccounts.raydiumProtocolPosition,
+      strategy.positionAccounts.raydiumTickArrayLower,
+      strategy.positionAccounts.raydiumTickArrayUpper,
+      strategy.positionAccounts.raydiumPositionNftAccount,
+      strategy.positionAccounts.raydiumTokenVault0,
+      strategy.positionAccounts.raydiumTokenVault1,
+      strategy.protocolAccounts.vaultStrategyCfgMint0RebalanceEscrow,
+      strategy.protocolAccounts.vaultStrategyCfgMint1RebalanceEscrow,
+      strategy.raydiumAccounts.bitmapExtMint0WithMint1,
+    ]);
+
+  const redeemVaultTx = async (
+    percentage: number,
+    remainingAccounts: AccountMeta[]
+  ) => {
+    const [strategy] = strategies;
+
+    const redeemVaultIx = await program.methods
+      .redeemVault(new BN(percentage * 1e9), new BN(0), new BN(0))
+      .accountsPartial({
+        investor: investor.publicKey,
+        vaultStrategyConfig: strategy.vaultStrategyConfigPda,
+        raydiumVault0Mint: strategy.mint0,
+        raydiumVault1Mint: strategy.mint1,
+        ...strategy.oracleAccounts,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [redeemVaultIx],
+      lookupTables: strategies.map((strategy) => strategy.lookupTableAccount),
+      computeUnits: 1_400_000,
+    });
+  };
+
+  it("Fail to redeem without the accounts of every strategy", async () => {
+    const tx = await redeemVaultTx(10, []);
+
+    await expectTxError(
+      () => sendTx(tx, [investor]),
+      "InsufficientRemainingAccounts"
+    );
+  });
+
+  it("Redeem Vault", async () => {
+    const sharesBefore: BN[] = [];
+    for (const strategy of strategies) {
+      sharesBefore.push(await getTokenAmount(strategy.investorShareAccount));
+    }
+
+    const remainingAccounts = strategies.flatMap((strategy, index) => [
+      ...(index > 0 ? [SEPARATOR_ACCOUNT] : []),
+      ...strategyRemainingAccounts(strategy),
+    ]);
+    const tx = await redeemVaultTx(10, remainingAccounts);
+    await sendTx(tx, [investor]);
+
+    // The same percentage of the shares is burned in every strategy
+    for (const [index, strategy] of strategies.entries()) {
+      const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+      expect(sharesBefore[index].sub(sharesAfter).toString()).to.equal(
+        sharesBefore[index].divn(10).toString()
+      );
+    }
+  });
+});
@@ -54,6 +54,7 @@ import { protocolPDAs } from "../../../app/protocol-pdas";
 import { confirmTransaction } from "@solana-developers/helpers";
 import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
 import { removeLiquidityRaydiumStrategyTx } from "../../../app/web/investor/remove-liquidity-raydium-strategy";
+import { expectTxError, getTokenAmount, sendTx } from "../helpers";
 
 setupDotEnv();
 
@@ -124,6 +125,27 @@ describe("remove-liquidity-raydium-vault-strategy", () => {
     console.log();
   });
 
+  it("Fail to remove less than the minimum percentage", async () => {
+    const { vaultStrategyPda } = protocolPDAs({
+      strategyCreator: creator.publicKey,
+      investor: creator.publicKey,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      strategyId: 1,
+      mint0: TokenA,
+      mint1: TokenB,
+    });
+
+    const { tx } = await removeLiquidityRaydiumStrategyTx({
+      provider,
+      investor: creator.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+      percentage: 5,
+    });
+
+    await expectTxError(() => sendTx(tx, [creator]), "InvalidRemovePercentage");
+  });
+
   it("Remove Liquidity Raydium Vault Strategy", async () => {
     const strategyId = 1;
     const strategyConfigName = VAULT_STRATEGY_CONFIG_NAME;
@@ -151,6 +173,16 @@ describe("remove-liquidity-raydium-vault-strategy", () => {
     });
 
     try {
+      const vaultStrategyAccountBefore =
+        await program.account.vaultStrategy.fetch(vaultStrategyPda);
+      const investorShareAccount = getAssociatedTokenAddressSync(
+        vaultStrategyAccountBefore.shareMint,
+        creator.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+      const sharesBefore = await getTokenAmount(investorShareAccount);
+
       const { tx } = await removeLiquidityRaydiumStrategyTx({
         provider,
         investor: creator.publicKey,
@@ -169,36 +201,6 @@ describe("remove-liquidity-raydium-vault-strategy", () => {
 
       console.log("\n Transaction signature:", txSignature);
 
-      tx.sign([creator]);
-
-      console.log("Transaction raw size", tx.serialize().length);
-
-      // const txSignature = await connection.sendTransaction(tx as any, {
-      //   skipPreflight: false,
-      //   preflightCommitment: "finalized",
-      // });
-      // await confirmTransaction(connection as any, txSignature, "finalized");
-
-      // console.log("\n Transaction signature:", txSignature);
-
-      const [investorMint0AccountPubKey] = PublicKey.findProgramAddressSync(
-        [
-          creator.publicKey.toBuffer(),
-          TOKEN_PROGRAM_ID.toBuffer(),
-          mint0.toBuffer(),
-        ],
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      );
-
-      const [creatorTokenAccount1PubKey] = PublicKey.findProgramAddressSync(
-        [
-          creator.publicKey.toBuffer(),
-          TOKEN_PROGRAM_ID.toBuffer(),
-          mint1.toBuffer(),
-        ],
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      );
-
       let investorMint0Account = await getOrCreateAssociatedTokenAccount(
         connection as any,
         creator,
@@ -264,13 +266,19 @@ describe("remove-liquidity-raydium-vault-strategy", () => {
       expect(investorStrategyPositionAccount.shares.toNumber()).to.equal(0);
       expect(investorStrategyPositionAccount.assets.toNumber()).to.equal(0);
 
+      // All the shares of the investor are burned
+      expect((await getTokenAmount(investorShareAccount)).toNumber()).to.equal(
+        0
+      );
+
       const vaultStrategyAccount =
         await program.account.vaultStrategy.fetch(vaultStrategyPda);
 
-      expect(vaultStrategyAccount.totalAssets.toNumber()).to.equal(184561723);
-      expect(vaultStrategyAccount.totalShares.toNumber()).to.equal(184561723);
+      expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+        vaultStrategyAccountBefore.totalShares.sub(sharesBefore).toString()
+      );
     } catch (error) {
-      console.error("Error adding liquidity:", error);
+      console.error("Error removing liquidity:", error);
       throw error;
     }
   });
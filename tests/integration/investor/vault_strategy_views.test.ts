@@ -0,0 +1,165 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import { raydiumStrategyAccounts } from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount } from "../helpers";
+
+setupDotEnv();
+
+describe("vault-strategy-views", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda, investorStrategyPositionPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  // Accounts of the views, the investor strategy position is only read by get_position_value
+  const viewAccounts = (
+    investorStrategyPosition: anchor.web3.PublicKey | null = null
+  ) => ({
+    vaultStrategyConfig: strategy.vaultStrategyConfigPda,
+    investorStrategyPosition,
+    raydiumPoolState: strategy.positionAccounts.raydiumPoolState,
+    raydiumPersonalPosition: strategy.positionAccounts.raydiumPersonalPosition,
+    raydiumTickArrayLower: strategy.positionAccounts.raydiumTickArrayLower,
+    raydiumTickArrayUpper: strategy.positionAccounts.raydiumTickArrayUpper,
+    raydiumVault0Mint: strategy.mint0,
+    raydiumVault1Mint: strategy.mint1,
+    ...strategy.oracleAccounts,
+  });
+
+  // A pool of another position than the strategy one
+  const otherPoolViewAccounts = () => ({
+    ...viewAccounts(),
+    raydiumPoolState: strategy.raydiumAccounts.poolStateUSDCWithMint0,
+  });
+
+  it("Get Vault NAV", async () => {
+    const vaultNav = await program.methods
+      .getVaultNav(strategyId)
+      .accountsPartial(viewAccounts())
+      .view();
+
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultNav.totalShares.toString()).to.equal(
+      vaultStrategyAccount.totalShares.toString()
+    );
+    expect(vaultNav.nav.gtn(0)).to.be.true;
+    expect(vaultNav.sharePrice.gtn(0)).to.be.true;
+  });
+
+  it("Fail to get the vault NAV with another pool", async () => {
+    await expectTxError(
+      () =>
+        program.methods
+          .getVaultNav(strategyId)
+          .accountsPartial(otherPoolViewAccounts())
+          .view(),
+      "InvalidRaydiumPosition"
+    );
+  });
+
+  it("Get Position Value", async () => {
+    const positionValue = await program.methods
+      .getPositionValue(strategyId)
+      .accountsPartial(viewAccounts(investorStrategyPositionPda))
+      .view();
+
+    const shares = await getTokenAmount(strategy.investorShareAccount);
+    expect(positionValue.shares.toString()).to.equal(shares.toString());
+    expect(positionValue.assets.gtn(0)).to.be.true;
+  });
+
+  it("Fail to get the position value without the investor position", async () => {
+    await expectTxError(
+      () =>
+        program.methods
+          .getPositionValue(strategyId)
+          .accountsPartial(viewAccounts())
+          .view(),
+      "MissingInvestorStrategyPosition"
+    );
+  });
+
+  it("Preview Deposit", async () => {
+    const depositPreview = await program.methods
+      .previewDeposit(strategyId, new BN(10_000_000), new BN(10_000_000))
+      .accountsPartial(viewAccounts())
+      .view();
+
+    expect(depositPreview.assets.gtn(0)).to.be.true;
+    expect(depositPreview.shares.gtn(0)).to.be.true;
+  });
+
+  it("Fail to preview a deposit with another pool", async () => {
+    await expectTxError(
+      () =>
+        program.methods
+          .previewDeposit(strategyId, new BN(10_000_000), new BN(10_000_000))
+          .accountsPartial(otherPoolViewAccounts())
+          .view(),
+      "InvalidRaydiumPosition"
+    );
+  });
+
+  it("Preview Redeem", async () => {
+    const shares = await getTokenAmount(strategy.investorShareAccount);
+    const redeemPreview = await program.methods
+      .previewRedeem(strategyId, shares.divn(10))
+      .accountsPartial(viewAccounts())
+      .view();
+
+    expect(redeemPreview.assets.gtn(0)).to.be.true;
+    expect(redeemPreview.liquidity.gtn(0)).to.be.true;
+  });
+
+  it("Fail to preview the redeem of no shares", async () => {
+    await expectTxError(
+      () =>
+        program.methods
+          .previewRedeem(strategyId, new BN(0))
+          .accountsPartial(viewAccounts())
+          .view(),
+      "LiquidityCalculatedToZero"
+    );
+  });
+});
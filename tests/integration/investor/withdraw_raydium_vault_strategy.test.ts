@@ -0,0 +1,112 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import { expectTxError, fundWallets, getTokenAmount, sendTx } from "../helpers";
+
+setupDotEnv();
+
+describe("withdraw-raydium-vault-strategy", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  const withdrawTx = async (assets: BN, maxShares: BN) => {
+    const withdrawIx = await program.methods
+      .withdrawRaydiumVaultStrategy(
+        strategyId,
+        assets,
+        maxShares,
+        new BN(0),
+        new BN(0)
+      )
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+      })
+      .remainingAccounts(
+        accountMetas([strategy.raydiumAccounts.bitmapExtMint0WithMint1])
+      )
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [withdrawIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Fail to withdraw when more than the maximum shares would be burned", async () => {
+    const tx = await withdrawTx(new BN(1e6), new BN(1));
+
+    await expectTxError(() => sendTx(tx, [investor]), "SharesAboveMaximum");
+  });
+
+  it("Withdraw Raydium Vault Strategy", async () => {
+    const assets = new BN(1e6); // 1 USDC
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+    const vaultStrategyBefore =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+
+    const tx = await withdrawTx(assets, sharesBefore);
+    await sendTx(tx, [investor]);
+
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    const burnedShares = sharesBefore.sub(sharesAfter);
+    expect(burnedShares.gtn(0)).to.be.true;
+
+    // The shares worth the assets are burned, rounded up
+    const vaultStrategyAccount =
+      await program.account.vaultStrategy.fetch(vaultStrategyPda);
+    expect(vaultStrategyAccount.totalShares.toString()).to.equal(
+      vaultStrategyBefore.totalShares.sub(burnedShares).toString()
+    );
+  });
+});
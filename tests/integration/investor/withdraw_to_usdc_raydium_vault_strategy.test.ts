@@ -0,0 +1,154 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TokenizedVaultsProgram } from "../../../target/types/tokenized_vaults_program";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { _creatorWallet, connection, setupDotEnv } from "../../../app/config";
+import { TokenA, TokenB } from "../../../app/constants";
+import { protocolPDAs } from "../../../app/protocol-pdas";
+import {
+  SEPARATOR_ACCOUNT,
+  accountMetas,
+  buildV0Tx,
+  raydiumStrategyAccounts,
+  swapAccounts,
+} from "../../../app/web/raydium-strategy";
+import { VAULT_STRATEGY_CONFIG_NAME } from "../constants";
+import {
+  U64_MAX,
+  expectTxError,
+  fundWallets,
+  getTokenAmount,
+  sendTx,
+} from "../helpers";
+
+setupDotEnv();
+
+describe("withdraw-to-usdc-raydium-vault-strategy", () => {
+  const investor = _creatorWallet;
+  const strategyId = 1;
+
+  // Get the provider and connection objects
+  const provider = new anchor.AnchorProvider(
+    connection as any,
+    new anchor.Wallet(investor as any),
+    {
+      commitment: "confirmed",
+    }
+  );
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .tokenizedVaultsProgram as Program<TokenizedVaultsProgram>;
+
+  const { vaultStrategyPda } = protocolPDAs({
+    strategyCreator: investor.publicKey,
+    investor: investor.publicKey,
+    strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    strategyId,
+    mint0: TokenA,
+    mint1: TokenB,
+  });
+  const [escrowVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow_vault:"), investor.publicKey.toBuffer()],
+    program.programId
+  );
+
+  let strategy: Awaited<ReturnType<typeof raydiumStrategyAccounts>>;
+
+  // The investor holds shares of the strategy, see add_liquidity_raydium_vault_strategy
+  before(async () => {
+    console.log("Running tests on ", process.env.ENV);
+    await fundWallets(investor.publicKey);
+
+    strategy = await raydiumStrategyAccounts({
+      provider,
+      investor: investor.publicKey,
+      vaultStrategyPda,
+      strategyConfigName: VAULT_STRATEGY_CONFIG_NAME,
+    });
+  });
+
+  // The USDC is credited to the investor escrow
+  const withdrawToUsdcTx = async (percentage: number, usdcAmountOutMin: BN) => {
+    const { raydium, mint0, mint1, USDC, raydiumAccounts } = strategy;
+
+    // The legs of a partial withdrawal stay in the current tick arrays
+    const swap0 = await swapAccounts({
+      raydium,
+      poolState: raydiumAccounts.poolStateUSDCWithMint0,
+      inputMint: mint0,
+      amountIn: new BN(1e7),
+    });
+    const swap1 = await swapAccounts({
+      raydium,
+      poolState: raydiumAccounts.poolStateUSDCWithMint1,
+      inputMint: mint1,
+      amountIn: new BN(1e7),
+    });
+
+    const withdrawToUsdcIx = await program.methods
+      .withdrawToUsdcRaydiumVaultStrategy(
+        strategyId,
+        new BN(percentage * 1e9),
+        new BN(0),
+        new BN(0),
+        usdcAmountOutMin
+      )
+      .accountsPartial({
+        investor: investor.publicKey,
+        ...strategy.positionAccounts,
+        ...strategy.oracleAccounts,
+        shareMint: strategy.vaultStrategyAccount.shareMint,
+        usdcMint: USDC,
+        investorUsdcAccount: null,
+        raydiumAmmConfigToken0ForUsdc: swap0.ammConfig,
+        raydiumPoolStateToken0ForUsdc: raydiumAccounts.poolStateUSDCWithMint0,
+        raydiumAmmConfigToken1ForUsdc: swap1.ammConfig,
+        raydiumPoolStateToken1ForUsdc: raydiumAccounts.poolStateUSDCWithMint1,
+        raydiumVault0Input: swap0.inputVault,
+        raydiumVault1Input: swap1.inputVault,
+        raydiumVault0Output: swap0.outputVault,
+        raydiumVault1Output: swap1.outputVault,
+        raydiumObservationState0: swap0.observationState,
+        raydiumObservationState1: swap1.observationState,
+      })
+      .remainingAccounts([
+        ...accountMetas([raydiumAccounts.bitmapExtMint0WithMint1]),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas(swap0.tickArrays),
+        SEPARATOR_ACCOUNT,
+        ...accountMetas(swap1.tickArrays),
+      ])
+      .instruction();
+
+    return buildV0Tx({
+      provider,
+      payer: investor.publicKey,
+      instructions: [withdrawToUsdcIx],
+      lookupTables: [strategy.lookupTableAccount],
+    });
+  };
+
+  it("Fail to withdraw below the minimum USDC out", async () => {
+    const tx = await withdrawToUsdcTx(10, U64_MAX);
+
+    await expectTxError(() => sendTx(tx, [investor]), "AmountOutBelowMinimum");
+  });
+
+  it("Withdraw To USDC Raydium Vault Strategy", async () => {
+    const escrowBefore = await getTokenAmount(escrowVaultPda);
+    const sharesBefore = await getTokenAmount(strategy.investorShareAccount);
+
+    const tx = await withdrawToUsdcTx(10, new BN(1));
+    await sendTx(tx, [investor]);
+
+    const escrowAfter = await getTokenAmount(escrowVaultPda);
+    expect(escrowAfter.gt(escrowBefore)).to.be.true;
+
+    // 10% of the shares are burned
+    const sharesAfter = await getTokenAmount(strategy.investorShareAccount);
+    expect(sharesBefore.sub(sharesAfter).toString()).to.equal(
+      sharesBefore.divn(10).toString()
+    );
+  });
+});